@@ -1,3 +1,219 @@
+//! Bauzeit-Informationen und die Schriftauszüge für den Etikettenexport.
+//!
+//! Der Export misst und zeichnet mit DejaVu Sans. Die vollständigen Schriften
+//! enthalten Tausende Glyphen, die keine Etikette braucht, und jeder Besucher
+//! lüde sie mit dem wasm-Bundle herunter. Der Build behält Latin-1, einige
+//! typografische Zeichen und alle Zeichen der Übersetzungen und Datentabellen.
+//! Die Glyphen-IDs bleiben gleich; weggelassene Glyphen verlieren nur Umriss
+//! und cmap-Eintrag und erscheinen als Ersatzglyphe.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use ttf_parser::{Face, Tag};
+
+const FONTS: [&str; 2] = ["DejaVuSans", "DejaVuSans-Bold"];
+
+/// Typografie ausserhalb Latin-1: œ/Œ (Französisch), Anführungszeichen,
+/// Striche, Auslassungspunkte, schmale Leerzeichen, €, ‰ und das Minus.
+const TYPOGRAPHY: &str = "œŒŸ‘’‚“”„‹›–—…•€‰−\u{2009}\u{202f}";
+
+/// Tabellen für Satz, PDF-Subsetter und Hinting. GSUB, GPOS und kern
+/// verwendet die Etikette nicht.
+const KEPT_TABLES: [&[u8; 4]; 9] = [b"OS/2", b"cvt ", b"fpgm", b"gasp", b"hhea", b"hmtx", b"maxp", b"name", b"prep"];
+
 fn main() {
     built::write_built_file().expect("Failed to acquire build-time information");
+
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let chars = label_chars();
+    for name in FONTS {
+        let data = std::fs::read(format!("assets/fonts/{name}.ttf")).expect("bundled font is readable");
+        let subset = subset_font(&data, &chars);
+        std::fs::write(Path::new(&out_dir).join(format!("{name}.ttf")), subset).expect("font subset is writable");
+    }
+}
+
+/// Latin-1, die Typografie oben und jedes Zeichen der Übersetzungen und
+/// CSV-Tabellen (Zutatennamen, Bezeichnungen).
+fn label_chars() -> BTreeSet<char> {
+    let mut chars: BTreeSet<char> = (' '..='~').chain('\u{a0}'..='ÿ').chain(TYPOGRAPHY.chars()).collect();
+    for dir in ["locales", "src"] {
+        for entry in std::fs::read_dir(dir).expect("source directory is readable") {
+            let path = entry.expect("directory entry is readable").path();
+            if path.extension().is_some_and(|e| e == "yml" || e == "csv") {
+                let text = std::fs::read_to_string(&path).expect("text file is readable");
+                chars.extend(text.chars().filter(|c| !c.is_control()));
+            }
+        }
+    }
+    chars
+}
+
+fn be_u16(data: &[u8], at: usize) -> u16 {
+    u16::from_be_bytes([data[at], data[at + 1]])
+}
+
+fn be_u32(data: &[u8], at: usize) -> u32 {
+    u32::from_be_bytes([data[at], data[at + 1], data[at + 2], data[at + 3]])
+}
+
+fn pad4(data: &mut Vec<u8>) {
+    while !data.len().is_multiple_of(4) {
+        data.push(0);
+    }
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+/// Bestandteile einer zusammengesetzten Glyphe (Buchstaben mit Akzent
+/// bestehen oft aus Grundbuchstabe und Akzent).
+fn components(glyph: &[u8]) -> Vec<u16> {
+    const ARGS_ARE_WORDS: u16 = 0x0001;
+    const HAVE_SCALE: u16 = 0x0008;
+    const MORE_COMPONENTS: u16 = 0x0020;
+    const HAVE_X_AND_Y_SCALE: u16 = 0x0040;
+    const HAVE_TWO_BY_TWO: u16 = 0x0080;
+
+    let mut found = Vec::new();
+    if glyph.len() < 10 || (be_u16(glyph, 0) as i16) >= 0 {
+        return found;
+    }
+    let mut at = 10;
+    loop {
+        let flags = be_u16(glyph, at);
+        found.push(be_u16(glyph, at + 2));
+        at += 4 + if flags & ARGS_ARE_WORDS != 0 { 4 } else { 2 };
+        at += if flags & HAVE_SCALE != 0 {
+            2
+        } else if flags & HAVE_X_AND_Y_SCALE != 0 {
+            4
+        } else if flags & HAVE_TWO_BY_TWO != 0 {
+            8
+        } else {
+            0
+        };
+        if flags & MORE_COMPONENTS == 0 {
+            return found;
+        }
+    }
+}
+
+/// cmap im Format 4 (Windows, Unicode BMP) für die behaltenen Zeichen.
+fn cmap(mapping: &BTreeMap<u16, u16>) -> Vec<u8> {
+    let mut segments: Vec<(u16, u16, u16)> = Vec::new();
+    for (&c, &glyph) in mapping {
+        let delta = glyph.wrapping_sub(c);
+        match segments.last_mut() {
+            Some((_, end, d)) if *end + 1 == c && *d == delta => *end = c,
+            _ => segments.push((c, c, delta)),
+        }
+    }
+    segments.push((0xffff, 0xffff, 1));
+
+    let seg_count = segments.len() as u16;
+    let search_range = 2 * (1u16 << (15 - seg_count.leading_zeros()));
+    let mut sub = Vec::new();
+    for value in [4, 16 + 8 * seg_count, 0, 2 * seg_count, search_range, search_range.trailing_zeros() as u16 - 1, 2 * seg_count - search_range] {
+        sub.extend(value.to_be_bytes());
+    }
+    segments.iter().for_each(|(_, end, _)| sub.extend(end.to_be_bytes()));
+    sub.extend(0u16.to_be_bytes());
+    segments.iter().for_each(|(start, _, _)| sub.extend(start.to_be_bytes()));
+    segments.iter().for_each(|(_, _, delta)| sub.extend(delta.to_be_bytes()));
+    segments.iter().for_each(|_| sub.extend(0u16.to_be_bytes()));
+
+    let mut table = Vec::new();
+    for value in [0u16, 1, 3, 1] {
+        table.extend(value.to_be_bytes());
+    }
+    table.extend(12u32.to_be_bytes());
+    table.extend(sub);
+    table
+}
+
+fn subset_font(data: &[u8], chars: &BTreeSet<char>) -> Vec<u8> {
+    let face = Face::parse(data, 0).expect("bundled font parses");
+    let table = |tag: &[u8; 4]| face.raw_face().table(Tag::from_bytes(tag)).unwrap_or_default();
+    let (head, loca, glyf) = (table(b"head"), table(b"loca"), table(b"glyf"));
+    let num_glyphs = be_u16(table(b"maxp"), 4) as usize;
+    let long_loca = be_u16(head, 50) == 1;
+    let offset = |i: usize| if long_loca { be_u32(loca, 4 * i) as usize } else { be_u16(loca, 2 * i) as usize * 2 };
+    let glyph = |i: usize| &glyf[offset(i)..offset(i + 1)];
+
+    let mapping: BTreeMap<u16, u16> = chars
+        .iter()
+        .filter(|c| (**c as u32) < 0xffff)
+        .filter_map(|c| face.glyph_index(*c).map(|g| (*c as u32 as u16, g.0)))
+        .collect();
+
+    let mut keep = vec![false; num_glyphs];
+    let mut pending: Vec<u16> = std::iter::once(0).chain(mapping.values().copied()).collect();
+    while let Some(id) = pending.pop() {
+        if !std::mem::replace(&mut keep[id as usize], true) {
+            pending.extend(components(glyph(id as usize)));
+        }
+    }
+
+    let mut new_glyf = Vec::new();
+    let mut new_loca = Vec::new();
+    for (id, kept) in keep.iter().enumerate() {
+        new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+        if *kept {
+            new_glyf.extend_from_slice(glyph(id));
+            pad4(&mut new_glyf);
+        }
+    }
+    new_loca.extend((new_glyf.len() as u32).to_be_bytes());
+
+    let mut new_head = head.to_vec();
+    new_head[8..12].fill(0);
+    new_head[50..52].copy_from_slice(&1u16.to_be_bytes());
+
+    // post Version 3: ohne Glyphennamen.
+    let mut post = table(b"post")[..32].to_vec();
+    post[..4].copy_from_slice(&0x0003_0000u32.to_be_bytes());
+
+    let mut tables: BTreeMap<[u8; 4], Vec<u8>> = KEPT_TABLES
+        .iter()
+        .filter(|tag| !table(tag).is_empty())
+        .map(|tag| (**tag, table(tag).to_vec()))
+        .collect();
+    tables.insert(*b"cmap", cmap(&mapping));
+    tables.insert(*b"glyf", new_glyf);
+    tables.insert(*b"head", new_head);
+    tables.insert(*b"loca", new_loca);
+    tables.insert(*b"post", post);
+
+    let num_tables = tables.len() as u16;
+    let search_range = 16 * (1u16 << (15 - num_tables.leading_zeros()));
+    let mut font = Vec::new();
+    font.extend(0x0001_0000u32.to_be_bytes());
+    for value in [num_tables, search_range, search_range.trailing_zeros() as u16 - 4, num_tables * 16 - search_range] {
+        font.extend(value.to_be_bytes());
+    }
+    let mut body = Vec::new();
+    let body_start = 12 + 16 * tables.len();
+    let mut head_at = 0;
+    for (tag, data) in &tables {
+        if tag == b"head" {
+            head_at = body_start + body.len();
+        }
+        font.extend(tag);
+        font.extend(checksum(data).to_be_bytes());
+        font.extend(((body_start + body.len()) as u32).to_be_bytes());
+        font.extend((data.len() as u32).to_be_bytes());
+        body.extend(data);
+        pad4(&mut body);
+    }
+    font.extend(body);
+    let adjustment = 0xb1b0_afbau32.wrapping_sub(checksum(&font));
+    font[head_at + 8..head_at + 12].copy_from_slice(&adjustment.to_be_bytes());
+    font
 }
@@ -25,7 +25,7 @@ pub fn is_fish_category(category: &str) -> bool {
     category_lower.contains("seafood")
 }
 
-/// Check if a category represents beef/cattle
+/// Check if a category represents beef/cattle (veal included)
 pub fn is_beef_category(category: &str) -> bool {
     let category_lower = category.to_lowercase();
 
@@ -33,12 +33,154 @@ pub fn is_beef_category(category: &str) -> bool {
     category_lower == "rind" ||
     category_lower == "rindfleisch" ||
     category_lower.contains("rind") ||
+    category_lower.contains("kalb") ||
     category_lower.contains("beef") ||
+    category_lower.contains("veal") ||
     category_lower.contains("cattle")
 }
 
+/// Check if a category represents pork
+pub fn is_pork_category(category: &str) -> bool {
+    let category_lower = category.to_lowercase();
+
+    category_lower == "schwein" ||
+    category_lower.contains("schwein") ||
+    category_lower.contains("pork")
+}
+
+/// Check if a category represents poultry
+pub fn is_poultry_category(category: &str) -> bool {
+    let category_lower = category.to_lowercase();
+
+    category_lower == "geflügel" ||
+    category_lower.contains("geflügel") ||
+    category_lower.contains("huhn") ||
+    category_lower.contains("poulet") ||
+    category_lower.contains("truthahn") ||
+    category_lower.contains("trute") ||
+
+    // English terms
+    category_lower.contains("poultry") ||
+    category_lower.contains("chicken") ||
+    category_lower.contains("turkey") ||
+    category_lower.contains("duck") ||
+    category_lower.contains("goose")
+}
+
+/// Milch und Milchprodukte, die eine Tierart im Namen tragen («Schafmilch»,
+/// «Ziegenkäse»). Sie sind keine Fleischkategorien.
+fn is_named_milk_product(category: &str) -> bool {
+    let category_lower = category.to_lowercase();
+
+    // «Milchlamm», «Milchkalb» und «Milchzicklein» sind Fleisch von Jungtieren
+    let suckling = ["milchlamm", "milchkalb", "milchzicklein", "milchgitzi", "milchferkel"];
+    if suckling.iter().any(|term| category_lower.contains(term)) {
+        return false;
+    }
+
+    is_dairy_category(category) ||
+    category_lower.contains("milch") ||
+    category_lower.contains("käse") ||
+    category_lower.contains("joghurt") ||
+    category_lower.contains("jogurt") ||
+    category_lower.contains("quark") ||
+    category_lower.contains("molke") ||
+    category_lower.contains("rahm") ||
+    category_lower.contains("lait") ||
+    category_lower.contains("fromage") ||
+    category_lower.contains("latte") ||
+    category_lower.contains("formaggio")
+}
+
+/// Check if a category represents sheep/lamb
+pub fn is_sheep_category(category: &str) -> bool {
+    if is_named_milk_product(category) {
+        return false;
+    }
+    let category_lower = category.to_lowercase();
+
+    category_lower == "lamm, schaf" ||
+    category_lower.contains("schaf") ||
+    category_lower.contains("lamm") ||
+    category_lower.contains("sheep") ||
+    category_lower.contains("lamb") ||
+    category_lower.contains("mutton")
+}
+
+/// Check if a category represents goat
+pub fn is_goat_category(category: &str) -> bool {
+    if is_named_milk_product(category) {
+        return false;
+    }
+    let category_lower = category.to_lowercase();
+
+    category_lower == "ziege" ||
+    category_lower.contains("ziege") ||
+    category_lower.contains("zicklein") ||
+    category_lower.contains("gitzi") ||
+    category_lower.contains("goat")
+}
+
+/// Eine Zeile der Tierarten-Tabelle für Fleisch: woran die Kategorie erkannt
+/// wird und wie die Herkunftsangaben auf der Etikette erscheinen.
+#[derive(Debug)]
+pub struct MeatOriginSpec {
+    matches: fn(&str) -> bool,
+    /// Bezeichnung in den Validierungsmeldungen («Rindfleisch»).
+    pub name_key: &'static str,
+    /// Etikettentext für den Ort, an dem das Tier überwiegend gelebt hat.
+    pub rearing_key: &'static str,
+    /// Etikettentext für den Schlachtort.
+    pub slaughter_key: &'static str,
+}
+
+/// Die Tierarten-Tabelle. Aufzucht- und Schlachtort sind für alle Arten
+/// Pflicht; beim Rind heisst die Angabe auf der Etikette «Geburtsort», bei
+/// den übrigen Arten «Aufgezogen in». Bei kombinierten Kategorien
+/// («Kalb; Lamm, Schaf; Rind; …») gewinnt die erste passende Zeile.
+pub const MEAT_SPECIES: &[MeatOriginSpec] = &[
+    MeatOriginSpec {
+        matches: is_beef_category,
+        name_key: "meat_species.rind",
+        rearing_key: "origin.birthplace",
+        slaughter_key: "origin.slaughtered_in",
+    },
+    MeatOriginSpec {
+        matches: is_pork_category,
+        name_key: "meat_species.schwein",
+        rearing_key: "origin.reared_in",
+        slaughter_key: "origin.slaughtered_in",
+    },
+    MeatOriginSpec {
+        matches: is_poultry_category,
+        name_key: "meat_species.gefluegel",
+        rearing_key: "origin.reared_in",
+        slaughter_key: "origin.slaughtered_in",
+    },
+    MeatOriginSpec {
+        matches: is_sheep_category,
+        name_key: "meat_species.schaf",
+        rearing_key: "origin.reared_in",
+        slaughter_key: "origin.slaughtered_in",
+    },
+    MeatOriginSpec {
+        matches: is_goat_category,
+        name_key: "meat_species.ziege",
+        rearing_key: "origin.reared_in",
+        slaughter_key: "origin.slaughtered_in",
+    },
+];
+
+/// Tierart der Kategorie, sofern sie in der Tabelle steht.
+pub fn meat_species(category: &str) -> Option<&'static MeatOriginSpec> {
+    MEAT_SPECIES.iter().find(|spec| (spec.matches)(category))
+}
+
 /// Check if a category represents meat
 pub fn is_meat_category(category: &str) -> bool {
+    if is_named_milk_product(category) {
+        return false;
+    }
     let category_lower = category.to_lowercase();
 
     // Official BLV API categories for meat products
@@ -65,6 +207,7 @@ pub fn is_meat_category(category: &str) -> bool {
     category_lower.contains("lamm") ||
     category_lower.contains("schaf") ||
     category_lower.contains("wild") ||
+    category_lower.contains("ziege") ||
 
     // Generic meat terms (fallback)
     category_lower.contains("fleisch") ||
@@ -77,6 +220,7 @@ pub fn is_meat_category(category: &str) -> bool {
     category_lower.contains("veal") ||
     category_lower.contains("lamb") ||
     category_lower.contains("mutton") ||
+    category_lower.contains("goat") ||
     category_lower.contains("chicken") ||
     category_lower.contains("poultry") ||
     category_lower.contains("turkey") ||
@@ -219,6 +363,20 @@ pub fn is_plant_category(category: &str) -> bool {
     category_lower.contains("plant")
 }
 
+/// Check if a category represents spices or herbs (BLV «Kräuter» and «Salz,
+/// Gewürze und Aromen»), the only ones that may carry a collective name
+pub fn is_spice_or_herb_category(category: &str) -> bool {
+    let category_lower = category.to_lowercase();
+
+    category_lower.contains("gewürz") ||
+    category_lower.contains("kräuter") ||
+    category_lower.contains("épice") ||
+    category_lower.contains("herbes aromatiques") ||
+    // English keywords
+    category_lower.contains("spice") ||
+    category_lower.contains("herb")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -253,6 +411,29 @@ mod tests {
         assert!(!is_beef_category("Geflügel"));
     }
 
+    #[test]
+    fn test_meat_species_table() {
+        assert_eq!(meat_species("Schwein").map(|s| s.name_key), Some("meat_species.schwein"));
+        assert_eq!(meat_species("Geflügel").map(|s| s.name_key), Some("meat_species.gefluegel"));
+        assert_eq!(meat_species("Lamm, Schaf").map(|s| s.name_key), Some("meat_species.schaf"));
+        assert_eq!(meat_species("Kalb").map(|s| s.name_key), Some("meat_species.rind"));
+        assert_eq!(meat_species("Ziege").map(|s| s.name_key), Some("meat_species.ziege"));
+        assert!(meat_species("Wild").is_none());
+        assert!(meat_species("Hühnerei").is_none());
+        assert!(meat_species("Getreide").is_none());
+    }
+
+    #[test]
+    fn test_sheep_and_goat_dairy_is_not_meat() {
+        for category in ["Schafmilch", "Ziegenkäse", "Ziegenmilch", "Schafjoghurt", "Fromage de chèvre"] {
+            assert!(meat_species(category).is_none(), "{category}");
+            assert!(!is_meat_category(category), "{category}");
+        }
+        assert!(is_sheep_category("Lammfleisch"));
+        assert!(is_goat_category("Gitzi"));
+        assert!(is_sheep_category("Milchlamm"));
+    }
+
     #[test]
     fn test_plant_category_detection() {
         assert!(is_plant_category("Getreide"));
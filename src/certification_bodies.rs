@@ -0,0 +1,123 @@
+//! Register der Bio-Kontrollstellen.
+//!
+//! Die Codes folgen dem Schema der EU-Öko-Verordnung, das die Schweiz
+//! übernimmt: Ländercode, Begriff für «biologisch» in der Landessprache und
+//! die Nummer der Kontrollstelle («CH-BIO-006», «DE-ÖKO-006», «FR-BIO-01»).
+//! `certification_bodies.csv` führt die angebotenen Stellen mit Namen und den
+//! Konfigurationen, für die sie zertifizieren dürfen (Knospe nur mit
+//! Anerkennung durch Bio Suisse). Für Bio sind unbekannte Stellen zulässig,
+//! solange der Code dem Schema ihres Landes entspricht; für die Knospe muss die
+//! Stelle im Register stehen.
+
+use crate::shared::Configuration;
+use serde::Deserialize;
+
+/// Code-Schema pro Land: Begriff für «biologisch» und Anzahl Ziffern.
+const SCHEMES: &[(&str, &str, usize)] = &[
+    ("CH", "BIO", 3),
+    ("DE", "ÖKO", 3),
+    ("AT", "BIO", 3),
+    ("FR", "BIO", 2),
+    ("IT", "BIO", 3),
+    ("NL", "BIO", 2),
+    ("BE", "BIO", 2),
+];
+
+/// Eine Zeile aus `certification_bodies.csv`.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct CertificationBody {
+    pub code: String,
+    pub name: String,
+    konfigurationen: String,
+}
+
+impl CertificationBody {
+    pub fn certifies(&self, configuration: Configuration) -> bool {
+        let key = match configuration {
+            Configuration::Bio => "bio",
+            Configuration::Knospe => "knospe",
+            Configuration::Conventional => return false,
+        };
+        self.konfigurationen.split('|').any(|k| k.trim() == key)
+    }
+
+    /// «CH-BIO-006 (bio.inspecta AG)», wie in der Auswahl.
+    pub fn label(&self) -> String {
+        format!("{} ({})", self.code, self.name)
+    }
+}
+
+/// Lädt das Register aus der eingebetteten CSV
+pub fn load_certification_bodies() -> Vec<CertificationBody> {
+    let csv_data = include_str!("certification_bodies.csv");
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv_data.as_bytes());
+
+    rdr.deserialize()
+        .filter_map(|r| match r {
+            Ok(body) => Some(body),
+            Err(e) => {
+                tracing::warn!("Failed to parse certification body CSV record: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Der Code am Anfang der Eingabe; ältere Formulare speichern den ganzen
+/// Auswahltext «CH-BIO-006 (bio.inspecta AG)».
+pub fn code_of(value: &str) -> &str {
+    value.split_whitespace().next().unwrap_or_default()
+}
+
+/// Das erwartete Format für das Land des Codes, z.B. «DE-ÖKO-xxx». Ohne
+/// bekanntes Land das Schweizer Format.
+pub fn expected_format(code: &str) -> String {
+    let country = code.split('-').next().unwrap_or_default().to_uppercase();
+    let (country, term, digits) = SCHEMES
+        .iter()
+        .find(|(c, _, _)| *c == country)
+        .copied()
+        .unwrap_or(SCHEMES[0]);
+    format!("{}-{}-{}", country, term, "x".repeat(digits))
+}
+
+/// Entspricht der Code dem Schema seines Landes?
+pub fn is_well_formed(code: &str) -> bool {
+    let parts: Vec<&str> = code.split('-').collect();
+    let [country, term, number] = parts.as_slice() else {
+        return false;
+    };
+    SCHEMES.iter().any(|(c, t, digits)| {
+        c == country
+            && t == term
+            && number.chars().count() == *digits
+            && number.chars().all(|ch| ch.is_ascii_digit())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_parses_completely() {
+        let csv_rows = include_str!("certification_bodies.csv").lines().skip(1).filter(|l| !l.is_empty()).count();
+        let registry = load_certification_bodies();
+        assert_eq!(registry.len(), csv_rows);
+        assert!(registry.iter().all(|b| is_well_formed(&b.code)), "every registered code follows its scheme");
+    }
+
+    #[test]
+    fn codes_follow_their_country_scheme() {
+        assert!(is_well_formed("CH-BIO-006"));
+        assert!(is_well_formed("DE-ÖKO-006"));
+        assert!(is_well_formed("FR-BIO-01"));
+        assert!(!is_well_formed("DE-BIO-006"));
+        assert!(!is_well_formed("FR-BIO-001"));
+        assert!(!is_well_formed("CH-BIO-6"));
+        assert_eq!(expected_format("DE-OKO-6"), "DE-ÖKO-xxx");
+        assert_eq!(expected_format("BIO-123"), "CH-BIO-xxx");
+    }
+}
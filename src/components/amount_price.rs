@@ -14,6 +14,22 @@ pub enum AmountType {
     Volume,
 }
 
+impl AmountType {
+    /// Reference quantity of the unit price: per 100 g/ml for small units,
+    /// per kg/l otherwise.
+    pub fn base_factor(&self, weight_unit: &str, volume_unit: &str) -> usize {
+        match (self, weight_unit, volume_unit) {
+            (AmountType::Weight, "mg", _) => 100_usize,
+            (AmountType::Weight, "g", _) => 100_usize,
+            (AmountType::Weight, "kg", _) => 1_usize,
+            (AmountType::Volume, _, "ml") => 100_usize,
+            (AmountType::Volume, _, "cl") => 100_usize,
+            (AmountType::Volume, _, "l") => 1_usize,
+            (_, _, _) => 1_usize,
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Serialize, Deserialize, Debug)]
 pub enum Amount {
     Single(Option<usize>),
@@ -27,6 +43,11 @@ impl Amount {
             Amount::Double(v1, v2) => (v1, v2),
         }
     }
+
+    /// The net amount (first field), zero when not entered.
+    pub fn net(self) -> usize {
+        self.get_value_tuple().0.unwrap_or(0)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -42,6 +63,64 @@ impl Price {
             Price::Double(v1, v2) => (v1, v2),
         }
     }
+
+    /// Amount implied by unit and total price.
+    pub fn calculated_amount(self, base_factor: usize) -> Option<usize> {
+        match self {
+            Price::Double(Some(unit_price), Some(total_price)) => {
+                Some(((total_price as f64 / unit_price as f64) * base_factor as f64) as usize)
+            }
+            _ => None,
+        }
+    }
+
+    /// Total price from the unit price and the net amount.
+    pub fn calculated_total(self, amount: Amount, base_factor: usize) -> Option<usize> {
+        let net_amount = amount.net();
+        if net_amount == 0 {
+            return None;
+        }
+        match self {
+            Price::Double(Some(unit_price), None) | Price::Single(Some(unit_price)) => {
+                Some((unit_price as f64 * (net_amount as f64 / base_factor as f64)) as usize)
+            }
+            _ => None,
+        }
+    }
+
+    /// Unit price from the total price and the net amount.
+    pub fn calculated_unit_price(self, amount: Amount, base_factor: usize) -> Option<usize> {
+        let net_amount = amount.net();
+        if net_amount == 0 {
+            return None;
+        }
+        match self {
+            Price::Double(_, Some(total_price)) => {
+                Some((total_price as f64 / (net_amount as f64 / base_factor as f64)) as usize)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Price in francs with two decimals.
+pub fn display_money_exact(cents: Option<usize>) -> String {
+    match cents {
+        None => String::new(),
+        Some(x) => format!("{:.2}", x as f64 / 100.0)
+    }
+}
+
+/// Price in francs, rounded to 5 Rappen as paid at the till.
+pub fn display_money_rounded(cents: Option<usize>) -> String {
+    match cents {
+        None => String::new(),
+        Some(x) => {
+            // Round to nearest 5 Rappen (5 cents)
+            let rounded_cents = ((x as f64 / 5.0).round() * 5.0) as usize;
+            format!("{:.2}", rounded_cents as f64 / 100.0)
+        }
+    }
 }
 
 impl Default for Price {
@@ -1,12 +1,31 @@
+use crate::certification_bodies::load_certification_bodies;
+use crate::shared::Configuration;
 use dioxus::prelude::*;
 use rust_i18n::t;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct CertificationBodySelectProps {
     pub bound_value: Signal<String>,
+    pub configuration: Signal<Configuration>,
 }
 
+/// Kontrollstellen aus dem Register, die für die Konfiguration zertifizieren
+/// dürfen, nach Land gruppiert.
 pub fn CertificationBodySelect(mut props: CertificationBodySelectProps) -> Element {
+    let groups = use_memo(move || {
+        let configuration = (props.configuration)();
+        let mut groups: Vec<(String, Vec<(String, String)>)> = Vec::new();
+        for body in load_certification_bodies().into_iter().filter(|b| b.certifies(configuration)) {
+            let country = body.code.split('-').next().unwrap_or_default().to_string();
+            let entry = (body.code.clone(), body.label());
+            match groups.iter_mut().find(|(c, _)| *c == country) {
+                Some((_, bodies)) => bodies.push(entry),
+                None => groups.push((country, vec![entry])),
+            }
+        }
+        groups
+    });
+
     rsx! {
         select {
             class: "select select-bordered w-full",
@@ -16,10 +35,13 @@ pub fn CertificationBodySelect(mut props: CertificationBodySelectProps) -> Eleme
                 props.bound_value.set(e.value());
             },
             option { value: "", {t!("certification_body.please_select").to_string()} }
-            option { value: "CH-BIO-006", {t!("certification_body.bio_006").to_string()} }
-            option { value: "CH-BIO-086", {t!("certification_body.bio_086").to_string()} }
-            option { value: "CH-BIO-038", {t!("certification_body.bio_038").to_string()} }
-            option { value: "CH-BIO-004", {t!("certification_body.bio_004").to_string()} }
+            for (country, bodies) in groups() {
+                optgroup { key: "{country}", label: "{country}",
+                    for (code, label) in bodies {
+                        option { key: "{code}", value: "{code}", selected: *props.bound_value.read() == code, "{label}" }
+                    }
+                }
+            }
         }
     }
-}
\ No newline at end of file
+}
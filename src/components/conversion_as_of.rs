@@ -0,0 +1,20 @@
+use crate::shared::ProductionDate;
+use dioxus::prelude::*;
+use rust_i18n::t;
+
+/// Names the production date against which a conversion end is judged, so it is
+/// clear why the Umstellung wording is (still) on the label.
+#[component]
+pub fn ConversionAsOf() -> Element {
+    let ProductionDate(production_date) = use_context::<ProductionDate>();
+    let date = chrono::NaiveDate::parse_from_str(&production_date.read(), "%Y-%m-%d")
+        .map(|d| d.format("%d.%m.%Y").to_string())
+        .unwrap_or_default();
+    rsx! {
+        if !date.is_empty() {
+            p { class: "text-xs text-base-content/60 mt-1",
+                {t!("umstellbetrieb.as_of", date = date).to_string()}
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::components::*;
 use crate::components::ingredient_path::{IngredientPath, descendant_definitions};
-use crate::core::{Ingredient, AmountUnit};
+use crate::core::{AmountUnit, CollectiveName, CompoundExemption, Ingredient, OriginAggregation};
+use crate::fish_origin::{FaoArea, FishProduction, FishingGear};
+use crate::production_methods::{EggHousing, PerformanceEnhancers};
+use crate::mountain_designation::MountainProvenance;
 use crate::model::{db_knows_non_agricultural, declaration_name, food_db, lookup_allergen, lookup_agricultural, Country};
 use crate::rules::RuleDef;
 use crate::services::UnifiedIngredient;
@@ -98,6 +101,17 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
     let mut edit_is_namensgebend = use_signal(|| {
         original_ingredient.is_namensgebend.unwrap_or(false)
     });
+    let mut edit_is_processing_aid = use_signal(|| original_ingredient.is_processing_aid());
+    let mut edit_reconstitution_factor = use_signal(|| original_ingredient.reconstitution_factor);
+    let mut edit_collective_name = use_signal(|| original_ingredient.collective_name);
+    let mut edit_compound_exemption = use_signal(|| original_ingredient.compound_exemption);
+    let mut edit_egg_housing = use_signal(|| original_ingredient.egg_housing);
+    let mut edit_performance_enhancers = use_signal(|| original_ingredient.performance_enhancers);
+    let mut edit_mountain_provenance = use_signal(|| original_ingredient.mountain_provenance);
+    let mut edit_supplier_certificate = use_signal(|| original_ingredient.supplier_certificate.clone());
+    let mut edit_umstellung_bis = use_signal(|| original_ingredient.umstellung_bis.clone());
+    let mut edit_fish_origin = use_signal(|| original_ingredient.fish_origin.clone());
+    let mut edit_origin_aggregation = use_signal(|| original_ingredient.origin_aggregation);
     let mut edit_children = use_signal(|| original_ingredient.children.clone());
     let mut edit_category = use_signal(|| original_ingredient.category.clone());
     // Canonical food_db name when the ingredient name is a curated alias term.
@@ -217,6 +231,17 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
             processing_steps: edit_processing_steps(),
             aus_umstellbetrieb: Some(edit_aus_umstellbetrieb()),
             override_children: None,
+            is_processing_aid: edit_is_processing_aid().then_some(true),
+            reconstitution_factor: edit_reconstitution_factor(),
+            collective_name: edit_collective_name(),
+            compound_exemption: edit_compound_exemption(),
+            egg_housing: edit_egg_housing(),
+            performance_enhancers: edit_performance_enhancers(),
+            mountain_provenance: edit_mountain_provenance(),
+            supplier_certificate: edit_supplier_certificate(),
+            umstellung_bis: edit_umstellung_bis().filter(|_| edit_aus_umstellbetrieb()),
+            fish_origin: edit_fish_origin(),
+            origin_aggregation: edit_origin_aggregation(),
             canonical,
         }
     };
@@ -246,6 +271,17 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
             processing_steps: original_ingredient.processing_steps.clone(),
             aus_umstellbetrieb: original_ingredient.aus_umstellbetrieb,
             override_children: None,
+            is_processing_aid: original_ingredient.is_processing_aid,
+            reconstitution_factor: original_ingredient.reconstitution_factor,
+            collective_name: original_ingredient.collective_name,
+            compound_exemption: original_ingredient.compound_exemption,
+            egg_housing: original_ingredient.egg_housing,
+            performance_enhancers: original_ingredient.performance_enhancers,
+            mountain_provenance: original_ingredient.mountain_provenance,
+            supplier_certificate: original_ingredient.supplier_certificate.clone(),
+            umstellung_bis: original_ingredient.umstellung_bis.clone(),
+            fish_origin: original_ingredient.fish_origin.clone(),
+            origin_aggregation: original_ingredient.origin_aggregation,
             canonical: original_ingredient.canonical.clone(),
         }]
     });
@@ -263,6 +299,17 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
             ing.unit = edit_unit();
             ing.is_allergen = is_allergen_custom();
             ing.is_namensgebend = Some(edit_is_namensgebend());
+            ing.is_processing_aid = edit_is_processing_aid().then_some(true);
+            ing.reconstitution_factor = edit_reconstitution_factor();
+            ing.collective_name = edit_collective_name();
+            ing.compound_exemption = edit_compound_exemption();
+            ing.egg_housing = edit_egg_housing();
+            ing.performance_enhancers = edit_performance_enhancers();
+            ing.mountain_provenance = edit_mountain_provenance();
+            ing.supplier_certificate = edit_supplier_certificate();
+            ing.umstellung_bis = edit_umstellung_bis();
+            ing.fish_origin = edit_fish_origin();
+            ing.origin_aggregation = edit_origin_aggregation();
             ing.children = live_children;
             ing.origins = edit_origins();
             ing.is_bio = Some(edit_is_bio());
@@ -338,6 +385,17 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
             let children = edit_children();
             let allergen = is_allergen_custom();
             let namensgebend = edit_is_namensgebend();
+            let processing_aid = edit_is_processing_aid();
+            let reconstitution_factor = edit_reconstitution_factor();
+            let collective_name = edit_collective_name();
+            let compound_exemption = edit_compound_exemption();
+            let egg_housing = edit_egg_housing();
+            let performance_enhancers = edit_performance_enhancers();
+            let mountain_provenance = edit_mountain_provenance();
+            let supplier_certificate = edit_supplier_certificate();
+            let umstellung_bis = edit_umstellung_bis().filter(|_| edit_aus_umstellbetrieb());
+            let fish_origin = edit_fish_origin();
+            let origin_aggregation = edit_origin_aggregation();
             let category = edit_category();
             let origins = edit_origins();
             let aufzucht_ort = edit_aufzucht_ort();
@@ -379,6 +437,17 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                 processing_steps,
                 aus_umstellbetrieb: Some(aus_umstellbetrieb),
                 override_children: None,
+                is_processing_aid: processing_aid.then_some(true),
+                reconstitution_factor,
+                collective_name,
+                compound_exemption,
+                egg_housing,
+                performance_enhancers,
+                mountain_provenance,
+                supplier_certificate,
+                umstellung_bis,
+                fish_origin,
+                origin_aggregation,
                 canonical,
             };
 
@@ -496,6 +565,17 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
             edit_is_composite.set(true);
             edit_children.set(saved.children.clone());
             edit_is_namensgebend.set(saved.is_namensgebend.unwrap_or(false));
+            edit_is_processing_aid.set(saved.is_processing_aid());
+            edit_reconstitution_factor.set(saved.reconstitution_factor);
+            edit_collective_name.set(saved.collective_name);
+            edit_compound_exemption.set(saved.compound_exemption);
+            edit_egg_housing.set(saved.egg_housing);
+            edit_performance_enhancers.set(saved.performance_enhancers);
+            edit_mountain_provenance.set(saved.mountain_provenance);
+            edit_supplier_certificate.set(saved.supplier_certificate.clone());
+            edit_umstellung_bis.set(saved.umstellung_bis.clone());
+            edit_fish_origin.set(saved.fish_origin);
+            edit_origin_aggregation.set(saved.origin_aggregation);
             edit_unit.set(saved.unit.clone());
             edit_origins.set(saved.origins.clone());
             is_allergen_custom.set(saved.is_allergen);
@@ -591,6 +671,17 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
         edit_unit.set(AmountUnit::default());
         edit_is_composite.set(false);
         edit_is_namensgebend.set(false);
+        edit_is_processing_aid.set(false);
+        edit_reconstitution_factor.set(None);
+        edit_collective_name.set(None);
+        edit_compound_exemption.set(None);
+        edit_egg_housing.set(None);
+        edit_performance_enhancers.set(None);
+        edit_mountain_provenance.set(None);
+        edit_supplier_certificate.set(None);
+        edit_umstellung_bis.set(None);
+        edit_fish_origin.set(None);
+        edit_origin_aggregation.set(None);
         edit_children.set(None);
         is_allergen_custom.set(false);
         edit_category.set(None);
@@ -638,6 +729,17 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                     processing_steps: None,
                     aus_umstellbetrieb: None,
                     override_children: None,
+                    is_processing_aid: None,
+                    reconstitution_factor: None,
+                    collective_name: None,
+                    compound_exemption: None,
+                    egg_housing: None,
+                    performance_enhancers: None,
+                    mountain_provenance: None,
+                    supplier_certificate: None,
+                    umstellung_bis: None,
+                    fish_origin: None,
+                    origin_aggregation: None,
                     canonical: None,
                 };
             }
@@ -672,6 +774,17 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
         edit_unit.set(orig.unit.clone());
         edit_is_composite.set(orig.children.as_ref().is_some_and(|c: &Vec<Ingredient>| !c.is_empty()));
         edit_is_namensgebend.set(orig.is_namensgebend.unwrap_or(false));
+        edit_is_processing_aid.set(orig.is_processing_aid());
+        edit_reconstitution_factor.set(orig.reconstitution_factor);
+        edit_collective_name.set(orig.collective_name);
+        edit_compound_exemption.set(orig.compound_exemption);
+        edit_egg_housing.set(orig.egg_housing);
+        edit_performance_enhancers.set(orig.performance_enhancers);
+        edit_mountain_provenance.set(orig.mountain_provenance);
+        edit_supplier_certificate.set(orig.supplier_certificate.clone());
+        edit_umstellung_bis.set(orig.umstellung_bis.clone());
+        edit_fish_origin.set(orig.fish_origin);
+        edit_origin_aggregation.set(orig.origin_aggregation);
         edit_children.set(orig.children.clone());
         is_allergen_custom.set(orig.is_allergen);
         edit_category.set(orig.category.clone());
@@ -716,7 +829,7 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
     let origin_locked_ch = use_memo(move || {
         is_knospe_config()
             && edit_is_bio()
-            && edit_origins().as_ref().is_some_and(|o| o.contains(&Country::CH))
+            && edit_origins().as_ref().is_some_and(|o| o.iter().any(Country::is_swiss))
     });
 
     // Does this composite have at least one child carrying a weight? When it does,
@@ -750,6 +863,32 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
     // Focus trigger signal (use provided or create a dummy)
     let focus_signal = props.focus_trigger.unwrap_or_else(|| use_signal(|| false));
 
+    // Aggregated origin («EU», «diverse Länder») once more than one country
+    // is picked; empty = follow the label-wide choice.
+    let origin_aggregation_field = move || rsx! {
+        if edit_origins.read().as_ref().is_some_and(|o| o.len() > 1) {
+            FormField {
+                label: t!("label.herkunftZusammenfassen").to_string(),
+                help: Some(t!("help.herkunftZusammenfassen").to_string()),
+                select {
+                    class: "select select-bordered w-full",
+                    onchange: move |evt| {
+                        edit_origin_aggregation.set(match evt.data.value().as_str() {
+                            "laender" => Some(OriginAggregation::Laender),
+                            "eu_nicht_eu" => Some(OriginAggregation::EuNichtEu),
+                            "diverse_laender" => Some(OriginAggregation::DiverseLaender),
+                            _ => None,
+                        });
+                    },
+                    option { value: "", selected: edit_origin_aggregation().is_none(), {t!("origin_aggregation.wie_etikette").to_string()} }
+                    option { value: "laender", selected: edit_origin_aggregation() == Some(OriginAggregation::Laender), {t!(OriginAggregation::Laender.translation_key()).to_string()} }
+                    option { value: "eu_nicht_eu", selected: edit_origin_aggregation() == Some(OriginAggregation::EuNichtEu), {t!(OriginAggregation::EuNichtEu.translation_key()).to_string()} }
+                    option { value: "diverse_laender", selected: edit_origin_aggregation() == Some(OriginAggregation::DiverseLaender), {t!(OriginAggregation::DiverseLaender.translation_key()).to_string()} }
+                }
+            }
+        }
+    };
+
     // Herkunft field for leaf ingredients. Rendered in one of two spots — directly
     // under the Knospe variant cards (Knospe quality, Testing 25.06.2026) or at the
     // classic position further down — so it lives in a closure; exactly one call
@@ -780,6 +919,7 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                 }
             }
         }
+        {origin_aggregation_field()}
     };
 
     rsx! {
@@ -831,6 +971,12 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                             edit_aufzucht_ort.set(None);
                             edit_schlachtungs_ort.set(None);
                             edit_fangort.set(None);
+                            edit_fish_origin.set(None);
+                            edit_egg_housing.set(None);
+                            edit_performance_enhancers.set(None);
+                            edit_mountain_provenance.set(None);
+                            edit_supplier_certificate.set(None);
+                            edit_umstellung_bis.set(None);
                             edit_erlaubte_ausnahme_bio.set(false);
                             edit_erlaubte_ausnahme_bio_details.set(String::new());
                             edit_erlaubte_ausnahme_knospe.set(false);
@@ -1072,7 +1218,7 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                         if matches!(variant, "knospe_ch" | "umstellung_ch") {
                             edit_origins.set(Some(vec![Country::CH]));
                         } else {
-                            let keep = edit_origins().filter(|o| !o.is_empty() && !o.contains(&Country::CH));
+                            let keep = edit_origins().filter(|o| !o.is_empty() && !o.iter().any(Country::is_swiss));
                             edit_origins.set(keep.or(Some(vec![Country::Import])));
                         }
                     };
@@ -1161,7 +1307,7 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                                                             }
                                                         }
                                                     } else {
-                                                        let origins_have_ch = edit_origins().as_ref().is_some_and(|o| o.contains(&Country::CH));
+                                                        let origins_have_ch = edit_origins().as_ref().is_some_and(|o| o.iter().any(Country::is_swiss));
                                                         let comp_variant = match (origins_have_ch, edit_aus_umstellbetrieb()) {
                                                             (true, false) => "knospe_ch",
                                                             (false, false) => "knospe_import",
@@ -1239,6 +1385,32 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                     }
                 }
 
+                // AP2.1 exemption: declare the composite without its components
+                // (allergens and additives still surface on the label).
+                br {}
+                FormField {
+                    label: t!("label.zusammensetzungWeglassen").to_string(),
+                    help: Some(t!("help.zusammensetzungWeglassen").to_string()),
+                    ValidationDisplay {
+                        paths: vec![
+                            format!("ingredients[{}][compound_exemption]", validation_index)
+                        ],
+                        select {
+                            class: "select select-bordered w-full",
+                            onchange: move |evt| {
+                                edit_compound_exemption.set(match evt.data.value().as_str() {
+                                    "under_two_percent" => Some(CompoundExemption::UnderTwoPercent),
+                                    "standardised" => Some(CompoundExemption::Standardised),
+                                    _ => None,
+                                });
+                            },
+                            option { value: "", selected: edit_compound_exemption().is_none(), {t!("compound_exemption.none").to_string()} }
+                            option { value: "under_two_percent", selected: edit_compound_exemption() == Some(CompoundExemption::UnderTwoPercent), {t!(CompoundExemption::UnderTwoPercent.translation_key()).to_string()} }
+                            option { value: "standardised", selected: edit_compound_exemption() == Some(CompoundExemption::Standardised), {t!(CompoundExemption::Standardised.translation_key()).to_string()} }
+                        }
+                    }
+                }
+
                 // Herkunft — editable on the composite itself, UNLESS a sub-ingredient
                 // already declares an origin (single-level rule). When locked, the
                 // greyed control shows the children's origins (change them on the
@@ -1271,6 +1443,9 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                                 }
                             }
                         }
+                        if !locked {
+                            {origin_aggregation_field()}
+                        }
                     }
                 }
             } else {
@@ -1336,6 +1511,54 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
 
                 br {}
 
+                // AP1.5: concentrated/dehydrated ingredients rank by reconstituted weight.
+                if use_context::<VerdictsContext>().0().reconstitution_input {
+                    FormField {
+                        label: t!("label.rekonstitutionsfaktor").to_string(),
+                        help: Some(t!("help.rekonstitutionsfaktor").to_string()),
+                        input {
+                            r#type: "number",
+                            placeholder: "1",
+                            class: "input input-bordered w-full",
+                            min: "1",
+                            step: "any",
+                            oninput: move |evt| {
+                                let value = evt.data.value();
+                                edit_reconstitution_factor.set(value.parse::<f64>().ok().filter(|f| *f > 1.0));
+                            },
+                            value: edit_reconstitution_factor().map_or(String::new(), |v| v.to_string()),
+                        }
+                    }
+                    br {}
+                }
+
+                // AP1.6: spices/herbs of at most 2% under a collective name.
+                if use_context::<VerdictsContext>().0().freie_reihenfolge_input {
+                    FormField {
+                        label: t!("label.sammelbezeichnung").to_string(),
+                        help: Some(t!("help.sammelbezeichnung").to_string()),
+                        ValidationDisplay {
+                            paths: vec![
+                                format!("ingredients[{}][collective_name]", validation_index)
+                            ],
+                            select {
+                                class: "select select-bordered w-full",
+                                onchange: move |evt| {
+                                    edit_collective_name.set(match evt.data.value().as_str() {
+                                        "gewuerze" => Some(CollectiveName::Gewuerze),
+                                        "kraeuter" => Some(CollectiveName::Kraeuter),
+                                        _ => None,
+                                    });
+                                },
+                                option { value: "", selected: edit_collective_name().is_none(), {t!("collective_names.keine").to_string()} }
+                                option { value: "gewuerze", selected: edit_collective_name() == Some(CollectiveName::Gewuerze), {t!("collective_names.gewuerze").to_string()} }
+                                option { value: "kraeuter", selected: edit_collective_name() == Some(CollectiveName::Kraeuter), {t!("collective_names.kraeuter").to_string()} }
+                            }
+                        }
+                    }
+                    br {}
+                }
+
                 // Allergen status
                 if !edit_name().is_empty() {
                     FormField {
@@ -1363,6 +1586,14 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                     }
                 }
             }
+            FormField {
+                help: Some(t!("help.verarbeitungshilfsstoff").to_string()),
+                label: t!("label.verarbeitungshilfsstoff").to_string(),
+                inline_checkbox: true,
+                CheckboxInput {
+                    bound_value: edit_is_processing_aid
+                }
+            }
             // Save status
             if let Some(status) = save_status() {
                 div { class: "alert alert-info mb-4",
@@ -1385,7 +1616,7 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                         // Derive current bio category from signals. Knospe splits into
                         // CH-Knospe ("knospe") and "knospe_import" by whether the origin
                         // is Swiss — quality is the bio flag, origin is the discriminator.
-                        let origins_have_ch = edit_origins().as_ref().is_some_and(|o| o.contains(&Country::CH));
+                        let origins_have_ch = edit_origins().as_ref().is_some_and(|o| o.iter().any(Country::is_swiss));
                         // Top-level quality. Variante b: every Knospe variant collapses to
                         // "knospe"; Swiss/Import and Umstellung are picked via the logo row.
                         let bio_cat = if edit_is_bio() { "knospe" }
@@ -1428,7 +1659,7 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                             if matches!(variant, "knospe_ch" | "umstellung_ch") {
                                 edit_origins.set(Some(vec![Country::CH]));
                             } else {
-                                let keep = edit_origins().filter(|o| !o.is_empty() && !o.contains(&Country::CH));
+                                let keep = edit_origins().filter(|o| !o.is_empty() && !o.iter().any(Country::is_swiss));
                                 edit_origins.set(keep.or(Some(vec![Country::Import])));
                             }
                         };
@@ -1775,7 +2006,7 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                             label: t!("label.verarbeitungsschritte").to_string(),
                             help: Some(t!("help.verarbeitungsschritte").to_string()),
                             div { class: "flex flex-col gap-2",
-                                for step in available_steps.read().iter().filter(|s| s.step_de != "aus zertifizierter Wildsammlung") {
+                                for step in available_steps.read().iter().filter(|s| s.step_de != crate::processing_service::WILDSAMMLUNG_STEP) {
                                     label { class: "flex items-center gap-2 cursor-pointer",
                                         input {
                                             r#type: "checkbox",
@@ -1802,6 +2033,9 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                                             }
                                         }
                                         span { "{step.step_de}" }
+                                        if step.zulassung == crate::processing_service::Zulassung::Bewilligungspflichtig {
+                                            span { class: "badge badge-warning badge-sm", {t!("processing_steps.bewilligungspflichtig").to_string()} }
+                                        }
                                     }
                                 }
                             }
@@ -1819,22 +2053,18 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
             }
             br {}
             {
-                // Beef fields
-                let should_show_beef_fields = use_memo(move || {
+                // Meat origin fields for the species in the species table
+                let should_show_meat_fields = use_memo(move || {
                     let rules = props.rules.read();
-                    let has_beef_rule = rules.contains(&RuleDef::AP7_4_RindfleischHerkunftDetails);
-                    if has_beef_rule {
-                        if let Some(category) = &edit_category() {
-                            crate::category_service::is_beef_category(category)
-                        } else {
-                            false
-                        }
+                    let has_meat_rule = rules.contains(&RuleDef::AP7_4_FleischHerkunftDetails);
+                    if has_meat_rule {
+                        edit_category().as_deref().and_then(crate::category_service::meat_species).is_some()
                     } else {
                         false
                     }
                 });
 
-                if should_show_beef_fields() {
+                if should_show_meat_fields() {
                     rsx! {
                         FormField {
                             label: t!("origin.aufzucht").to_string(),
@@ -1890,18 +2120,121 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                 });
 
                 if should_show_fish_field() {
+                    let fish_origin = edit_fish_origin().unwrap_or_default();
+                    let production = fish_origin.production;
+                    // Country for inland fishing and aquaculture; the FAO area
+                    // replaces it for sea fishing.
+                    let show_country = production != Some(FishProduction::Meeresfang);
+                    let country_label = if production == Some(FishProduction::Aquakultur) {
+                        t!("origin.aquakultur_land").to_string()
+                    } else {
+                        t!("origin.fangort").to_string()
+                    };
                     rsx! {
                         FormField {
-                            label: t!("origin.fangort").to_string(),
+                            label: t!("origin.fish_production").to_string(),
+                            help: Some(t!("help.fish_production").to_string()),
                             ValidationDisplay {
                                 paths: vec![
-                                    format!("ingredients[{}][fangort]", validation_index)
+                                    format!("ingredients[{}][fish_production]", validation_index)
                                 ],
-                                CountrySelect {
-                                    value: edit_fangort.read().clone(),
-                                    include_all_countries: true,
-                                    onchange: move |country| {
-                                        edit_fangort.set(country);
+                                select {
+                                    class: "select select-bordered w-full",
+                                    onchange: move |evt| {
+                                        let mut fish_origin = edit_fish_origin().unwrap_or_default();
+                                        fish_origin.production = FishProduction::ALL
+                                            .into_iter()
+                                            .find(|p| p.translation_key() == evt.data.value());
+                                        edit_fish_origin.set(Some(fish_origin));
+                                    },
+                                    option { value: "", selected: production.is_none(), {t!("fish_production.none").to_string()} }
+                                    for p in FishProduction::ALL {
+                                        option { value: p.translation_key(), selected: production == Some(p), {t!(p.translation_key()).to_string()} }
+                                    }
+                                }
+                            }
+                        }
+                        if production == Some(FishProduction::Meeresfang) {
+                            FormField {
+                                label: t!("origin.fao_area").to_string(),
+                                ValidationDisplay {
+                                    paths: vec![
+                                        format!("ingredients[{}][fao_area]", validation_index)
+                                    ],
+                                    select {
+                                        class: "select select-bordered w-full",
+                                        onchange: move |evt| {
+                                            let mut fish_origin = edit_fish_origin().unwrap_or_default();
+                                            fish_origin.fao_area = evt.data.value().parse().ok().and_then(FaoArea::from_code);
+                                            edit_fish_origin.set(Some(fish_origin));
+                                        },
+                                        option { value: "", selected: fish_origin.fao_area.is_none(), {t!("fish_origin.fao_area_none").to_string()} }
+                                        for area in FaoArea::ALL {
+                                            option { value: "{area.code()}", selected: fish_origin.fao_area == Some(area), {area.label()} }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if production == Some(FishProduction::Binnenfischerei) {
+                            FormField {
+                                label: t!("origin.water_body").to_string(),
+                                ValidationDisplay {
+                                    paths: vec![
+                                        format!("ingredients[{}][water_body]", validation_index)
+                                    ],
+                                    input {
+                                        class: "input input-bordered w-full",
+                                        r#type: "text",
+                                        placeholder: t!("origin.water_body_placeholder").to_string(),
+                                        value: fish_origin.water_body.clone().unwrap_or_default(),
+                                        oninput: move |evt| {
+                                            let mut fish_origin = edit_fish_origin().unwrap_or_default();
+                                            let value = evt.data.value();
+                                            fish_origin.water_body = (!value.is_empty()).then_some(value);
+                                            edit_fish_origin.set(Some(fish_origin));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if show_country {
+                            FormField {
+                                label: country_label,
+                                ValidationDisplay {
+                                    paths: vec![
+                                        format!("ingredients[{}][fangort]", validation_index)
+                                    ],
+                                    CountrySelect {
+                                        value: edit_fangort.read().clone(),
+                                        include_all_countries: true,
+                                        onchange: move |country| {
+                                            edit_fangort.set(country);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if production.is_some_and(|p| p.is_wild_catch()) {
+                            FormField {
+                                label: t!("origin.fishing_gear").to_string(),
+                                ValidationDisplay {
+                                    paths: vec![
+                                        format!("ingredients[{}][fishing_gear]", validation_index)
+                                    ],
+                                    select {
+                                        class: "select select-bordered w-full",
+                                        onchange: move |evt| {
+                                            let mut fish_origin = edit_fish_origin().unwrap_or_default();
+                                            fish_origin.gear = FishingGear::ALL
+                                                .into_iter()
+                                                .find(|g| g.translation_key() == evt.data.value());
+                                            edit_fish_origin.set(Some(fish_origin));
+                                        },
+                                        option { value: "", selected: fish_origin.gear.is_none(), {t!("fishing_gear.none").to_string()} }
+                                        for gear in FishingGear::ALL {
+                                            option { value: gear.translation_key(), selected: fish_origin.gear == Some(gear), {t!(gear.translation_key()).to_string()} }
+                                        }
                                     }
                                 }
                             }
@@ -1911,6 +2244,155 @@ pub fn IngredientPane(props: IngredientPaneProps) -> Element {
                     rsx! {}
                 }
             }
+            {
+                // LDV: production methods not permitted in Switzerland (eggs
+                // from cage housing, meat with performance enhancers).
+                let ldv_category = use_memo(move || {
+                    if props.rules.read().contains(&RuleDef::LDV_NichtZugelasseneProduktionsmethoden) {
+                        edit_category()
+                    } else {
+                        None
+                    }
+                });
+                let show_egg_housing = ldv_category().is_some_and(|c| crate::category_service::is_egg_category(&c));
+                let show_performance_enhancers = ldv_category().is_some_and(|c| crate::category_service::is_meat_category(&c));
+
+                rsx! {
+                    if show_egg_housing {
+                        FormField {
+                            label: t!("label.eggHousing").to_string(),
+                            help: Some(t!("help.eggHousing").to_string()),
+                            ValidationDisplay {
+                                paths: vec![
+                                    format!("ingredients[{}][egg_housing]", validation_index)
+                                ],
+                                select {
+                                    class: "select select-bordered w-full",
+                                    onchange: move |evt| {
+                                        edit_egg_housing.set(
+                                            EggHousing::ALL.into_iter().find(|h| h.translation_key() == evt.data.value())
+                                        );
+                                    },
+                                    option { value: "", selected: edit_egg_housing().is_none(), {t!("egg_housing.none").to_string()} }
+                                    for housing in EggHousing::ALL {
+                                        option { value: housing.translation_key(), selected: edit_egg_housing() == Some(housing), {t!(housing.translation_key()).to_string()} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if show_performance_enhancers {
+                        FormField {
+                            label: t!("label.performanceEnhancers").to_string(),
+                            help: Some(t!("help.performanceEnhancers").to_string()),
+                            ValidationDisplay {
+                                paths: vec![
+                                    format!("ingredients[{}][performance_enhancers]", validation_index)
+                                ],
+                                select {
+                                    class: "select select-bordered w-full",
+                                    onchange: move |evt| {
+                                        edit_performance_enhancers.set(
+                                            PerformanceEnhancers::ALL.into_iter().find(|p| p.translation_key() == evt.data.value())
+                                        );
+                                    },
+                                    option { value: "", selected: edit_performance_enhancers().is_none(), {t!("performance_enhancers.none").to_string()} }
+                                    for enhancers in PerformanceEnhancers::ALL {
+                                        option { value: enhancers.translation_key(), selected: edit_performance_enhancers() == Some(enhancers), {t!(enhancers.translation_key()).to_string()} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            // BAlV: mountain / summering area provenance backs «Berg» / «Alp»
+            // in the title; only agricultural raw materials count.
+            if props.rules.read().contains(&RuleDef::BAlV_BergAlpBezeichnung) && !edit_nicht_landwirtschaftlich() {
+                FormField {
+                    label: t!("label.mountainProvenance").to_string(),
+                    help: Some(t!("help.mountainProvenance").to_string()),
+                    select {
+                        class: "select select-bordered w-full",
+                        onchange: move |evt| {
+                            edit_mountain_provenance.set(
+                                MountainProvenance::ALL.into_iter().find(|p| p.translation_key() == evt.data.value())
+                            );
+                        },
+                        option { value: "", selected: edit_mountain_provenance().is_none(), {t!("mountain_provenance.none").to_string()} }
+                        for provenance in MountainProvenance::ALL {
+                            option { value: provenance.translation_key(), selected: edit_mountain_provenance() == Some(provenance), {t!(provenance.translation_key()).to_string()} }
+                        }
+                    }
+                }
+            }
+            // Conversion end: from that day on the ingredient counts as fully
+            // organic and the Umstellung wording disappears by itself.
+            if edit_aus_umstellbetrieb() {
+                FormField {
+                    label: t!("label.umstellungBis").to_string(),
+                    help: Some(t!("help.umstellungBis").to_string()),
+                    input {
+                        r#type: "date",
+                        class: "input input-bordered w-full",
+                        value: edit_umstellung_bis().unwrap_or_default(),
+                        oninput: move |evt| {
+                            edit_umstellung_bis.set(Some(evt.data.value()).filter(|d| !d.is_empty()));
+                        }
+                    }
+                    ConversionAsOf {}
+                }
+            }
+            // The supplier certificate backs the Bio/Knospe claim; it has to be
+            // valid until the date on the label.
+            if props.rules.read().contains(&RuleDef::Bio_Knospe_LieferantenZertifikat)
+                && ((is_knospe_config() && edit_is_bio()) || (!is_knospe_config() && edit_bio_ch()))
+            {
+                FormField {
+                    label: t!("label.supplierCertificate").to_string(),
+                    help: Some(t!("help.supplierCertificate").to_string()),
+                    ValidationDisplay {
+                        paths: vec![
+                            format!("ingredients[{}][supplier_certificate]", validation_index)
+                        ],
+                        div { class: "grid grid-cols-1 md:grid-cols-3 gap-2",
+                            input {
+                                r#type: "text",
+                                class: "input input-bordered w-full",
+                                placeholder: t!("placeholder.supplier").to_string(),
+                                value: edit_supplier_certificate().map(|c| c.supplier).unwrap_or_default(),
+                                oninput: move |evt| {
+                                    let mut certificate = edit_supplier_certificate().unwrap_or_default();
+                                    certificate.supplier = evt.data.value();
+                                    edit_supplier_certificate.set(Some(certificate).filter(|c| !c.is_empty()));
+                                }
+                            }
+                            input {
+                                r#type: "text",
+                                class: "input input-bordered w-full",
+                                placeholder: t!("placeholder.certificateNumber").to_string(),
+                                value: edit_supplier_certificate().map(|c| c.number).unwrap_or_default(),
+                                oninput: move |evt| {
+                                    let mut certificate = edit_supplier_certificate().unwrap_or_default();
+                                    certificate.number = evt.data.value();
+                                    edit_supplier_certificate.set(Some(certificate).filter(|c| !c.is_empty()));
+                                }
+                            }
+                            input {
+                                r#type: "date",
+                                class: "input input-bordered w-full",
+                                title: t!("label.certificateValidUntil").to_string(),
+                                value: edit_supplier_certificate().map(|c| c.valid_until).unwrap_or_default(),
+                                oninput: move |evt| {
+                                    let mut certificate = edit_supplier_certificate().unwrap_or_default();
+                                    certificate.valid_until = evt.data.value();
+                                    edit_supplier_certificate.set(Some(certificate).filter(|c| !c.is_empty()));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
             // Wildsammlung sits at the very bottom of the modal (Testing 25.06.2026,
             // hand note 2) — relevant for the Knospe quality and, with its own
             // wording, for the Bio-V «Bio» quality (DEC-11).
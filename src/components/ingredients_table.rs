@@ -2,7 +2,7 @@ use crate::components::card_stack::{CardStack, GenesisModal};
 use crate::shared::VerdictsContext;
 use crate::components::ingredient_path::IngredientPath;
 use crate::components::*;
-use crate::core::Ingredient;
+use crate::core::{Ingredient, OriginAggregation, OriginStyle};
 use crate::rules::RuleDef;
 use dioxus::prelude::*;
 use rust_i18n::t;
@@ -12,6 +12,9 @@ use std::collections::HashMap;
 pub struct IngredientsTableProps {
     ingredients: Signal<Vec<Ingredient>>,
     manual_total: Signal<Option<f64>>,
+    freie_reihenfolge: Signal<bool>,
+    origin_aggregation: Signal<OriginAggregation>,
+    origin_style: Signal<OriginStyle>,
     validation_messages: Memo<HashMap<String, Vec<String>>>,
     rules: Memo<Vec<RuleDef>>,
     rezeptur_vollstaendig: Signal<bool>,
@@ -65,6 +68,18 @@ pub fn IngredientsTable(mut props: IngredientsTableProps) -> Element {
     });
 
     let show_knospe_icon = props.rules.read().contains(&RuleDef::Knospe_ShowBioSuisseLogo);
+    // AP1.6: with free order below 2% the recipe order is what the label
+    // prints for the tail, so the rows become movable.
+    let freie_reihenfolge_input = use_context::<VerdictsContext>().0().freie_reihenfolge_input;
+    let show_reorder = freie_reihenfolge_input && (props.freie_reihenfolge)();
+    // The label-wide origin aggregation only matters once an ingredient
+    // comes from more than one country.
+    let has_multiple_origins = props.ingredients.read().iter().any(|i| {
+        std::iter::once(i)
+            .chain(i.ingredient_leaves())
+            .any(|x| x.origins.as_ref().is_some_and(|o| o.len() > 1))
+    });
+    let has_origins = props.ingredients.read().iter().any(|i| i.computed_origins().is_some_and(|o| !o.is_empty()));
 
     rsx! {
         div { class: "grid grid-cols-3 gap-4 items-center border-top",
@@ -83,7 +98,7 @@ pub fn IngredientsTable(mut props: IngredientsTableProps) -> Element {
                 0,
                 &editing_path,
                 props.ingredients,
-                show_knospe_icon,
+                TreeOptions { show_knospe_icon, show_reorder },
                 None,
             )}
 
@@ -118,6 +133,54 @@ pub fn IngredientsTable(mut props: IngredientsTableProps) -> Element {
                         div {}
                     }
             }
+            if props.ingredients.len() > 0 && freie_reihenfolge_input {
+                FormField {
+                    label: t!("label.freieReihenfolge").to_string(),
+                    help: Some(t!("help.freieReihenfolge").to_string()),
+                    inline_checkbox: true,
+                    CheckboxInput {
+                        bound_value: props.freie_reihenfolge
+                    }
+                }
+            }
+            if has_origins {
+                FormField {
+                    label: t!("label.herkunftSchreibweise").to_string(),
+                    help: Some(t!("help.herkunftSchreibweise").to_string()),
+                    select {
+                        class: "select select-bordered w-full",
+                        onchange: move |evt| {
+                            props.origin_style.set(match evt.data.value().as_str() {
+                                "short" => OriginStyle::Short,
+                                "full" => OriginStyle::Full,
+                                _ => OriginStyle::Code,
+                            });
+                        },
+                        option { value: "code", selected: (props.origin_style)() == OriginStyle::Code, {t!(OriginStyle::Code.translation_key()).to_string()} }
+                        option { value: "short", selected: (props.origin_style)() == OriginStyle::Short, {t!(OriginStyle::Short.translation_key()).to_string()} }
+                        option { value: "full", selected: (props.origin_style)() == OriginStyle::Full, {t!(OriginStyle::Full.translation_key()).to_string()} }
+                    }
+                }
+            }
+            if has_multiple_origins {
+                FormField {
+                    label: t!("label.herkunftZusammenfassen").to_string(),
+                    help: Some(t!("help.herkunftZusammenfassenEtikette").to_string()),
+                    select {
+                        class: "select select-bordered w-full",
+                        onchange: move |evt| {
+                            props.origin_aggregation.set(match evt.data.value().as_str() {
+                                "eu_nicht_eu" => OriginAggregation::EuNichtEu,
+                                "diverse_laender" => OriginAggregation::DiverseLaender,
+                                _ => OriginAggregation::Laender,
+                            });
+                        },
+                        option { value: "laender", selected: (props.origin_aggregation)() == OriginAggregation::Laender, {t!(OriginAggregation::Laender.translation_key()).to_string()} }
+                        option { value: "eu_nicht_eu", selected: (props.origin_aggregation)() == OriginAggregation::EuNichtEu, {t!(OriginAggregation::EuNichtEu.translation_key()).to_string()} }
+                        option { value: "diverse_laender", selected: (props.origin_aggregation)() == OriginAggregation::DiverseLaender, {t!(OriginAggregation::DiverseLaender.translation_key()).to_string()} }
+                    }
+                }
+            }
         }
         if props.ingredients.len() > 0 {
             {
@@ -200,6 +263,14 @@ pub fn IngredientsTable(mut props: IngredientsTableProps) -> Element {
     }
 }
 
+/// Display switches that hold for the whole tree.
+#[derive(Clone, Copy)]
+struct TreeOptions {
+    show_knospe_icon: bool,
+    /// AP1.6: move buttons on the top-level rows.
+    show_reorder: bool,
+}
+
 /// Recursively render the ingredient tree with indentation.
 fn render_ingredient_tree(
     ingredients: &[Ingredient],
@@ -207,13 +278,15 @@ fn render_ingredient_tree(
     depth: usize,
     editing_path: &Signal<IngredientPath>,
     root_ingredients: Signal<Vec<Ingredient>>,
-    show_knospe_icon: bool,
+    options: TreeOptions,
     // Some(is_ch) when an ancestor composite claims the quality — children show
     // the inherited Knospe desaturated ("Pastellfarben", Testing 25.06.2026).
     inherited_knospe: Option<bool>,
 ) -> Element {
     use crate::model::Country;
 
+    let TreeOptions { show_knospe_icon, show_reorder } = options;
+    let count = ingredients.len();
     let elements: Vec<Element> = ingredients.iter().enumerate()
         .map(|(i, ingr)| {
             let full_path: IngredientPath = {
@@ -238,7 +311,7 @@ fn render_ingredient_tree(
             let knospe_variant: Option<bool> = if show_knospe_icon && ingr.computed_bio_status().unwrap_or(false) {
                 computed_origins.as_ref()
                     .filter(|o| !o.is_empty())
-                    .map(|o| o.contains(&Country::CH))
+                    .map(|o| o.iter().any(Country::is_swiss))
             } else {
                 None
             };
@@ -312,6 +385,32 @@ fn render_ingredient_tree(
                                 },
                                 icons::ListDetail {}
                             }
+                            if show_reorder {
+                                button {
+                                    class: "btn btn-outline join-item",
+                                    title: t!("label.nachOben").to_string(),
+                                    disabled: i == 0,
+                                    onclick: {
+                                        let mut root_ingredients = root_ingredients;
+                                        move |_| {
+                                            root_ingredients.write().swap(i - 1, i);
+                                        }
+                                    },
+                                    "↑"
+                                }
+                                button {
+                                    class: "btn btn-outline join-item",
+                                    title: t!("label.nachUnten").to_string(),
+                                    disabled: i + 1 == count,
+                                    onclick: {
+                                        let mut root_ingredients = root_ingredients;
+                                        move |_| {
+                                            root_ingredients.write().swap(i, i + 1);
+                                        }
+                                    },
+                                    "↓"
+                                }
+                            }
                             if depth == 0 {
                                 button {
                                     class: "btn btn-outline join-item",
@@ -337,7 +436,7 @@ fn render_ingredient_tree(
                             depth + 1,
                             &editing_path_signal,
                             root_ingredients,
-                            show_knospe_icon,
+                            TreeOptions { show_reorder: false, ..options },
                             knospe_variant.or(inherited_knospe),
                         )}
                     }
@@ -1,8 +1,8 @@
-use crate::components::{Amount, AmountType, Price};
+use crate::components::{display_money_exact, display_money_rounded, Amount, AmountType, Price};
 use crate::components::icons::{BioSuisseRegular, BioSuisseNoCross, UmstellungsknospeSatzRegular, UmstellungsknospeSatzImport};
 use crate::layout::DisclaimerContext;
 use crate::shared::VerdictsContext;
-use crate::verdicts::{BioBlockReason, BioVerdict, CheckState, KnospeBlockReason, KnospeVerdict};
+use crate::verdicts::{BioBlockReason, BioVerdict, CheckState, KnospeBlockReason, KnospeVerdict, MountainBlockReason, MountainVerdict, SwissnessBlockReason, SwissnessVerdict};
 use crate::nl2br::Nl2Br;
 use dioxus::prelude::*;
 use rust_i18n::t;
@@ -72,24 +72,6 @@ pub fn LabelPreview(
     calculated_unit_price: Option<Memo<(bool, usize)>>,
     calculated_total_price: Option<Memo<(bool, usize)>>,
 ) -> Element {
-    fn display_money_exact(cents: Option<usize>) -> String {
-        match cents {
-            None => String::new(),
-            Some(x) => format!("{:.2}", x as f64 / 100.0)
-        }
-    }
-
-    fn display_money_rounded(cents: Option<usize>) -> String {
-        match cents {
-            None => String::new(),
-            Some(x) => {
-                // Round to nearest 5 Rappen (5 cents)
-                let rounded_cents = ((x as f64 / 5.0).round() * 5.0) as usize;
-                format!("{:.2}", rounded_cents as f64 / 100.0)
-            }
-        }
-    }
-
     let address_combined: Memo<String> = use_memo(move || {
         let parts = vec![producer_name(), producer_address(), {
             let zip = producer_zip();
@@ -122,19 +104,7 @@ pub fn LabelPreview(
     });
 
     let get_base_factor = use_memo(move || {
-        match (
-            &*amount_type.read(),
-            weight_unit.read().as_str(),
-            volume_unit.read().as_str(),
-        ) {
-            (AmountType::Weight, "mg", _) => 100_usize,
-            (AmountType::Weight, "g", _) => 100_usize,
-            (AmountType::Weight, "kg", _) => 1_usize,
-            (AmountType::Volume, _, "ml") => 100_usize,
-            (AmountType::Volume, _, "cl") => 100_usize,
-            (AmountType::Volume, _, "l") => 1_usize,
-            (_, _, _) => 1_usize,
-        }
+        amount_type.read().base_factor(&weight_unit.read(), &volume_unit.read())
     });
 
     let get_base_factor_and_unit = use_memo(move || match get_base_factor() {
@@ -218,14 +188,22 @@ pub fn LabelPreview(
                             let suffix_allowed = matches!(v.bio, Some(BioVerdict::Allowed { .. }))
                                 || matches!(v.knospe, Some(KnospeVerdict::Logo { bio_suffix: true, .. }));
                             let bio_suffix = if suffix_allowed { t!("preview.bio_suffix").to_string() } else { String::new() };
+                            // AP3.1: «mit Süssungsmittel(n)» belongs to the
+                            // Sachbezeichnung, not to the warnings further down.
+                            let sachbezeichnung_zusatz: String = v
+                                .mandatory_warnings
+                                .iter()
+                                .filter(|w| w.kind.belongs_to_sachbezeichnung())
+                                .map(|w| format!(" {}", w.text()))
+                                .collect();
                             if !(*product_title.read()).is_empty() {
                                 rsx! {
                                     h3 { class: "text-2xl", "{product_title}" }
-                                    span { class: "mb-1 text-base", "{product_subtitle}{bio_suffix}" }
+                                    span { class: "mb-1 text-base", "{product_subtitle}{bio_suffix}{sachbezeichnung_zusatz}" }
                                 }
                             } else {
                                 rsx! {
-                                    h3 { class: "text-2xl mb-1", "{product_subtitle}{bio_suffix}" }
+                                    h3 { class: "text-2xl mb-1", "{product_subtitle}{bio_suffix}{sachbezeichnung_zusatz}" }
                                 }
                             }
                         }
@@ -246,6 +224,80 @@ pub fn LabelPreview(
                     }
                 }
 
+                {
+                    // AP3.1: mandatory warnings triggered by ingredients (azo
+                    // dyes, aspartame, polyols, caffeine), one per line.
+                    let hinweise: Vec<String> = verdicts
+                        .0()
+                        .mandatory_warnings
+                        .iter()
+                        .filter(|w| !w.kind.belongs_to_sachbezeichnung())
+                        .map(|w| w.text())
+                        .collect();
+                    if hinweise.is_empty() {
+                        rsx! {}
+                    } else {
+                        rsx! {
+                            div { class: "py-2",
+                                for hinweis in hinweise {
+                                    div { class: "text-sm", "{hinweis}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                {
+                    // AP7.6: origin of a primary ingredient that differs from
+                    // the origin the label highlights.
+                    let statements: Vec<String> = verdicts
+                        .0()
+                        .primary_origin_statements
+                        .iter()
+                        .map(|s| s.text())
+                        .collect();
+                    if statements.is_empty() {
+                        rsx! {}
+                    } else {
+                        rsx! {
+                            div { class: "py-2",
+                                for statement in statements {
+                                    div { class: "text-sm", "{statement}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                {
+                    // LDV: eggs from cage housing, meat possibly produced with
+                    // performance enhancers.
+                    let statements: Vec<String> = verdicts
+                        .0()
+                        .production_method_statements
+                        .iter()
+                        .map(|s| s.text())
+                        .collect();
+                    if statements.is_empty() {
+                        rsx! {}
+                    } else {
+                        rsx! {
+                            div { class: "py-2",
+                                for statement in statements {
+                                    div { class: "text-sm", "{statement}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(statement) = verdicts.0().honey_origin_statement.as_ref() {
+                    // AP7.7: origin of honey sold as a mono product.
+                    div { class: "py-2",
+                        div { class: "text-sm", "{statement.text()}" }
+                    }
+                }
+
                 if date_prefix() != t!("label.keinDatum") {
                     div {
                         class: "py-2 grid grid-cols-1 gap-4",
@@ -459,6 +511,36 @@ pub fn LabelPreview(
                 {
                     let v = verdicts.0();
                     let alternative_marking = v.alternative_marking_allowed;
+                    // Swissness: one hint for the verdict, one per reason.
+                    let swissness_reasons: Vec<String> = match &v.swissness {
+                        Some(SwissnessVerdict::NotAllowed { reasons, .. }) => reasons
+                            .iter()
+                            .map(|reason| match reason {
+                                SwissnessBlockReason::ShareBelow80 => t!("swissness_hints.share_below_80").to_string(),
+                                SwissnessBlockReason::MilkNotSwiss { ingredients } => {
+                                    t!("swissness_hints.milk_not_swiss", ingredients = ingredients.join(", ")).to_string()
+                                }
+                                SwissnessBlockReason::OriginUnknown { ingredients } => {
+                                    t!("swissness_hints.origin_unknown", ingredients = ingredients.join(", ")).to_string()
+                                }
+                                SwissnessBlockReason::NothingToAssess => t!("swissness_hints.nothing_to_assess").to_string(),
+                            })
+                            .collect(),
+                        _ => Vec::new(),
+                    };
+                    // BAlV: same shape for «Berg» / «Alp».
+                    let mountain_reasons: Vec<String> = match &v.mountain_designation {
+                        Some(MountainVerdict::NotAllowed { reasons, .. }) => reasons
+                            .iter()
+                            .map(|reason| match reason {
+                                MountainBlockReason::ShareBelow90 => t!("mountain_hints.share_below_90").to_string(),
+                                MountainBlockReason::MilkOutsideArea { ingredients } => {
+                                    t!("mountain_hints.milk_outside_area", ingredients = ingredients.join(", ")).to_string()
+                                }
+                            })
+                            .collect(),
+                        _ => Vec::new(),
+                    };
                     rsx! {
                         // Bio-V tri-state «Rezeptur prüfen».
                         match (&v.bio_check, &v.bio) {
@@ -515,6 +597,36 @@ pub fn LabelPreview(
                             },
                             (None, _) => rsx! {},
                         }
+                        // Knospe: processing outside the Bio Suisse groups needs a human look.
+                        if !v.processing_review.is_empty() {
+                            Hint { text: t!("bio_hints.processing_manual_review", ingredients = v.processing_review.join(", ")).to_string() }
+                        }
+                        // Swissness (MSchG): Schweizerkreuz / «Swiss made».
+                        match &v.swissness {
+                            Some(SwissnessVerdict::Allowed { share }) => rsx! {
+                                Hint { text: t!("swissness_hints.allowed", share = format!("{:.1}", share)).to_string() }
+                            },
+                            Some(SwissnessVerdict::NotAllowed { share, .. }) => rsx! {
+                                WarningHint { text: t!("swissness_hints.not_allowed", share = format!("{:.1}", share)).to_string() }
+                                for reason in swissness_reasons {
+                                    Hint { text: reason }
+                                }
+                            },
+                            None => rsx! {},
+                        }
+                        // BAlV: «Berg» / «Alp» in the title.
+                        match &v.mountain_designation {
+                            Some(MountainVerdict::Allowed { designation, share }) => rsx! {
+                                Hint { text: t!("mountain_hints.allowed", designation = t!(designation.translation_key()), share = format!("{:.1}", share)).to_string() }
+                            },
+                            Some(MountainVerdict::NotAllowed { designation, share, .. }) => rsx! {
+                                WarningHint { text: t!("mountain_hints.not_allowed", designation = t!(designation.translation_key()), share = format!("{:.1}", share)).to_string() }
+                                for reason in mountain_reasons {
+                                    Hint { text: reason }
+                                }
+                            },
+                            None => rsx! {},
+                        }
                     }
                 }
             }
@@ -1,3 +1,4 @@
+pub use conversion_as_of::ConversionAsOf;
 pub use country_select::CountrySelect;
 pub use multi_country_select::MultiCountrySelect;
 pub use date_input::DateInput;
@@ -23,6 +24,7 @@ pub use link_share_modal::LinkShareModal;
 pub use saved_ingredients_manager::SavedIngredientsManager;
 pub use unified_ingredient_input::UnifiedIngredientInput;
 
+mod conversion_as_of;
 mod country_select;
 mod multi_country_select;
 mod date_input;
@@ -1,7 +1,7 @@
 use crate::components::icons::{
     BioSuisseNoCross, BioSuisseRegular, UmstellungsknospeNoCross, UmstellungsknospeRegular,
 };
-use crate::components::FormField;
+use crate::components::{ConversionAsOf, FormField};
 use crate::pages::label_page::MonoQuality;
 use crate::shared::Configuration;
 use dioxus::prelude::*;
@@ -22,6 +22,8 @@ use rust_i18n::t;
 pub struct MonoQualitySelectProps {
     pub quality: Signal<MonoQuality>,
     pub configuration: Signal<Configuration>,
+    /// End of the conversion period (YYYY-MM-DD) for the Umstellung variants.
+    pub conversion_end: Signal<String>,
 }
 
 pub fn MonoQualitySelect(mut props: MonoQualitySelectProps) -> Element {
@@ -173,6 +175,21 @@ pub fn MonoQualitySelect(mut props: MonoQualitySelectProps) -> Element {
                     }
                 }
             }
+            // Umstellung ends on a known day; from then on the label drops the
+            // Umstellung wording without being edited.
+            if current.is_umstellung() {
+                FormField {
+                    label: t!("label.umstellungBis").to_string(),
+                    help: Some(t!("help.umstellungBis").to_string()),
+                    input {
+                        r#type: "date",
+                        class: "input input-bordered w-full",
+                        value: "{props.conversion_end}",
+                        oninput: move |evt| props.conversion_end.set(evt.data.value()),
+                    }
+                    ConversionAsOf {}
+                }
+            }
         }
     }
 }
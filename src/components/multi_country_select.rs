@@ -1,4 +1,5 @@
 use crate::model::Country;
+use crate::swiss_regions::SwissRegion;
 use dioxus::prelude::*;
 use rust_i18n::t;
 
@@ -103,6 +104,17 @@ pub fn MultiCountrySelect(props: MultiCountrySelectProps) -> Element {
                     }
                 }
 
+                // Swiss cantons and regions, counted as CH
+                optgroup { label: "{t!(\"origin.swiss_regions_header\").to_string()}",
+                    for region in SwissRegion::REGIONS.into_iter().chain(SwissRegion::CANTONS) {
+                        option {
+                            key: "swiss-{region.code()}",
+                            value: "{region.code()}",
+                            {Country::Region(region).localized_name()}
+                        }
+                    }
+                }
+
                 // Common European countries
                 optgroup { label: "{t!(\"origin.countries_header\").to_string()}",
                     for country in COMMON_COUNTRIES {
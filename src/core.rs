@@ -1,7 +1,21 @@
-use crate::verdicts::{BioBlockReason, BioVerdict, CheckState, KnospeBlockReason, KnospeLogo, KnospeVerdict, Verdicts};
-use crate::model::{lookup_allergen, lookup_agricultural, Country};
+use crate::verdicts::{BioBlockReason, BioVerdict, CheckState, KnospeBlockReason, KnospeLogo, KnospeShares, KnospeVerdict, MountainVerdict, Verdicts};
+use crate::mandatory_warnings::mandatory_warnings;
+use crate::swissness::{decide_swissness, load_swissness_exclusions};
+use crate::fish_origin::{fish_origin_parts, missing_fish_details, FishOrigin};
+use crate::honey_origin::{honey_origins, is_honey, HoneyOriginStatement};
+use crate::mountain_designation::{decide_mountain_designation, MountainDesignation, MountainProvenance};
+use crate::certification_bodies::{code_of, expected_format, is_well_formed, load_certification_bodies};
+use crate::shared::Configuration;
+use crate::protected_designations::{load_protected_designations, protected_designation_in};
+use crate::processing_service::{check_steps_for_blv_category, load_processing_rules, StepIssue};
+use crate::supplier_certificate::SupplierCertificate;
+use crate::production_methods::{missing_production_method, production_method_statements, EggHousing, PerformanceEnhancers};
+use crate::origin_emphasis::{check_primary_ingredient, emphasised_country, primary_ingredients, PrimaryOriginCheck};
+use crate::model::{lookup_allergen, lookup_agricultural, Country, CountryGroup};
+use crate::swiss_regions::SwissRegion;
 use crate::rules::RuleDef;
-use crate::category_service::{is_fish_category, is_beef_category, is_meat_category, is_egg_category, is_honey_category, is_dairy_category, is_insect_category, is_plant_category};
+use crate::category_service::{is_fish_category, meat_species, is_meat_category, is_egg_category, is_honey_category, is_dairy_category, is_insect_category, is_plant_category, is_spice_or_herb_category};
+use chrono::NaiveDate;
 use rust_i18n::t;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::cmp::PartialEq;
@@ -187,7 +201,7 @@ fn parse_country_code(value: &str) -> Option<Country> {
         "VU" => Some(Country::VU), "WF" => Some(Country::WF), "WS" => Some(Country::WS),
         "YE" => Some(Country::YE), "YT" => Some(Country::YT), "ZA" => Some(Country::ZA),
         "ZM" => Some(Country::ZM), "ZW" => Some(Country::ZW),
-        _ => None,
+        _ => SwissRegion::from_code(value).map(Country::Region),
     }
 }
 
@@ -200,6 +214,27 @@ pub struct Input {
     /// «Keine Zutatenliste (Einzelzutat)» — the product has no recipe at all,
     /// so the «Rezeptur prüfen» hints must stay silent (DEC-3).
     pub ignore_ingredients: bool,
+    /// AP1.6: ingredients below 2% follow the recipe order instead of their
+    /// weight, so the producer can arrange the tail freely.
+    pub freie_reihenfolge_unter_2_prozent: bool,
+    /// Sachbezeichnung, Zusatz und Produktionsland as printed — AP7.6 reads
+    /// them for a highlighted origin.
+    pub product_title: String,
+    pub product_subtitle: String,
+    pub production_country: String,
+    /// Label-wide choice how several countries of origin are printed; an
+    /// ingredient may override it.
+    pub origin_aggregation: OriginAggregation,
+    /// Label-wide choice how countries are named: code, short or full name.
+    pub origin_style: OriginStyle,
+    /// AOP/IGP certificate (number or certification body) when the title
+    /// uses a protected designation.
+    pub protected_designation_certificate: Option<String>,
+    /// Date on the label (mindestens haltbar / zu verbrauchen bis); supplier
+    /// certificates must stay valid until then.
+    pub best_before: Option<NaiveDate>,
+    /// Day the label is produced; conversion end dates are evaluated as of it.
+    pub production_date: Option<NaiveDate>,
 }
 
 impl Input {
@@ -237,6 +272,9 @@ impl Output {
 
 pub struct Calculator {
     pub(crate) rule_defs: Vec<RuleDef>,
+    /// The page the calculator runs for; `None` for bare rule lists, which
+    /// are checked like Bio where a rule depends on it.
+    pub(crate) configuration: Option<Configuration>,
 }
 
 /// Share (in percent) of the agricultural weight that satisfies `numerator`,
@@ -247,13 +285,13 @@ pub struct Calculator {
 /// once keeps the six rules below to their actual difference — the predicates —
 /// and makes the `empty` fallback an explicit, per-rule decision rather than a
 /// detail buried in copied code.
-fn agricultural_share(
+pub(crate) fn agricultural_share(
     ingredients: &[Ingredient],
     denominator: impl Fn(&Ingredient) -> bool,
     numerator: impl Fn(&Ingredient) -> bool,
     empty: f64,
 ) -> f64 {
-    let leaves: Vec<&Ingredient> = ingredients.iter().flat_map(|i| i.leaves()).collect();
+    let leaves: Vec<&Ingredient> = ingredients.iter().flat_map(|i| i.ingredient_leaves()).collect();
 
     let total: f64 = leaves
         .iter()
@@ -279,7 +317,7 @@ fn calculate_swiss_agricultural_percentage(ingredients: &[Ingredient]) -> f64 {
     agricultural_share(
         ingredients,
         |_| true,
-        |i| i.computed_origins().is_some_and(|o| o.contains(&Country::CH)),
+        |i| i.computed_origins().is_some_and(|o| o.iter().any(Country::is_swiss)),
         0.0,
     )
 }
@@ -290,7 +328,7 @@ fn calculate_bio_swiss_agricultural_percentage(ingredients: &[Ingredient]) -> f6
     agricultural_share(
         ingredients,
         |i| i.computed_bio_status().unwrap_or(false),
-        |i| i.computed_origins().is_some_and(|o| o.contains(&Country::CH)),
+        |i| i.computed_origins().is_some_and(|o| o.iter().any(Country::is_swiss)),
         0.0,
     )
 }
@@ -352,7 +390,7 @@ fn calculate_erlaubte_ausnahme_knospe_percentage(ingredients: &[Ingredient]) ->
 fn has_erlaubte_ausnahme(ingredients: &[Ingredient]) -> bool {
     ingredients
         .iter()
-        .flat_map(|i| i.leaves())
+        .flat_map(|i| i.ingredient_leaves())
         .filter(|i| i.is_agricultural())
         .any(|i| {
             (i.erlaubte_ausnahme_bio.unwrap_or(false)
@@ -392,14 +430,14 @@ fn wildsammlung_wording(rules: &[RuleDef]) -> String {
 fn has_agricultural_ingredient(ingredients: &[Ingredient]) -> bool {
     ingredients
         .iter()
-        .flat_map(|i| i.leaves())
+        .flat_map(|i| i.ingredient_leaves())
         .any(|i| i.is_agricultural() && i.amount > 0.0)
 }
 
 /// Determines if a product is a Monoprodukt (single agricultural ingredient)
 fn is_mono_product(ingredients: &[Ingredient]) -> bool {
     ingredients.iter()
-        .flat_map(|i| i.leaves())
+        .flat_map(|i| i.ingredient_leaves())
         .filter(|i| i.is_agricultural())
         .count() == 1
 }
@@ -440,6 +478,9 @@ impl InheritedQuality {
 /// legend lines match what is printed.
 fn tree_marker_presence(ingredients: &[Ingredient]) -> (bool, bool) {
     fn walk(ing: &Ingredient, inherited: InheritedQuality, star: &mut bool, double_star: &mut bool) {
+        if !ing.is_declared() {
+            return;
+        }
         let own_bio = ing.is_bio == Some(true) || ing.bio_ch == Some(true);
         let own_umst = ing.aus_umstellbetrieb.unwrap_or(false);
         match ing.children.as_ref().filter(|c| !c.is_empty()) {
@@ -486,8 +527,8 @@ fn format_percentage(percentage: f64) -> String {
 }
 
 impl Calculator {
-    pub(crate) fn new() -> Self {
-        Calculator { rule_defs: vec![] }
+    pub(crate) fn new(configuration: Configuration) -> Self {
+        Calculator { rule_defs: vec![], configuration: Some(configuration) }
     }
 
     /// Debug logging method to display all rules as a table in browser console
@@ -587,6 +628,24 @@ pub struct Ingredient {
     pub schlachtungs_ort: Option<Country>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fangort: Option<Country>,
+    /// Fish only: production method, FAO fishing area / water body and gear.
+    /// The country (sea fishing excepted) stays in `fangort`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fish_origin: Option<FishOrigin>,
+    /// Eggs only: laying hen housing. Cage housing is not permitted in
+    /// Switzerland and calls for the LDV statement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub egg_housing: Option<EggHousing>,
+    /// Meat only: hormonal / antibiotic performance enhancers the meat may
+    /// have been produced with (LDV statement).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performance_enhancers: Option<PerformanceEnhancers>,
+    /// BAlV: raised in the mountain or summering area («Berg», «Alp»).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mountain_provenance: Option<MountainProvenance>,
+    /// Supplier and the certificate that backs the Bio/Knospe claim.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supplier_certificate: Option<SupplierCertificate>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bio_ch: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -601,8 +660,36 @@ pub struct Ingredient {
     pub processing_steps: Option<Vec<String>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub aus_umstellbetrieb: Option<bool>,
+    /// End of the farm's conversion period (YYYY-MM-DD). From that day on the
+    /// ingredient counts as fully organic, so labels need no manual re-edit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub umstellung_bis: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub override_children: Option<bool>,
+    /// Processing aid or carry-over additive (release agent, enzyme used as a
+    /// processing aid, additive carried over from a composite). It stays in the
+    /// recipe for the weight, but is not an ingredient in the legal sense: it is
+    /// left out of the printed list and of the Bio/Knospe shares.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_processing_aid: Option<bool>,
+    /// Concentrated or dehydrated ingredient that is reconstituted during
+    /// manufacture: factor from the weight used to the weight before
+    /// concentration/dehydration (100 g milk powder, factor 8 → 800 g milk).
+    /// Only moves the ingredient in the list order (AP1.5), never the totals.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reconstitution_factor: Option<f64>,
+    /// AP1.6: spice or herb declared under the collective name «Gewürze» /
+    /// «Kräuter» instead of its own name — only allowed below 2%.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collective_name: Option<CollectiveName>,
+    /// AP2.1: opt-in to declare this composite without its components. Allergens
+    /// and additives with a technological function are still listed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compound_exemption: Option<CompoundExemption>,
+    /// How several countries of origin are printed. `None` follows the
+    /// label-wide choice (`Input::origin_aggregation`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin_aggregation: Option<OriginAggregation>,
     /// Canonical food_db name when `name` is a curated alias term (e.g. name
     /// "Mehl" with canonical "Weizenmehl"). Drives allergen/agricultural/category
     /// lookups; `None` when `name` is itself the canonical entry.
@@ -635,6 +722,101 @@ impl AmountUnit {
     }
 }
 
+/// Collective names for spices and herbs below 2% each.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CollectiveName {
+    Gewuerze,
+    Kraeuter,
+}
+
+/// Why a composite may be declared without listing its components.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CompoundExemption {
+    /// Compound ingredient below 2% of the finished product whose composition
+    /// is laid down in law, or a spice/herb mixture below 2%.
+    UnderTwoPercent,
+    /// Standardised food that needs no ingredient list of its own — at any share.
+    Standardised,
+}
+
+impl CompoundExemption {
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            CompoundExemption::UnderTwoPercent => "compound_exemption.under_two_percent",
+            CompoundExemption::Standardised => "compound_exemption.standardised",
+        }
+    }
+}
+
+/// How the origins of an ingredient from several countries are printed.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OriginAggregation {
+    /// Every country by its code: «(DE, FR, IT)».
+    #[default]
+    Laender,
+    /// Grouped: «(EU)», «(Nicht-EU)», «(EU/Nicht-EU)». Switzerland stays named.
+    EuNichtEu,
+    /// «(diverse Länder)» — from three countries on, otherwise grouped.
+    DiverseLaender,
+}
+
+/// How a country is named in the origin statements on the label.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OriginStyle {
+    /// ISO code: «(CH)».
+    #[default]
+    Code,
+    /// Short name in the label's language: «(USA)», «(Schweiz)».
+    Short,
+    /// Full name in the label's language: «(Vereinigte Staaten)».
+    Full,
+}
+
+impl OriginStyle {
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            OriginStyle::Code => "origin_style.code",
+            OriginStyle::Short => "origin_style.short",
+            OriginStyle::Full => "origin_style.full",
+        }
+    }
+
+    /// The country as printed on the label.
+    /// Swiss regions are always spelled out («Emmental»); a code such as
+    /// `CH-BE` means nothing to the reader of a label.
+    pub fn name(&self, country: &Country) -> String {
+        match self {
+            _ if matches!(country, Country::Region(_)) => country.localized_name(),
+            OriginStyle::Code => country.country_code().to_string(),
+            OriginStyle::Short => country.short_name(),
+            OriginStyle::Full => country.localized_name(),
+        }
+    }
+}
+
+impl OriginAggregation {
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            OriginAggregation::Laender => "origin_aggregation.laender",
+            OriginAggregation::EuNichtEu => "origin_aggregation.eu_nicht_eu",
+            OriginAggregation::DiverseLaender => "origin_aggregation.diverse_laender",
+        }
+    }
+}
+
+impl CollectiveName {
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            CollectiveName::Gewuerze => "collective_names.gewuerze",
+            CollectiveName::Kraeuter => "collective_names.kraeuter",
+        }
+    }
+}
+
 impl Ingredient {
     pub fn from_name_amount(name: String, amount: f64) -> Self {
         Self {
@@ -659,7 +841,18 @@ impl Ingredient {
             erlaubte_ausnahme_knospe_details: None,
             processing_steps: None,
             aus_umstellbetrieb: None,
+            umstellung_bis: None,
             override_children: None,
+            is_processing_aid: None,
+            reconstitution_factor: None,
+            collective_name: None,
+            compound_exemption: None,
+            egg_housing: None,
+            performance_enhancers: None,
+            mountain_provenance: None,
+            supplier_certificate: None,
+            fish_origin: None,
+            origin_aggregation: None,
             canonical: None,
         }
     }
@@ -709,7 +902,7 @@ impl Ingredient {
     /// For composites this aggregates bottom-up: compliant iff every child is.
     pub fn is_knospe_compliant(&self) -> bool {
         if self.aggregates_quality_from_children() {
-            return self.children.as_ref().unwrap().iter().all(|c| c.is_knospe_compliant());
+            return self.quality_children().all(|c| c.is_knospe_compliant());
         }
         self.is_bio.unwrap_or(false)
             || self.erlaubte_ausnahme_bio.unwrap_or(false)
@@ -723,7 +916,7 @@ impl Ingredient {
     /// For composites this aggregates bottom-up: compliant iff every child is.
     pub fn is_bio_ch_compliant(&self) -> bool {
         if self.aggregates_quality_from_children() {
-            return self.children.as_ref().unwrap().iter().all(|c| c.is_bio_ch_compliant());
+            return self.quality_children().all(|c| c.is_bio_ch_compliant());
         }
         self.bio_ch.unwrap_or(false) && !self.aus_umstellbetrieb.unwrap_or(false)
     }
@@ -737,8 +930,11 @@ impl Ingredient {
     /// `is_bio_ch_compliant`, so a composite making its own quality claim is judged
     /// on that claim rather than on its children.
     pub fn has_undeclared_non_bio(&self) -> bool {
+        if self.is_processing_aid() {
+            return false;
+        }
         if self.aggregates_quality_from_children() {
-            return self.children.as_ref().unwrap().iter().any(|c| c.has_undeclared_non_bio());
+            return self.quality_children().any(|c| c.has_undeclared_non_bio());
         }
         self.is_agricultural()
             && !self.bio_ch.unwrap_or(false)
@@ -750,8 +946,11 @@ impl Ingredient {
         let mut name = String::new();
         name.push_str(&self.name);
         if let Some(children) = &self.children {
+            let children: Vec<Ingredient> = sort_children_by_weight(children)
+                .into_iter()
+                .filter(|c| c.is_declared())
+                .collect();
             if !children.is_empty() {
-                let children = sort_children_by_weight(children);
                 name.push_str(" (");
                 name.push_str(
                     &children
@@ -767,25 +966,49 @@ impl Ingredient {
     }
 
     pub fn composites(&self) -> String {
-        self.composites_with_rules(&[], 0.0, 0)
+        self.composites_with_rules(&[], 0.0, 0, OriginStyle::default())
     }
 
-    pub fn composites_with_rules(&self, rules: &[RuleDef], total_amount: f64, agricultural_ingredient_count: usize) -> String {
+    pub fn composites_with_rules(&self, rules: &[RuleDef], total_amount: f64, agricultural_ingredient_count: usize, origin_style: OriginStyle) -> String {
         // A quality claimed on this composite itself (bought certified unit) is
         // pushed DOWN onto the children's markers (Testing 25.06.2026) — the
         // parent name never carries `*`/`**`.
-        self.composites_with_inherited(rules, total_amount, agricultural_ingredient_count, InheritedQuality::from_parent(self))
+        self.composites_with_inherited(rules, total_amount, agricultural_ingredient_count, origin_style, InheritedQuality::from_parent(self))
     }
 
-    fn composites_with_inherited(&self, rules: &[RuleDef], total_amount: f64, agricultural_ingredient_count: usize, inherited: InheritedQuality) -> String {
+    fn composites_with_inherited(&self, rules: &[RuleDef], total_amount: f64, agricultural_ingredient_count: usize, origin_style: OriginStyle, inherited: InheritedQuality) -> String {
         let mut output = String::new();
+        if self.components_exempt(total_amount) {
+            // Exempt composite: only allergens and additives with a function
+            // surface, taken from anywhere in the subtree.
+            let surfaced: Vec<String> = self
+                .leaves()
+                .into_iter()
+                .filter(|leaf| !std::ptr::eq(*leaf, self))
+                .filter(|leaf| leaf.is_allergen || (leaf.is_additive() && !leaf.is_processing_aid()))
+                .map(|leaf| {
+                    let escaped_name = html_escape(&leaf.name);
+                    if leaf.is_allergen { format!("<b>{}</b>", escaped_name) } else { escaped_name }
+                })
+                .collect();
+            if !surfaced.is_empty() {
+                output.push_str(&format!(" ({})", surfaced.join(", ")));
+            }
+            return output;
+        }
         if let Some(children) = &self.children {
             if !children.is_empty() {
                 let has_bio_input_rule = rules.contains(&RuleDef::Bio_Knospe_EingabeIstBio)
                     || rules.contains(&RuleDef::Bio_PartialBioMarking);
                 let suppress_asterisk = rules.contains(&RuleDef::Bio_AllAgriAreBio);
 
-                let children = sort_children_by_weight(children);
+                let children: Vec<Ingredient> = sort_children_by_weight(children)
+                    .into_iter()
+                    .filter(|c| c.is_declared())
+                    .collect();
+                if children.is_empty() {
+                    return output;
+                }
                 output.push_str(" (");
                 output.push_str(
                     &children
@@ -839,7 +1062,7 @@ impl Ingredient {
                                 bio: inherited.bio || child.is_bio == Some(true) || child.bio_ch == Some(true),
                                 umstellung: inherited.umstellung || child.aus_umstellbetrieb == Some(true),
                             };
-                            base_name.push_str(&child.composites_with_inherited(rules, total_amount, agricultural_ingredient_count, child_inherited));
+                            base_name.push_str(&child.composites_with_inherited(rules, total_amount, agricultural_ingredient_count, origin_style, child_inherited));
                             // Add processing steps
                             if let Some(steps) = &child.processing_steps {
                                 if !steps.is_empty() {
@@ -850,10 +1073,10 @@ impl Ingredient {
                             // Append origin: when rules are active, respect Knospe rules;
                             // when no rules (basic composites display), always show origins.
                             if rules.is_empty() {
-                                if let Some(origin_str) = format_valid_origins(&child.origins) {
+                                if let Some(origin_str) = format_valid_origins(&child.origins, child, origin_style) {
                                     base_name = format!("{} {}", base_name, origin_str);
                                 }
-                            } else if let Some(origin_str) = format_origin_for_knospe_rules(child, rules, total_amount, agricultural_ingredient_count) {
+                            } else if let Some(origin_str) = format_origin_for_knospe_rules(child, rules, total_amount, agricultural_ingredient_count, origin_style) {
                                 base_name = format!("{} {}", base_name, origin_str);
                             }
                             base_name
@@ -919,6 +1142,30 @@ impl Ingredient {
             })
     }
 
+    /// End of the conversion period, when one is set and parses.
+    pub fn conversion_end(&self) -> Option<NaiveDate> {
+        self.umstellung_bis
+            .as_deref()
+            .and_then(|d| NaiveDate::parse_from_str(d.trim(), "%Y-%m-%d").ok())
+    }
+
+    /// The Umstellung flags as of `date`, recursively: a conversion that has
+    /// ended by then no longer counts. Without a date every conversion is still
+    /// running, as before conversion end dates existed.
+    pub fn resolve_conversion(&self, date: Option<NaiveDate>) -> Ingredient {
+        let mut resolved = self.clone();
+        let ended = date.is_some_and(|date| resolved.conversion_end().is_some_and(|end| end <= date));
+        if ended && resolved.aus_umstellbetrieb == Some(true) {
+            resolved.aus_umstellbetrieb = Some(false);
+        }
+        if let Some(children) = resolved.children.as_mut() {
+            for child in children.iter_mut() {
+                *child = child.resolve_conversion(date);
+            }
+        }
+        resolved
+    }
+
     /// Resolve percentage-mode children into absolute (gram/ml) children, recursively.
     /// A percent child's grams become `parent_total * pct / 100` in the parent's unit.
     /// The parent stays a normal bottom-up composite afterwards, so its weight equals the
@@ -974,6 +1221,67 @@ impl Ingredient {
         }
     }
 
+    /// Weight before concentration/dehydration; the plain weight when no
+    /// reconstitution factor (> 1) is set.
+    pub fn reconstituted_amount(&self) -> f64 {
+        let factor = self.reconstitution_factor.filter(|f| *f > 1.0).unwrap_or(1.0);
+        self.computed_amount() * factor
+    }
+
+    /// Zugefügtes Wasser (Trinkwasser, Mineralwasser, …) — by name or canonical.
+    pub fn is_added_water(&self) -> bool {
+        self.is_leaf()
+            && [Some(self.name.as_str()), self.canonical.as_deref()]
+                .into_iter()
+                .flatten()
+                .any(|n| {
+                    let n = n.trim().to_lowercase();
+                    n == "wasser" || n == "trinkwasser" || n == "mineralwasser"
+                })
+    }
+
+    /// Additive named by its E-number («Antioxidationsmittel E 306»). Inside an
+    /// exempt composite these still have to be declared.
+    pub fn is_additive(&self) -> bool {
+        let tokens: Vec<&str> = self
+            .name
+            .split(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | ','))
+            .filter(|t| !t.is_empty())
+            .collect();
+        tokens.iter().enumerate().any(|(i, token)| {
+            let number = match token.strip_prefix(['E', 'e']) {
+                Some("") => tokens.get(i + 1).copied().unwrap_or(""),
+                Some(rest) => rest,
+                None => return false,
+            };
+            // Sub-letters as in E160a
+            let digits = number.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+            (3..=4).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit())
+        })
+    }
+
+    /// Whether the components of this composite may stay off the label. An
+    /// `UnderTwoPercent` claim only holds while the composite is below 2%
+    /// (`validate_compound_exemptions` flags the rest).
+    pub fn components_exempt(&self, total_amount: f64) -> bool {
+        match self.compound_exemption {
+            Some(CompoundExemption::Standardised) => true,
+            Some(CompoundExemption::UnderTwoPercent) => {
+                total_amount > 0.0
+                    && calculate_ingredient_percentage(self.computed_amount(), total_amount) < FREE_ORDER_THRESHOLD
+            }
+            None => false,
+        }
+    }
+
+    /// Water and alcohol partly evaporate during manufacture, so their weight
+    /// in the finished product is not their recipe weight.
+    fn is_volatile(&self) -> bool {
+        self.is_added_water()
+            || (self.is_leaf()
+                && matches!(self.name.trim().to_lowercase().as_str(), "alkohol" | "ethanol" | "weingeist"))
+    }
+
     /// Effective unit: own unit if leaf/override; otherwise Milliliter if any
     /// child rolls up to Milliliter, else Gram. Mixing g and ml children is
     /// rare, but a single ml child should pull the parent display to ml so
@@ -996,7 +1304,7 @@ impl Ingredient {
     /// is a bottom-up attribute (weight is the top-down one).
     pub fn computed_bio_status(&self) -> Option<bool> {
         if self.aggregates_quality_from_children() {
-            let children = self.children.as_ref().unwrap();
+            let children: Vec<&Ingredient> = self.quality_children().collect();
             if children.iter().any(|c| c.computed_bio_status().is_some()) {
                 Some(children.iter().all(|c| c.computed_bio_status().unwrap_or(false)))
             } else {
@@ -1010,7 +1318,7 @@ impl Ingredient {
     /// Effective bio_ch status: same bottom-up logic as bio
     pub fn computed_bio_ch_status(&self) -> Option<bool> {
         if self.aggregates_quality_from_children() {
-            let children = self.children.as_ref().unwrap();
+            let children: Vec<&Ingredient> = self.quality_children().collect();
             if children.iter().any(|c| c.computed_bio_ch_status().is_some()) {
                 Some(children.iter().all(|c| c.computed_bio_ch_status().unwrap_or(false)))
             } else {
@@ -1055,7 +1363,7 @@ impl Ingredient {
         });
         let self_flagged = self.is_agricultural()
             && self.is_bio == Some(true)
-            && !self.origins.as_ref().is_some_and(|o| o.contains(&Country::CH))
+            && !self.origins.as_ref().is_some_and(|o| o.iter().any(Country::is_swiss))
             && !has_real_origin;
         self_flagged
             || self
@@ -1074,6 +1382,66 @@ impl Ingredient {
                 .iter().flat_map(|c| c.leaves()).collect()
         }
     }
+
+    /// Like `leaves`, minus processing aids and carry-over additives (and
+    /// everything below one). Those are not ingredients, so the Bio/Knospe
+    /// shares and the Monoprodukt count must neither credit nor blame them.
+    pub fn ingredient_leaves(&self) -> Vec<&Ingredient> {
+        if self.is_processing_aid() {
+            vec![]
+        } else if self.is_leaf() {
+            vec![self]
+        } else {
+            self.children.as_ref().unwrap()
+                .iter().flat_map(|c| c.ingredient_leaves()).collect()
+        }
+    }
+
+    pub fn is_processing_aid(&self) -> bool {
+        self.is_processing_aid.unwrap_or(false)
+    }
+
+    /// Allergen on this node or anywhere below it.
+    fn contains_allergen(&self) -> bool {
+        self.is_allergen
+            || self.children.as_ref().is_some_and(|cs| cs.iter().any(|c| c.contains_allergen()))
+    }
+
+    /// Whether this node is printed in the ingredient list. Processing aids and
+    /// carry-over additives are not — except when allergenic: allergens must be
+    /// declared even when they only reach the product as a processing aid.
+    pub fn is_declared(&self) -> bool {
+        !self.is_processing_aid() || self.contains_allergen()
+    }
+
+    /// How this ingredient's origins are printed. Meat and fish must name the
+    /// country (AP7.3/AP7.5), so their origin is never aggregated.
+    pub fn effective_origin_aggregation(&self) -> OriginAggregation {
+        let names_country = self
+            .effective_category()
+            .is_some_and(|c| is_meat_category(&c) || is_fish_category(&c));
+        if names_country {
+            OriginAggregation::Laender
+        } else {
+            self.origin_aggregation.unwrap_or_default()
+        }
+    }
+
+    /// Fill in the origin aggregation where none is chosen: the label-wide
+    /// choice at the top, a composite's own choice for its components.
+    pub fn with_origin_aggregation(mut self, inherited: OriginAggregation) -> Self {
+        let own = *self.origin_aggregation.get_or_insert(inherited);
+        if let Some(children) = self.children.take() {
+            self.children = Some(children.into_iter().map(|c| c.with_origin_aggregation(own)).collect());
+        }
+        self
+    }
+
+    /// Children that take part in the bottom-up quality aggregation: a
+    /// conventional release agent does not make a bio composite non-bio.
+    fn quality_children(&self) -> impl Iterator<Item = &Ingredient> {
+        self.children.iter().flatten().filter(|c| !c.is_processing_aid())
+    }
 }
 
 impl Default for Ingredient {
@@ -1100,7 +1468,18 @@ impl Default for Ingredient {
             erlaubte_ausnahme_knospe_details: None,
             processing_steps: None,
             aus_umstellbetrieb: None,
+            umstellung_bis: None,
             override_children: None,
+            is_processing_aid: None,
+            reconstitution_factor: None,
+            collective_name: None,
+            compound_exemption: None,
+            egg_housing: None,
+            performance_enhancers: None,
+            mountain_provenance: None,
+            supplier_certificate: None,
+            fish_origin: None,
+            origin_aggregation: None,
             canonical: None,
         }
     }
@@ -1114,7 +1493,7 @@ pub struct SubIngredient {
 }
 
 /// HTML-escape a string to prevent XSS when rendered via dangerous_inner_html.
-fn html_escape(s: &str) -> String {
+pub(crate) fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
         .replace('>', "&gt;")
@@ -1127,6 +1506,7 @@ struct OutputFormatter {
     RuleDefs: Vec<RuleDef>,
     total_amount: f64,
     agricultural_ingredient_count: usize,
+    origin_style: OriginStyle,
 }
 
 impl PartialEq for RuleDef {
@@ -1136,12 +1516,13 @@ impl PartialEq for RuleDef {
 }
 
 impl OutputFormatter {
-    pub fn from(ingredient: Ingredient, total_amount: f64, RuleDefs: Vec<RuleDef>, agricultural_ingredient_count: usize) -> Self {
+    pub fn from(ingredient: Ingredient, total_amount: f64, RuleDefs: Vec<RuleDef>, agricultural_ingredient_count: usize, origin_style: OriginStyle) -> Self {
         Self {
             ingredient,
             total_amount,
             RuleDefs,
             agricultural_ingredient_count,
+            origin_style,
         }
     }
 
@@ -1215,7 +1596,7 @@ impl OutputFormatter {
             .RuleDefs.contains(&RuleDef::AP2_1_ZusammegesetztOutput)
             && self.ingredient.children.as_ref().is_some_and(|c| !c.is_empty())
         {
-            output = format! {"{}{}", output, self.ingredient.composites_with_rules(&self.RuleDefs, self.total_amount, self.agricultural_ingredient_count)};
+            output = format! {"{}{}", output, self.ingredient.composites_with_rules(&self.RuleDefs, self.total_amount, self.agricultural_ingredient_count, self.origin_style)};
         }
         // Verarbeitungsschritte ausgeben (nach Zutatname/Subkomponenten, vor Herkunft)
         // When Wildsammlung °-marker is active, exclude it from the regular processing steps
@@ -1251,7 +1632,7 @@ impl OutputFormatter {
         // reach the label instead of being silently dropped (Testing 25.06.2026).
         if has_children {
             if has_declared_origin(&self.ingredient) {
-                if let Some(origin_str) = format_origin_for_knospe_rules(&self.ingredient, &self.RuleDefs, self.total_amount, self.agricultural_ingredient_count) {
+                if let Some(origin_str) = format_origin_for_knospe_rules(&self.ingredient, &self.RuleDefs, self.total_amount, self.agricultural_ingredient_count, self.origin_style) {
                     output = format!("{} {}", output, origin_str);
                 }
             }
@@ -1260,31 +1641,29 @@ impl OutputFormatter {
 
         if has_knospe_100_rule || has_knospe_90_99_rule || has_knospe_under90_rule {
             // Knospe origin rules — shared with composite children
-            if let Some(origin_str) = format_origin_for_knospe_rules(&self.ingredient, &self.RuleDefs, self.total_amount, self.agricultural_ingredient_count) {
+            if let Some(origin_str) = format_origin_for_knospe_rules(&self.ingredient, &self.RuleDefs, self.total_amount, self.agricultural_ingredient_count, self.origin_style) {
                 output = format!("{} {}", output, origin_str);
             }
         } else {
-            // Check for beef-specific origin display first.
-            // When beef details are rendered, they replace the standard
-            // herkunft display so we don't print "(Geburtsort: CH, …) (CH)".
-            let mut beef_details_rendered = false;
-            if self.RuleDefs.contains(&RuleDef::AP7_4_RindfleischHerkunftDetails) {
-                if let Some(category) = &self.ingredient.category {
-                    if is_beef_category(category) {
-                        let mut beef_origin_parts = Vec::new();
-
-                        if let Some(aufzucht_ort) = &self.ingredient.aufzucht_ort {
-                            beef_origin_parts.push(t!("origin.birthplace", country = aufzucht_ort.country_code()).to_string());
-                        }
+            // Meat origin details per species (rearing/slaughter place) come
+            // first. When rendered, they replace the standard herkunft display
+            // so we don't print "(Geburtsort: CH, …) (CH)".
+            let mut meat_details_rendered = false;
+            if self.RuleDefs.contains(&RuleDef::AP7_4_FleischHerkunftDetails) {
+                if let Some(spec) = self.ingredient.category.as_deref().and_then(meat_species) {
+                    let mut meat_origin_parts = Vec::new();
+
+                    if let Some(aufzucht_ort) = &self.ingredient.aufzucht_ort {
+                        meat_origin_parts.push(t!(spec.rearing_key, country = self.origin_style.name(aufzucht_ort)).to_string());
+                    }
 
-                        if let Some(schlachtungs_ort) = &self.ingredient.schlachtungs_ort {
-                            beef_origin_parts.push(t!("origin.slaughtered_in", country = schlachtungs_ort.country_code()).to_string());
-                        }
+                    if let Some(schlachtungs_ort) = &self.ingredient.schlachtungs_ort {
+                        meat_origin_parts.push(t!(spec.slaughter_key, country = self.origin_style.name(schlachtungs_ort)).to_string());
+                    }
 
-                        if !beef_origin_parts.is_empty() {
-                            output = format!("{} ({})", output, beef_origin_parts.join(", "));
-                            beef_details_rendered = true;
-                        }
+                    if !meat_origin_parts.is_empty() {
+                        output = format!("{} ({})", output, meat_origin_parts.join(", "));
+                        meat_details_rendered = true;
                     }
                 }
             }
@@ -1293,17 +1672,18 @@ impl OutputFormatter {
             if self.RuleDefs.contains(&RuleDef::AP7_5_FischFangort) {
                 if let Some(category) = &self.ingredient.category {
                     if is_fish_category(category) {
-                        if let Some(fangort) = &self.ingredient.fangort {
-                            output = format!("{} ({})", output, fangort.country_code());
+                        let parts = fish_origin_parts(&self.ingredient, self.origin_style);
+                        if !parts.is_empty() {
+                            output = format!("{} ({})", output, parts.join(", "));
                             fish_details_rendered = true;
                         }
                     }
                 }
             }
             // Add country of origin display for traditional herkunft rules
-            // (skipped when beef/fish details already rendered the origin).
-            if !beef_details_rendered && !fish_details_rendered {
-                if let Some(origin_str) = format_origin_for_knospe_rules(&self.ingredient, &self.RuleDefs, self.total_amount, self.agricultural_ingredient_count) {
+            // (skipped when meat/fish details already rendered the origin).
+            if !meat_details_rendered && !fish_details_rendered {
+                if let Some(origin_str) = format_origin_for_knospe_rules(&self.ingredient, &self.RuleDefs, self.total_amount, self.agricultural_ingredient_count, self.origin_style) {
                     output = format!("{} {}", output, origin_str);
                 }
             }
@@ -1318,14 +1698,25 @@ impl Calculator {
     }
 
     // Optional RuleRegistry integration methods
-    pub fn from_registry_config(config: crate::shared::Configuration) -> Self {
+    pub fn from_registry_config(config: Configuration) -> Self {
         use crate::rules::RuleRegistry;
         let registry = RuleRegistry::new();
         let rules = registry
             .get_rules_for_config(&config)
             .cloned()
             .unwrap_or_default();
-        Calculator { rule_defs: rules }
+        Calculator { rule_defs: rules, configuration: Some(config) }
+    }
+
+    /// Knospe and Swiss shares behind `decide_knospe`, as the recipe declaration reports them.
+    fn knospe_shares(&self, ingredients: &[Ingredient]) -> Option<KnospeShares> {
+        if !self.rule_defs.contains(&RuleDef::Knospe_ShowBioSuisseLogo) {
+            return None;
+        }
+        Some(KnospeShares {
+            knospe: calculate_knospe_certified_percentage(ingredients),
+            swiss: self.swiss_agricultural_percentage(ingredients),
+        })
     }
 
     /// Swiss share of the agricultural weight, as the active configuration means it.
@@ -1365,7 +1756,7 @@ impl Calculator {
         if umstellung && is_mono_product(ingredients) {
             let mono_is_bio_ch = ingredients
                 .iter()
-                .flat_map(|i| i.leaves())
+                .flat_map(|i| i.ingredient_leaves())
                 .filter(|i| i.is_agricultural())
                 .all(|i| i.bio_ch == Some(true));
             if mono_is_bio_ch && !undeclared_non_bio {
@@ -1411,7 +1802,8 @@ impl Calculator {
             });
         }
 
-        let knospe_percentage = calculate_knospe_certified_percentage(ingredients);
+        let shares = self.knospe_shares(ingredients)?;
+        let knospe_percentage = shares.knospe;
         // Permitted non-organic exceptions count as Knospe-compliant in the
         // percentage, so it alone cannot catch e.g. 40% Pektin. Bio Suisse caps
         // them at 5% of the agricultural weight, same as Bio-V (DEC-8).
@@ -1423,7 +1815,7 @@ impl Calculator {
             let umstellung = has_umstellbetrieb_in_tree(ingredients);
             let logo = KnospeLogo {
                 // Which artwork depends on the Swiss share of the certified goods.
-                swiss_cross: self.swiss_agricultural_percentage(ingredients) >= 90.0,
+                swiss_cross: shares.swiss >= 90.0,
                 umstellung,
             };
             // DEC-10: « Bio» an der Sachbezeichnung, analog Bio-V. Umstellung
@@ -1476,8 +1868,15 @@ impl Calculator {
         // Resolve percentage-mode composites into absolute gram/ml children up front,
         // so the entire downstream pipeline (computed_amount, QUID, sorting, validations)
         // operates on plain weights. The persisted Form keeps the percentages.
+        // Conversion periods that have ended by the production date are resolved
+        // the same way, so every Umstellung rule sees the current status.
+        let production_date = input.production_date;
         let input = Input {
-            ingredients: input.ingredients.iter().map(|i| i.resolve_percentages()).collect(),
+            ingredients: input
+                .ingredients
+                .iter()
+                .map(|i| i.resolve_percentages().resolve_conversion(production_date))
+                .collect(),
             ..input
         };
 
@@ -1499,6 +1898,16 @@ impl Calculator {
             }
         }
 
+        // Weight by which each top-level ingredient is ranked: in the finished
+        // product with AP1.5, the recipe weight otherwise.
+        let order_weights: Vec<f64> = if self.rule_defs.contains(&RuleDef::AP1_5_ReihenfolgeEndprodukt) {
+            finished_product_weights(&input.ingredients, total_amount)
+        } else {
+            input.ingredients.iter().map(|i| i.computed_amount()).collect()
+        };
+        let free_order = input.freie_reihenfolge_unter_2_prozent
+            && self.rule_defs.contains(&RuleDef::AP1_6_FreieReihenfolgeUnter2Prozent);
+
         // Whether the output label would carry the Import-Knospe (no Swiss cross):
         // 100% Knospe-certified but <90% Swiss share. Computed up front (pure
         // functions) because the origin validator below depends on the logo choice;
@@ -1523,6 +1932,14 @@ impl Calculator {
                     self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking ingredient amounts > 0"));
                     validate_amount(&input.ingredients, &mut validation_messages)
                 }
+                if let RuleDef::AP2_1_ZusammegesetztOutput = ruleDef {
+                    self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking compound exemptions are only claimed below 2%"));
+                    validate_compound_exemptions(&input.ingredients, total_amount, &mut validation_messages)
+                }
+                if let RuleDef::AP1_6_FreieReihenfolgeUnter2Prozent = ruleDef {
+                    self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking collective names are only used below 2%"));
+                    validate_collective_names(&input.ingredients, &order_weights, total_amount, &mut validation_messages)
+                }
                 if let RuleDef::AP1_2_ProzentOutputNamensgebend = ruleDef {
                     self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking namensgebend sub-ingredients carry an amount"));
                     validate_namensgebend_amounts(&input.ingredients, &mut validation_messages)
@@ -1535,14 +1952,18 @@ impl Calculator {
                     self.log_rule_processing(ruleDef, "VALIDATION", Some(&format!("Checking meat origin for ingredients >20% of {}g total", total_amount)));
                     validate_meat_origin(&input.ingredients, total_amount, &mut validation_messages);
                 }
-                if let RuleDef::AP7_4_RindfleischHerkunftDetails = ruleDef {
-                    self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking beef origin details (birthplace/slaughter)"));
-                    validate_beef_origin_details(&input.ingredients, &mut validation_messages);
+                if let RuleDef::AP7_4_FleischHerkunftDetails = ruleDef {
+                    self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking meat origin details per species (rearing/slaughter)"));
+                    validate_meat_origin_details(&input.ingredients, &mut validation_messages);
                 }
                 if let RuleDef::AP7_5_FischFangort = ruleDef {
                     self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking fish catch location"));
                     validate_fish_catch_location(&input.ingredients, &mut validation_messages);
                 }
+                if let RuleDef::LDV_NichtZugelasseneProduktionsmethoden = ruleDef {
+                    self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking production method of imported eggs and meat"));
+                    validate_production_methods(&input.ingredients, &mut validation_messages);
+                }
                 if let RuleDef::Knospe_AlleZutatenHerkunft = ruleDef {
                     self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking origin for Import-Knospe ingredients when the Import-Knospe shows"));
                     validate_import_knospe_origin(&input.ingredients, import_knospe_logo_would_show, &mut validation_messages)
@@ -1551,11 +1972,24 @@ impl Calculator {
                     self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking Knospe <90% specific ingredient origin requirements"));
                     validate_knospe_under90_origin(&input.ingredients, total_amount, &mut validation_messages);
                 }
+                if let RuleDef::Knospe_Verarbeitungsschritte = ruleDef {
+                    self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking processing steps against the Bio Suisse whitelist"));
+                    validate_knospe_processing_steps(&input.ingredients, &mut validation_messages);
+                }
             }
             // Non-ingredient validations always run
             if let RuleDef::Bio_Knospe_ZertifizierungsstellePflicht = ruleDef {
                 self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking mandatory certification body for Bio/Knospe"));
-                validate_certification_body(&input.certification_body, &mut validation_messages);
+                let configuration = self.configuration.unwrap_or(Configuration::Bio);
+                validate_certification_body(&input.certification_body, configuration, &mut validation_messages);
+            }
+            if let RuleDef::AOP_IGP_GeschuetzteBezeichnung = ruleDef {
+                self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking title for protected designations (AOP/IGP)"));
+                validate_protected_designation(&input, &mut validation_messages);
+            }
+            if let RuleDef::Bio_Knospe_LieferantenZertifikat = ruleDef {
+                self.log_rule_processing(ruleDef, "VALIDATION", Some("Checking supplier certificates against the label date"));
+                validate_supplier_certificates(&input.ingredients, input.best_before, &mut validation_messages);
             }
         }
 
@@ -1584,16 +2018,40 @@ impl Calculator {
             .rule_defs
             .contains(&RuleDef::AP1_3_EingabeNamensgebendeZutat);
 
-        let mut sorted_ingredients = input.ingredients.clone();
-        sorted_ingredients.sort_by(|y, x| {
-            x.computed_amount()
-                .partial_cmp(&y.computed_amount())
-                .unwrap_or(std::cmp::Ordering::Equal)
+        // AP1.5: order by weight in the finished product (evaporated water,
+        // reconstituted concentrates) and drop added water of ≤5%. Without the
+        // rule the list follows the recipe weights.
+        let finished_order = self.rule_defs.contains(&RuleDef::AP1_5_ReihenfolgeEndprodukt);
+        let mut weighted: Vec<(Ingredient, f64)> = input.ingredients.iter().cloned().zip(order_weights).collect();
+        if finished_order {
+            self.log_rule_processing(&RuleDef::AP1_5_ReihenfolgeEndprodukt, "OUTPUT", Some(&format!("Ordering by weight in the finished product of {}g", total_amount)));
+            weighted.retain(|(ing, weight)| {
+                !ing.is_added_water()
+                    || calculate_ingredient_percentage(*weight, total_amount) > ADDED_WATER_DECLARATION_THRESHOLD
+            });
+        }
+        // AP1.6: the tail below 2% keeps the recipe order. Below-2% entries
+        // compare equal among themselves, so the stable sort leaves them as
+        // entered while everything above is still ranked by weight.
+        let below_two_percent = |w: f64| free_order && calculate_ingredient_percentage(w, total_amount) < FREE_ORDER_THRESHOLD;
+        weighted.sort_by(|(_, y), (_, x)| {
+            if below_two_percent(*x) && below_two_percent(*y) {
+                std::cmp::Ordering::Equal
+            } else {
+                x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal)
+            }
         });
+        let sorted_ingredients: Vec<Ingredient> = weighted.iter().map(|(ing, _)| ing.clone()).collect();
 
         let manuelles_total_input = self
             .rule_defs
             .contains(&RuleDef::AP1_4_ManuelleEingabeTotal);
+        let reconstitution_input = self
+            .rule_defs
+            .contains(&RuleDef::AP1_5_ReihenfolgeEndprodukt);
+        let freie_reihenfolge_input = self
+            .rule_defs
+            .contains(&RuleDef::AP1_6_FreieReihenfolgeUnter2Prozent);
 
         // Determine which ingredients require country of origin display
         #[cfg(target_arch = "wasm32")]
@@ -1640,6 +2098,36 @@ impl Calculator {
         // Knospe: logo, variant and «Bio» suffix — decided as one typed verdict
         // (TD-1 Stufe 2), then mapped onto the conditional keys.
         let knospe_verdict = self.decide_knospe(&input.ingredients);
+        let knospe_shares = self.knospe_shares(&input.ingredients);
+
+        // AP7.6: a highlighted origin (title wording or the Knospe with Swiss
+        // cross) that the primary ingredient does not share calls for a
+        // «Rohstoff aus …» statement; without a known origin it cannot be made.
+        let mut primary_origin_statements = Vec::new();
+        if self.rule_defs.contains(&RuleDef::AP7_6_HerkunftPrimaerzutat) {
+            let swiss_cross = matches!(
+                knospe_verdict,
+                Some(KnospeVerdict::Logo { logo: KnospeLogo { swiss_cross: true, .. }, .. })
+            );
+            let emphasised = emphasised_country(&input.product_title, &input.product_subtitle, &input.production_country)
+                .or(swiss_cross.then_some(Country::CH));
+            if let Some(country) = emphasised {
+                self.log_rule_processing(&RuleDef::AP7_6_HerkunftPrimaerzutat, "OUTPUT", Some(&format!("Origin {:?} emphasised - checking primary ingredients", country)));
+                for i in primary_ingredients(&input.ingredients, total_amount) {
+                    match check_primary_ingredient(&input.ingredients[i], &country, input.origin_style) {
+                        PrimaryOriginCheck::Matches => {}
+                        PrimaryOriginCheck::Statement(statement) => primary_origin_statements.push(statement),
+                        PrimaryOriginCheck::OriginMissing => {
+                            if input.rezeptur_vollstaendig {
+                                validation_messages.entry(format!("ingredients[{}][origin]", i))
+                                    .or_default()
+                                    .push(t!("validation.primary_ingredient_origin_required").to_string());
+                            }
+                        }
+                    }
+                }
+            }
+        }
         let knospe_check = if self.rule_defs.contains(&RuleDef::Knospe_ShowBioSuisseLogo) {
             // The check must agree with the logo gate, otherwise logo and
             // «Rezeptur prüfen» text would contradict each other — which is
@@ -1731,6 +2219,73 @@ impl Calculator {
             }
         }
 
+        // AP3.1: warnings triggered by individual ingredients. Driven by the
+        // trigger table in `mandatory_warnings.csv`; the thresholds (polyols,
+        // caffeine) are shares of the finished product, hence `total_amount`.
+        let mandatory_warnings = if self.rule_defs.contains(&RuleDef::AP3_1_PflichthinweiseZutaten) {
+            mandatory_warnings(&input.ingredients, total_amount)
+        } else {
+            Vec::new()
+        };
+
+        // LDV: eggs from cage housing and meat possibly produced with
+        // hormonal/antibiotic performance enhancers need a statement.
+        let production_method_statements = if self.rule_defs.contains(&RuleDef::LDV_NichtZugelasseneProduktionsmethoden) {
+            production_method_statements(&input.ingredients)
+        } else {
+            Vec::new()
+        };
+
+        // AP7.7: honey names its country of origin; blends only with the
+        // prescribed «Mischung von Honig aus …» wording. A mono product has no
+        // ingredient list to carry it, so it gets its own statement.
+        let mut honey_origin_statement = None;
+        if self.rule_defs.contains(&RuleDef::AP7_7_HerkunftHonig) {
+            if input.rezeptur_vollstaendig || input.ignore_ingredients {
+                validate_honey_origin(&input.ingredients, &mut validation_messages);
+            }
+            if input.ignore_ingredients {
+                honey_origin_statement = input
+                    .ingredients
+                    .first()
+                    .filter(|i| is_honey(i))
+                    .and_then(|i| HoneyOriginStatement::for_mono_product(i, input.origin_aggregation, input.origin_style));
+            }
+        }
+
+        // Swissness (MSchG): independent of Bio/Knospe, the Swiss cross and
+        // «Swiss made» need 80% Swiss raw materials (milk 100%), with the
+        // raw materials Switzerland cannot supply taken out of the calculation.
+        let swissness = if self.rule_defs.contains(&RuleDef::Swissness_80_Prozent_Rohstoffe) && !input.ingredients.is_empty() {
+            Some(decide_swissness(&input.ingredients, &load_swissness_exclusions()))
+        } else {
+            None
+        };
+
+        // Knospe: processing of ingredients outside the Bio Suisse mapping
+        // cannot be checked against the whitelist and is left to a human.
+        let processing_review = if self.rule_defs.contains(&RuleDef::Knospe_Verarbeitungsschritte) {
+            processing_review_ingredients(&input.ingredients)
+        } else {
+            Vec::new()
+        };
+
+        // BAlV: «Berg» / «Alp» in the title or subtitle must be backed by the
+        // raw materials; the title field carries the validation message.
+        let mountain_designation = if self.rule_defs.contains(&RuleDef::BAlV_BergAlpBezeichnung) && !input.ingredients.is_empty() {
+            MountainDesignation::in_title(&input.product_title, &input.product_subtitle)
+                .map(|designation| decide_mountain_designation(&input.ingredients, designation))
+        } else {
+            None
+        };
+        if let Some(MountainVerdict::NotAllowed { designation, share, .. }) = &mountain_designation {
+            validation_messages.entry("product_title".to_string())
+                .or_default()
+                .push(t!("validation.mountain_designation_not_supported",
+                    designation = t!(designation.translation_key()),
+                    share = format!("{:.1}", share)).to_string());
+        }
+
         // All decisions are made — assemble the typed verdicts and derive the
         // legacy key→bool contract from them. This is the only place where
         // verdict → key happens; the exclusivity invariants follow from the
@@ -1743,7 +2298,17 @@ impl Calculator {
             alternative_marking_allowed,
             namensgebende_zutat_input,
             manuelles_total_input,
+            reconstitution_input,
+            freie_reihenfolge_input,
             origin_required_indices,
+            mandatory_warnings,
+            primary_origin_statements,
+            production_method_statements,
+            honey_origin_statement,
+            swissness,
+            mountain_designation,
+            processing_review,
+            knospe_shares,
         };
         let verdicts_out = verdicts;
 
@@ -1793,7 +2358,7 @@ impl Calculator {
 
         // Count agricultural ingredients for Monoprodukt detection in OutputFormatter
         let agricultural_ingredient_count = sorted_ingredients.iter()
-            .flat_map(|i| i.leaves())
+            .flat_map(|i| i.ingredient_leaves())
             .filter(|i| i.is_agricultural())
             .count();
 
@@ -1806,9 +2371,17 @@ impl Calculator {
             });
 
         // Generiere Zutatenliste
-        let ingredients_label = sorted_ingredients
+        // Processing aids / carry-over additives stay out of the list.
+        weighted.retain(|(item, _)| item.is_declared());
+        let listed = if self.rule_defs.contains(&RuleDef::AP1_6_FreieReihenfolgeUnter2Prozent) {
+            group_collective_names(weighted, total_amount)
+        } else {
+            weighted.into_iter().map(|(ing, _)| ing).collect()
+        };
+        let ingredients_label = listed
             .into_iter()
-            .map(|item| OutputFormatter::from(item, total_amount, output_rules.clone(), agricultural_ingredient_count))
+            .map(|item| item.with_origin_aggregation(input.origin_aggregation))
+            .map(|item| OutputFormatter::from(item, total_amount, output_rules.clone(), agricultural_ingredient_count, input.origin_style))
             .map(|fmt| fmt.format())
             .collect::<Vec<_>>()
             .join(", ");
@@ -1890,7 +2463,7 @@ fn validate_origin(
 /// Format origin display string for an ingredient according to active Knospe rules.
 /// Returns None if origin should not be displayed, Some("(CH)") etc. if it should.
 /// Used by both OutputFormatter::format() and composites_with_rules() for consistency.
-fn format_origin_for_knospe_rules(ingredient: &Ingredient, rules: &[RuleDef], total_amount: f64, agricultural_ingredient_count: usize) -> Option<String> {
+fn format_origin_for_knospe_rules(ingredient: &Ingredient, rules: &[RuleDef], total_amount: f64, agricultural_ingredient_count: usize, origin_style: OriginStyle) -> Option<String> {
     let has_knospe_100_rule = rules.contains(&RuleDef::Knospe_100_Percent_CH_NoOrigin);
     let has_knospe_90_99_rule = rules.contains(&RuleDef::Knospe_90_99_Percent_CH_ShowOrigin);
     let has_knospe_under90_rule = rules.contains(&RuleDef::Knospe_Under90_Percent_CH_IngredientRules);
@@ -1900,8 +2473,15 @@ fn format_origin_for_knospe_rules(ingredient: &Ingredient, rules: &[RuleDef], to
         None
     } else if has_knospe_90_99_rule {
         // Rule B: 90-99.99% Swiss — show origin for Swiss agricultural ingredients only
-        if ingredient.is_agricultural() && ingredient.computed_origins().is_some_and(|o| o.contains(&Country::CH)) {
-            Some("(CH)".to_string())
+        let swiss: Vec<String> = ingredient
+            .computed_origins()
+            .unwrap_or_default()
+            .iter()
+            .filter(|c| c.is_swiss())
+            .map(|c| origin_style.name(c))
+            .collect();
+        if ingredient.is_agricultural() && !swiss.is_empty() {
+            Some(format!("({})", swiss.join(", ")))
         } else {
             None
         }
@@ -1911,7 +2491,7 @@ fn format_origin_for_knospe_rules(ingredient: &Ingredient, rules: &[RuleDef], to
         let is_mono_product = agricultural_ingredient_count == 1;
 
         if should_show_origin_knospe_under90(ingredient, percentage, total_amount, is_mono_product) {
-            format_valid_origins(&ingredient.computed_origins())
+            format_valid_origins(&ingredient.computed_origins(), ingredient, origin_style)
         } else {
             None
         }
@@ -1923,7 +2503,7 @@ fn format_origin_for_knospe_rules(ingredient: &Ingredient, rules: &[RuleDef], to
             || *x == RuleDef::Knospe_AlleZutatenHerkunft
         );
         if has_herkunft_rule {
-            format_valid_origins(&ingredient.computed_origins())
+            format_valid_origins(&ingredient.computed_origins(), ingredient, origin_style)
         } else {
             None
         }
@@ -1944,22 +2524,182 @@ fn sort_children_by_weight(children: &[Ingredient]) -> Vec<Ingredient> {
     sorted
 }
 
-/// Format valid origins into a parenthetical string, dropping placeholders that
-/// must never reach the label: `NoOriginRequired`, and the generic `Import`
-/// sentinel (imported, country unspecified — declaring "(Import)" is not valid).
-fn format_valid_origins(origins: &Option<Vec<Country>>) -> Option<String> {
-    origins.as_ref().and_then(|origins| {
-        let valid: Vec<&str> = origins
-            .iter()
-            .filter(|o| !matches!(o, Country::NoOriginRequired | Country::Import))
-            .map(|o| o.country_code())
-            .collect();
-        if valid.is_empty() {
-            None
+/// Weight of each top-level ingredient in the finished product, by which the
+/// list is ordered (AP1.5). Concentrated/dehydrated ingredients count with
+/// their reconstituted weight. The volatile ingredients (added water, alcohol)
+/// get what remains of the finished weight once all other ingredients are
+/// deducted — the legal formula for added water — shared in proportion to
+/// their recipe weight and never more than was put in. When the reconstituted
+/// ingredients alone outweigh the finished product, the water did not cover
+/// the reconstitution: then only what evaporated is deducted, so added water
+/// is not lost from the list.
+pub fn finished_product_weights(ingredients: &[Ingredient], finished_total: f64) -> Vec<f64> {
+    let fixed: f64 = ingredients
+        .iter()
+        .filter(|i| !i.is_volatile())
+        .map(|i| i.reconstituted_amount())
+        .sum();
+    let volatile_raw: f64 = ingredients
+        .iter()
+        .filter(|i| i.is_volatile())
+        .map(|i| i.computed_amount())
+        .sum();
+    let volatile_finished = if fixed > finished_total {
+        let recipe_total: f64 = ingredients.iter().map(|i| i.computed_amount()).sum();
+        let evaporated = (recipe_total - finished_total).max(0.0);
+        (volatile_raw - evaporated).clamp(0.0, volatile_raw)
+    } else {
+        (finished_total - fixed).clamp(0.0, volatile_raw)
+    };
+    ingredients
+        .iter()
+        .map(|i| {
+            if !i.is_volatile() {
+                i.reconstituted_amount()
+            } else if volatile_raw > 0.0 {
+                i.computed_amount() / volatile_raw * volatile_finished
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Added water of at most 5% of the finished product need not be declared.
+const ADDED_WATER_DECLARATION_THRESHOLD: f64 = 5.0;
+
+/// Below this share ingredients may be listed in any order, and spices/herbs
+/// may be declared under a collective name (AP1.6). Strictly below: the legal
+/// text allows the free order for ingredients making up «less than 2 %» of the
+/// finished product (Regulation (EU) No 1169/2011, Annex VII Part A No. 4, as
+/// taken over by the LIV); the collective names use the same line.
+const FREE_ORDER_THRESHOLD: f64 = 2.0;
+
+/// A collective name only fits a spice or herb. Without a known category the
+/// producer's choice stands.
+fn is_spice_or_herb(ingredient: &Ingredient) -> bool {
+    ingredient.effective_category().is_none_or(|c| is_spice_or_herb_category(&c))
+}
+
+/// Whether the ingredient may carry its collective name: a spice or herb,
+/// below 2% and not an allergen (an allergen must stay visible under its own
+/// name).
+fn collective_name_permitted(ingredient: &Ingredient, weight: f64, total_amount: f64) -> bool {
+    calculate_ingredient_percentage(weight, total_amount) < FREE_ORDER_THRESHOLD
+        && !ingredient.contains_allergen()
+        && is_spice_or_herb(ingredient)
+}
+
+/// Merge the spices/herbs flagged with a collective name into one entry per
+/// name, placed where its first member stood. Members that may not carry the
+/// collective name (validated separately) keep their own name. The entry is
+/// bio only if every member is, and comes from every member's countries.
+fn group_collective_names(weighted: Vec<(Ingredient, f64)>, total_amount: f64) -> Vec<Ingredient> {
+    let mut out: Vec<Ingredient> = Vec::new();
+    let mut groups: Vec<(CollectiveName, usize)> = Vec::new();
+    for (ing, weight) in weighted {
+        let collective = ing
+            .collective_name
+            .filter(|_| collective_name_permitted(&ing, weight, total_amount));
+        let Some(collective) = collective else {
+            out.push(ing);
+            continue;
+        };
+        if let Some(&(_, idx)) = groups.iter().find(|(c, _)| *c == collective) {
+            let entry = &mut out[idx];
+            entry.amount += ing.computed_amount();
+            entry.is_bio = Some(entry.is_bio.unwrap_or(false) && ing.computed_bio_status().unwrap_or(false));
+            entry.bio_ch = Some(entry.bio_ch.unwrap_or(false) && ing.computed_bio_ch_status().unwrap_or(false));
+            entry.aus_umstellbetrieb = Some(entry.aus_umstellbetrieb.unwrap_or(false) || ing.aus_umstellbetrieb.unwrap_or(false));
+            if let Some(origins) = ing.computed_origins() {
+                let merged = entry.origins.get_or_insert_with(Vec::new);
+                for origin in origins {
+                    if !merged.contains(&origin) {
+                        merged.push(origin);
+                    }
+                }
+            }
         } else {
-            Some(format!("({})", valid.join(", ")))
+            groups.push((collective, out.len()));
+            out.push(Ingredient {
+                name: t!(collective.translation_key()).to_string(),
+                amount: ing.computed_amount(),
+                is_agricultural: ing.is_agricultural(),
+                is_bio: Some(ing.computed_bio_status().unwrap_or(false)),
+                bio_ch: Some(ing.computed_bio_ch_status().unwrap_or(false)),
+                aus_umstellbetrieb: ing.aus_umstellbetrieb,
+                origins: ing.computed_origins(),
+                ..Default::default()
+            });
         }
-    })
+    }
+    out
+}
+
+/// The origins that may be printed: without the placeholders that must never
+/// reach the label — `NoOriginRequired`, and the generic `Import` sentinel
+/// (imported, country unspecified — declaring "(Import)" is not valid).
+pub fn declarable_origins(origins: &[Country]) -> Vec<&Country> {
+    origins
+        .iter()
+        .filter(|o| !matches!(o, Country::NoOriginRequired | Country::Import))
+        .collect()
+}
+
+/// Format the declarable origins of `ingredient` into a parenthetical string,
+/// aggregated as the ingredient asks. Honey blends use their own wording.
+fn format_valid_origins(origins: &Option<Vec<Country>>, ingredient: &Ingredient, style: OriginStyle) -> Option<String> {
+    let valid = declarable_origins(origins.as_deref()?);
+    if valid.is_empty() {
+        return None;
+    }
+    let aggregation = ingredient.effective_origin_aggregation();
+    let text = if is_honey(ingredient) {
+        honey_origins(&valid, aggregation, style)
+    } else {
+        aggregate_origins(&valid, aggregation, style)
+    };
+    Some(format!("({})", text))
+}
+
+/// Countries from which «diverse Länder» may replace the list.
+const DIVERSE_LAENDER_MIN_COUNTRIES: usize = 3;
+
+/// The origins as printed inside the parentheses. A single country is always
+/// named; grouping keeps Switzerland and Swiss regions by name («CH, EU») and summarises the
+/// foreign countries by their group membership. «diverse Länder» needs at
+/// least three countries and otherwise falls back to the grouping.
+fn aggregate_origins(origins: &[&Country], aggregation: OriginAggregation, style: OriginStyle) -> String {
+    let codes = || origins.iter().map(|o| style.name(o)).collect::<Vec<_>>().join(", ");
+    match aggregation {
+        _ if origins.len() < 2 => codes(),
+        OriginAggregation::Laender => codes(),
+        OriginAggregation::DiverseLaender if origins.len() >= DIVERSE_LAENDER_MIN_COUNTRIES => {
+            t!("origin_aggregation.diverse_laender_text").to_string()
+        }
+        OriginAggregation::EuNichtEu | OriginAggregation::DiverseLaender => {
+            let foreign_groups: HashSet<CountryGroup> = origins
+                .iter()
+                .filter(|o| !o.is_swiss())
+                .flat_map(|o| o.groups())
+                .collect();
+            let groups: Vec<String> = [CountryGroup::Eu, CountryGroup::NichtEu]
+                .into_iter()
+                .filter(|g| foreign_groups.contains(g))
+                .map(|g| t!(g.translation_key()).to_string())
+                .collect();
+            if groups.is_empty() {
+                return codes();
+            }
+            let grouped = groups.join("/");
+            let swiss: Vec<String> = origins.iter().filter(|o| o.is_swiss()).map(|o| style.name(o)).collect();
+            if swiss.is_empty() {
+                grouped
+            } else {
+                format!("{}, {}", swiss.join(", "), grouped)
+            }
+        }
+    }
 }
 
 /// Determines if an ingredient should show origin for Knospe <90% CH rules
@@ -2007,7 +2747,7 @@ fn should_show_origin_knospe_under90(ingredient: &Ingredient, percentage: f64, _
 
     // Swiss agricultural ingredients with >=10% share (regardless of category)
     if ingredient.is_agricultural() &&
-       ingredient.computed_origins().is_some_and(|o| o.contains(&Country::CH)) &&
+       ingredient.computed_origins().is_some_and(|o| o.iter().any(Country::is_swiss)) &&
        percentage >= 10.0 {
         return true;
     }
@@ -2064,6 +2804,62 @@ fn validate_namensgebend_amounts(
     }
 }
 
+/// AP1.6: the collective names «Gewürze»/«Kräuter» are only available for
+/// spices and herbs below 2% — and never for an allergen, which would
+/// disappear behind the collective name.
+fn validate_collective_names(
+    ingredients: &[Ingredient],
+    order_weights: &[f64],
+    total_amount: f64,
+    validation_messages: &mut HashMap<String, Vec<String>>,
+) {
+    for (i, (ingredient, weight)) in ingredients.iter().zip(order_weights).enumerate() {
+        if ingredient.collective_name.is_none() {
+            continue;
+        }
+        let key = format!("ingredients[{}][collective_name]", i);
+        if calculate_ingredient_percentage(*weight, total_amount) >= FREE_ORDER_THRESHOLD {
+            validation_messages.entry(key.clone())
+                .or_default()
+                .push(t!("validation.collective_name_over_2_percent").to_string());
+        }
+        if ingredient.contains_allergen() {
+            validation_messages.entry(key)
+                .or_default()
+                .push(t!("validation.collective_name_allergen").to_string());
+        }
+        if !is_spice_or_herb(ingredient) {
+            validation_messages.entry(format!("ingredients[{}][collective_name]", i))
+                .or_default()
+                .push(t!("validation.collective_name_not_spice").to_string());
+        }
+    }
+}
+
+/// AP2.1: the «below 2%» exemption from listing a composite's components only
+/// holds while the composite really is below 2% of the finished product.
+fn validate_compound_exemptions(
+    ingredients: &[Ingredient],
+    total_amount: f64,
+    validation_messages: &mut HashMap<String, Vec<String>>,
+) {
+    fn subtree_violates(ing: &Ingredient, total_amount: f64) -> bool {
+        (ing.compound_exemption == Some(CompoundExemption::UnderTwoPercent)
+            && !ing.components_exempt(total_amount))
+            || ing
+                .children
+                .as_ref()
+                .is_some_and(|cs| cs.iter().any(|c| subtree_violates(c, total_amount)))
+    }
+    for (i, ingredient) in ingredients.iter().enumerate() {
+        if subtree_violates(ingredient, total_amount) {
+            validation_messages.entry(format!("ingredients[{}][compound_exemption]", i))
+                .or_default()
+                .push(t!("validation.compound_exemption_over_2_percent").to_string());
+        }
+    }
+}
+
 /// Reworked per Testing 25.06.2026: origin is required only for ingredients
 /// carrying the Import-(Umstellungs-)Knospe without a real country, and only
 /// when the label output is the Import-Knospe. Non-agricultural ingredients
@@ -2123,52 +2919,185 @@ fn validate_origin_single_level(
     }
 }
 
+/// The certification body is mandatory for Bio and Knospe. Its code has to
+/// follow the scheme of its country (`CH-BIO-xxx`, `DE-ÖKO-xxx`, …); bodies
+/// from the bundled registry must also be recognised for the configuration.
+/// Knospe only knows the bodies Bio Suisse recognises, so there the code has to
+/// be in the registry.
 fn validate_certification_body(
     certification_body: &Option<String>,
+    configuration: Configuration,
     validation_messages: &mut HashMap<String, Vec<String>>,
 ) {
-    match certification_body {
-        None => {
-            validation_messages.entry("certification_body".to_string())
-                .or_default()
-                .push(t!("validation.certification_body_required").to_string());
+    let value = certification_body.as_deref().unwrap_or_default().trim();
+    if value.is_empty() {
+        validation_messages.entry("certification_body".to_string())
+            .or_default()
+            .push(t!("validation.certification_body_required").to_string());
+        return;
+    }
+    let code = code_of(value);
+    if !is_well_formed(code) {
+        let format = expected_format(code);
+        validation_messages.entry("certification_body".to_string())
+            .or_default()
+            .push(t!("validation.certification_body_format", format = format).to_string());
+        return;
+    }
+    let registered = load_certification_bodies().into_iter().find(|b| b.code == code);
+    if registered.is_none() && configuration == Configuration::Knospe {
+        validation_messages.entry("certification_body".to_string())
+            .or_default()
+            .push(t!("validation.certification_body_unknown_for_knospe", code = code).to_string());
+    }
+    if let Some(body) = registered.filter(|b| !b.certifies(configuration)) {
+        let key = match configuration {
+            Configuration::Knospe => "validation.certification_body_not_for_knospe",
+            _ => "validation.certification_body_not_for_bio",
+        };
+        validation_messages.entry("certification_body".to_string())
+            .or_default()
+            .push(t!(key, body = body.label()).to_string());
+    }
+}
+
+/// A Bio/Knospe claim on an ingredient (or a saved composite) is only backed
+/// while the supplier's certificate is valid: warn when it expires before the
+/// date on the label. Reported under the top-level ingredient.
+fn validate_supplier_certificates(
+    ingredients: &[Ingredient],
+    best_before: Option<NaiveDate>,
+    validation_messages: &mut HashMap<String, Vec<String>>,
+) {
+    fn expired<'a>(ingredient: &'a Ingredient, date: NaiveDate, found: &mut Vec<&'a Ingredient>) {
+        let claims_bio = ingredient.is_bio.unwrap_or(false) || ingredient.bio_ch.unwrap_or(false);
+        if claims_bio && ingredient.supplier_certificate.as_ref().is_some_and(|c| c.expires_before(date)) {
+            found.push(ingredient);
         }
-        Some(s) if s.is_empty() => {
-            validation_messages.entry("certification_body".to_string())
+        for child in ingredient.children.iter().flatten() {
+            expired(child, date, found);
+        }
+    }
+
+    let Some(date) = best_before else {
+        return;
+    };
+    for (i, ingredient) in ingredients.iter().enumerate() {
+        let mut found = Vec::new();
+        expired(ingredient, date, &mut found);
+        for node in found {
+            let Some(certificate) = node.supplier_certificate.as_ref() else {
+                continue;
+            };
+            let valid_until = certificate.valid_until_date().map(|d| d.format("%d.%m.%Y").to_string()).unwrap_or_default();
+            validation_messages
+                .entry(format!("ingredients[{}][supplier_certificate]", i))
                 .or_default()
-                .push(t!("validation.certification_body_required").to_string());
+                .push(t!(
+                    "validation.supplier_certificate_expires",
+                    ingredient = node.name,
+                    valid_until = valid_until,
+                    date = date.format("%d.%m.%Y").to_string()
+                ).to_string());
         }
-        Some(s) => {
-            if !s.starts_with("CH-BIO-") {
-                validation_messages.entry("certification_body".to_string())
+    }
+}
+
+/// A registered AOP/IGP name in the title or subtitle needs the certificate;
+/// without it the message suggests a neutral wording instead.
+fn validate_protected_designation(
+    input: &Input,
+    validation_messages: &mut HashMap<String, Vec<String>>,
+) {
+    if input.protected_designation_certificate.as_deref().is_some_and(|c| !c.trim().is_empty()) {
+        return;
+    }
+    let registry = load_protected_designations();
+    if let Some(designation) = protected_designation_in(&[&input.product_title, &input.product_subtitle], &registry) {
+        let alternative_key = designation.alternative_key();
+        validation_messages.entry("protected_designation_certificate".to_string())
+            .or_default()
+            .push(t!("validation.protected_designation_certificate_required",
+                name = designation.name(),
+                protection = t!(designation.schutz.translation_key()),
+                alternative = t!(&alternative_key)).to_string());
+    }
+}
+
+/// Knospe: no processing step of an ingredient (or of its components) may be
+/// forbidden or need an approval for its Bio Suisse group. Steps missing from
+/// the list and categories without a Bio Suisse mapping are left to
+/// `processing_review_ingredients`.
+fn validate_knospe_processing_steps(
+    ingredients: &[Ingredient],
+    validation_messages: &mut HashMap<String, Vec<String>>,
+) {
+    let rules = load_processing_rules();
+    for (i, ingredient) in ingredients.iter().enumerate() {
+        for leaf in ingredient.ingredient_leaves() {
+            let (Some(category), Some(steps)) = (leaf.effective_category(), leaf.processing_steps.as_ref()) else {
+                continue;
+            };
+            for issue in check_steps_for_blv_category(&rules, &category, steps).unwrap_or_default() {
+                let message = match issue {
+                    StepIssue::Verboten { step } => {
+                        t!("validation.processing_step_forbidden", step = step, ingredient = leaf.name)
+                    }
+                    StepIssue::Bewilligungspflichtig { step } => {
+                        t!("validation.processing_step_approval_required", step = step, ingredient = leaf.name)
+                    }
+                    StepIssue::NichtGelistet { .. } => continue,
+                };
+                validation_messages.entry(format!("ingredients[{}][processing_steps]", i))
                     .or_default()
-                    .push(t!("validation.certification_body_format").to_string());
+                    .push(message.to_string());
             }
         }
     }
 }
 
-fn validate_beef_origin_details(
+/// Agricultural ingredients whose processing cannot be checked automatically:
+/// their category has no Bio Suisse group, or a step is not on the list of
+/// their group (in recipe order, no duplicates).
+fn processing_review_ingredients(ingredients: &[Ingredient]) -> Vec<String> {
+    let rules = load_processing_rules();
+    let mut names: Vec<String> = Vec::new();
+    for leaf in ingredients.iter().flat_map(|i| i.ingredient_leaves()) {
+        let Some(category) = leaf.effective_category().filter(|_| leaf.is_agricultural()) else {
+            continue;
+        };
+        let steps = leaf.processing_steps.clone().unwrap_or_default();
+        let review = match check_steps_for_blv_category(&rules, &category, &steps) {
+            None => true,
+            Some(issues) => issues.iter().any(|issue| matches!(issue, StepIssue::NichtGelistet { .. })),
+        };
+        if review && !names.contains(&leaf.name) {
+            names.push(leaf.name.clone());
+        }
+    }
+    names
+}
+
+fn validate_meat_origin_details(
     ingredients: &[Ingredient],
     validation_messages: &mut HashMap<String, Vec<String>>,
 ) {
     for (i, ingredient) in ingredients.iter().enumerate() {
-        // Check if this ingredient is beef-based using the category
-        if let Some(category) = &ingredient.category {
-            if is_beef_category(category) {
-                // Validate aufzucht_ort (birthplace/where it lived)
-                if ingredient.aufzucht_ort.is_none() {
-                    validation_messages.entry(format!("ingredients[{}][aufzucht_ort]", i))
-                        .or_default()
-                        .push(t!("validation.beef_breeding_location_required").to_string());
-                }
+        // The species table decides from the category whether details are due
+        if let Some(spec) = ingredient.category.as_deref().and_then(meat_species) {
+            let species = t!(spec.name_key).to_string();
+            // Validate aufzucht_ort (where the animal lived most of its life)
+            if ingredient.aufzucht_ort.is_none() {
+                validation_messages.entry(format!("ingredients[{}][aufzucht_ort]", i))
+                    .or_default()
+                    .push(t!("validation.meat_breeding_location_required", species = species).to_string());
+            }
 
-                // Validate schlachtungs_ort (slaughter location)
-                if ingredient.schlachtungs_ort.is_none() {
-                    validation_messages.entry(format!("ingredients[{}][schlachtungs_ort]", i))
-                        .or_default()
-                        .push(t!("validation.beef_slaughter_location_required").to_string());
-                }
+            // Validate schlachtungs_ort (slaughter location)
+            if ingredient.schlachtungs_ort.is_none() {
+                validation_messages.entry(format!("ingredients[{}][schlachtungs_ort]", i))
+                    .or_default()
+                    .push(t!("validation.meat_slaughter_location_required", species = species).to_string());
             }
         }
     }
@@ -2182,24 +3111,63 @@ fn validate_fish_catch_location(
         // Check if this ingredient is fish-based using the category
         if let Some(category) = &ingredient.category {
             if is_fish_category(category) {
-                // Validate fangort (catch location)
-                if ingredient.fangort.is_none() {
-                    validation_messages.entry(format!("ingredients[{}][fangort]", i))
+                for (field, key) in missing_fish_details(ingredient) {
+                    validation_messages.entry(format!("ingredients[{}][{}]", i, field))
                         .or_default()
-                        .push(t!("validation.fish_catch_location_required").to_string());
+                        .push(t!(key).to_string());
                 }
             }
         }
     }
 }
 
+fn validate_honey_origin(
+    ingredients: &[Ingredient],
+    validation_messages: &mut HashMap<String, Vec<String>>,
+) {
+    for (i, ingredient) in ingredients.iter().enumerate() {
+        let has_origin = ingredient
+            .computed_origins()
+            .is_some_and(|o| !declarable_origins(&o).is_empty());
+        if is_honey(ingredient) && !has_origin {
+            validation_messages.entry(format!("ingredients[{}][origin]", i))
+                .or_default()
+                .push(t!("validation.honey_origin_required").to_string());
+        }
+    }
+}
+
+fn validate_production_methods(
+    ingredients: &[Ingredient],
+    validation_messages: &mut HashMap<String, Vec<String>>,
+) {
+    for (i, ingredient) in ingredients.iter().enumerate() {
+        // Composites carry the production method on their egg/meat children;
+        // those are reported under the composite, by name.
+        for leaf in ingredient.ingredient_leaves() {
+            let Some((field, key)) = missing_production_method(leaf) else {
+                continue;
+            };
+            let message = if std::ptr::eq(leaf, ingredient) {
+                t!(key).to_string()
+            } else {
+                t!(&format!("{}_in_component", key), ingredient = leaf.name).to_string()
+            };
+            let messages = validation_messages.entry(format!("ingredients[{}][{}]", i, field)).or_default();
+            if !messages.contains(&message) {
+                messages.push(message);
+            }
+        }
+    }
+}
+
 fn validate_knospe_under90_origin(
     ingredients: &[Ingredient],
     total_amount: f64,
     validation_messages: &mut HashMap<String, Vec<String>>,
 ) {
     let agricultural_count = ingredients.iter()
-        .flat_map(|i| i.leaves())
+        .flat_map(|i| i.ingredient_leaves())
         .filter(|i| i.is_agricultural())
         .count();
 
@@ -2225,7 +3193,7 @@ fn validate_knospe_under90_origin(
                 } else if is_dairy_category(category) || is_meat_category(category) || is_insect_category(category) {
                     t!("validation.knospe_dairy_meat_insects_origin_required").to_string()
                 } else if ingredient.is_agricultural() &&
-                          ingredient.computed_origins().is_some_and(|o| o.contains(&Country::CH)) &&
+                          ingredient.computed_origins().is_some_and(|o| o.iter().any(Country::is_swiss)) &&
                           percentage >= 10.0 {
                     t!("validation.knospe_over_10_percent_origin_required").to_string()
                 } else {
@@ -2,7 +2,7 @@ use super::*;
 
 #[test]
 fn beef_origin_display_shows_geburtsort() {
-    let calculator = calculator_with(vec![RuleDef::AP7_4_RindfleischHerkunftDetails]);
+    let calculator = calculator_with(vec![RuleDef::AP7_4_FleischHerkunftDetails]);
     let input = InputBuilder::new()
         .ingredient(
             IngredientBuilder::new("Rindfleisch", 500.0)
@@ -54,7 +54,7 @@ fn test_beef_with_swiss_conventional_rules() {
 #[test]
 fn test_beef_origin_validation_and_display() {
     let calculator = calculator_with(vec![
-        RuleDef::AP7_4_RindfleischHerkunftDetails,
+        RuleDef::AP7_4_FleischHerkunftDetails,
     ]);
 
     // Test with beef ingredient missing both aufzucht_ort and schlachtungs_ort
@@ -98,19 +98,19 @@ fn test_beef_origin_validation_and_display() {
     // Should display beef-specific origin format in label
     assert!(output_with_origins.label.contains("Rindfleisch (Geburtsort: FR, Geschlachtet in: DE)"));
 
-    // Test with non-beef ingredient - should not require beef fields
+    // Test with game meat - not in the species table, no detail fields required
     let input_non_beef = InputBuilder::new()
         .vollstaendig()
         .ingredient(
-            IngredientBuilder::new("Schweinefleisch", 300.0)
-                .category("Schwein")
+            IngredientBuilder::new("Hirschfleisch", 300.0)
+                .category("Wild")
                 .build()
         )
         .build();
 
     let output_non_beef = calculator.execute(input_non_beef);
 
-    // Should not have validation errors for beef fields since it's not beef
+    // Should not have validation errors for the detail fields
     assert!(!output_non_beef.validation_messages.contains_key("ingredients[0][aufzucht_ort]"));
     assert!(!output_non_beef.validation_messages.contains_key("ingredients[0][schlachtungs_ort]"));
 }
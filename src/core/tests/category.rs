@@ -1,4 +1,5 @@
 use super::super::*;
+use crate::category_service::is_beef_category;
 
 #[test]
 fn test_is_fish_category() {
@@ -0,0 +1,56 @@
+// Certification body from the bundled registry: the code must follow the
+// scheme of its country, and registered bodies must be recognised for the
+// configuration (Knospe needs Bio Suisse recognition).
+
+use super::*;
+use crate::shared::Configuration;
+
+fn messages_for(config: Configuration, certification_body: &str) -> Vec<String> {
+    let calculator = calculator_for(config);
+    let input = InputBuilder::new()
+        .certification_body(certification_body)
+        .ingredient(IngredientBuilder::new_agri("Hafer", 1000.0).bio().build())
+        .build();
+    let output = calculator.execute(input);
+    output.validation_messages.get("certification_body").cloned().unwrap_or_default()
+}
+
+#[test]
+fn german_body_is_valid_for_bio() {
+    assert!(messages_for(Configuration::Bio, "DE-ÖKO-006").is_empty());
+}
+
+#[test]
+fn german_body_is_not_recognised_for_knospe() {
+    let messages = messages_for(Configuration::Knospe, "DE-ÖKO-006");
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].contains("DE-ÖKO-006 (ABCERT AG)"));
+    assert!(messages[0].contains("Knospe"));
+}
+
+#[test]
+fn swiss_body_is_valid_for_knospe() {
+    assert!(messages_for(Configuration::Knospe, "CH-BIO-086").is_empty());
+}
+
+#[test]
+fn format_follows_the_country_scheme() {
+    let messages = messages_for(Configuration::Bio, "DE-BIO-006");
+    assert!(messages.iter().any(|m| m.contains("DE-ÖKO-xxx")));
+    let messages = messages_for(Configuration::Bio, "FR-BIO-001");
+    assert!(messages.iter().any(|m| m.contains("FR-BIO-xx")));
+}
+
+#[test]
+fn unregistered_body_with_valid_code_is_accepted() {
+    assert!(messages_for(Configuration::Bio, "NL-BIO-01").is_empty());
+}
+
+#[test]
+fn knospe_needs_a_registered_body() {
+    for code in ["NL-BIO-01", "CH-BIO-999"] {
+        let messages = messages_for(Configuration::Knospe, code);
+        assert_eq!(messages.len(), 1, "{code}");
+        assert!(messages[0].contains("keine von Bio Suisse anerkannte Kontrollstelle"), "{}", messages[0]);
+    }
+}
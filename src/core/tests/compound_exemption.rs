@@ -0,0 +1,75 @@
+use super::*;
+
+fn spice_blend(amount_each: f64) -> IngredientBuilder {
+    IngredientBuilder::new("Gewürzmischung", 0.0).children(vec![
+        IngredientBuilder::new("Paprika", amount_each).build(),
+        IngredientBuilder::new("Pfeffer", amount_each).build(),
+        IngredientBuilder::new("Senfsaat", amount_each).allergen().build(),
+        IngredientBuilder::new("Trennmittel E 551", amount_each).agricultural(false).build(),
+    ])
+}
+
+#[test]
+fn exempt_composite_below_two_percent_lists_only_allergens_and_additives() {
+    let calculator = calculator_with(vec![RuleDef::AP2_1_ZusammegesetztOutput]);
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Kartoffeln", 980.0).build())
+        .ingredient(spice_blend(4.0).compound_exemption(CompoundExemption::UnderTwoPercent).build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(
+        output.label.contains("Gewürzmischung (<b>Senfsaat</b>, Trennmittel E 551)"),
+        "label: {}",
+        output.label
+    );
+    assert!(!output.label.contains("Paprika"));
+    assert!(output.validation_messages.is_empty());
+}
+
+#[test]
+fn without_opt_in_every_component_is_listed() {
+    let calculator = calculator_with(vec![RuleDef::AP2_1_ZusammegesetztOutput]);
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Kartoffeln", 980.0).build())
+        .ingredient(spice_blend(5.0).build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(output.label.contains("Paprika"), "label: {}", output.label);
+}
+
+#[test]
+fn under_two_percent_claim_above_the_line_is_flagged_and_expanded() {
+    let calculator = calculator_with(vec![RuleDef::AP2_1_ZusammegesetztOutput]);
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Kartoffeln", 900.0).build())
+        .ingredient(spice_blend(25.0).compound_exemption(CompoundExemption::UnderTwoPercent).build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(output.validation_messages.contains_key("ingredients[1][compound_exemption]"));
+    assert!(output.label.contains("Paprika"), "label: {}", output.label);
+}
+
+#[test]
+fn standardised_compound_is_exempt_at_any_share() {
+    let calculator = calculator_with(vec![RuleDef::AP2_1_ZusammegesetztOutput]);
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Kartoffeln", 500.0).build())
+        .ingredient(spice_blend(125.0).compound_exemption(CompoundExemption::Standardised).build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(!output.label.contains("Paprika"), "label: {}", output.label);
+    assert!(output.label.contains("<b>Senfsaat</b>"));
+    assert!(output.validation_messages.is_empty());
+}
+
+#[test]
+fn additive_detection_needs_an_e_number() {
+    assert!(IngredientBuilder::new("Antioxidationsmittel E 306", 1.0).build().is_additive());
+    assert!(IngredientBuilder::new("E1422", 1.0).build().is_additive());
+    assert!(IngredientBuilder::new("Farbstoff E160a", 1.0).build().is_additive());
+    assert!(!IngredientBuilder::new("Paprika", 1.0).build().is_additive());
+    assert!(!IngredientBuilder::new("Tee100", 1.0).build().is_additive());
+}
@@ -0,0 +1,114 @@
+// Umstellung with a known end: the conversion flags are evaluated as of the
+// production date, so labels made after the conversion ended lose the
+// Umstellung wording without anyone editing the recipe.
+
+use crate::conditional_keys as keys;
+use super::*;
+use crate::pages::label_page::{Form, MonoQuality};
+use crate::shared::Configuration;
+
+fn knospe_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::Knospe_ShowBioSuisseLogo, RuleDef::Bio_Knospe_EingabeIstBio])
+}
+
+fn recipe(production_date: &str) -> Input {
+    InputBuilder::new()
+        .production_date(production_date)
+        .ingredient(
+            IngredientBuilder::new_agri("Dinkel", 800.0)
+                .bio()
+                .umstellbetrieb()
+                .umstellung_bis("2026-06-30")
+                .origin(Country::CH)
+                .build(),
+        )
+        .ingredient(IngredientBuilder::new_agri("Honig", 200.0).bio().origin(Country::CH).build())
+        .build()
+}
+
+#[test]
+fn conversion_still_running_shows_the_umstellungsknospe() {
+    let c = knospe_calculator().execute(recipe("2026-06-29")).conditionals();
+    assert_eq!(c.get(keys::KNOSPE_UMSTELLUNG_LOGO), Some(&true));
+}
+
+#[test]
+fn conversion_ended_by_the_production_date_shows_the_knospe() {
+    let c = knospe_calculator().execute(recipe("2026-06-30")).conditionals();
+    assert_eq!(c.get(keys::KNOSPE_UMSTELLUNG_LOGO), None);
+    assert_eq!(c.get(keys::BIO_SUISSE_REGULAR), Some(&true));
+}
+
+#[test]
+fn without_a_production_date_the_conversion_is_running() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new_agri("Dinkel", 1000.0).bio().umstellbetrieb().umstellung_bis("2000-01-01").build())
+        .build();
+    let c = knospe_calculator().execute(input).conditionals();
+    assert_eq!(c.get(keys::KNOSPE_UMSTELLUNG_LOGO), Some(&true));
+}
+
+#[test]
+fn ended_conversion_counts_as_bio_ch() {
+    let calculator = calculator_with(vec![RuleDef::Bio_ShowBioSachbezeichnung]);
+    let composite = |date: &str| {
+        InputBuilder::new()
+            .production_date(date)
+            .ingredient(IngredientBuilder::new_agri("Hafer", 500.0).bio_ch().build())
+            .ingredient(
+                IngredientBuilder::new_agri("Gerste", 500.0).bio_ch().umstellbetrieb().umstellung_bis("2026-01-01").build(),
+            )
+            .build()
+    };
+    let running = calculator.execute(composite("2025-12-31")).conditionals();
+    assert_eq!(running.get(keys::BIO_MARKETING_ALLOWED), None);
+    let ended = calculator.execute(composite("2026-01-01")).conditionals();
+    assert_eq!(ended.get(keys::BIO_MARKETING_ALLOWED), Some(&true));
+}
+
+#[test]
+fn ended_conversion_on_a_component_is_resolved_too() {
+    let dinkel = IngredientBuilder::new("Dinkelmehl", 1000.0)
+        .children(vec![IngredientBuilder::new_agri("Dinkel", 1000.0).bio().umstellbetrieb().umstellung_bis("2026-06-30").build()])
+        .build();
+    let resolved = dinkel.resolve_conversion(chrono::NaiveDate::from_ymd_opt(2026, 7, 1));
+    assert_eq!(resolved.children.unwrap()[0].aus_umstellbetrieb, Some(false));
+}
+
+fn mono_output(quality: MonoQuality, conversion_end: &str) -> crate::core::Output {
+    let form = Form {
+        ignore_ingredients: true,
+        mono_quality: quality,
+        mono_umstellung_bis: conversion_end.to_string(),
+        product_subtitle: "Weizenmehl".to_string(),
+        ..Form::default()
+    };
+    calculator_for(Configuration::Knospe).execute(form.into())
+}
+
+#[test]
+fn mono_product_follows_its_conversion_end() {
+    let ended = mono_output(MonoQuality::UmstellungKnospeCh, "2000-01-01").conditionals();
+    assert_eq!(ended.get(keys::KNOSPE_UMSTELLUNG_LOGO), None);
+    assert_eq!(ended.get(keys::BIO_SUISSE_REGULAR), Some(&true));
+
+    let running = mono_output(MonoQuality::UmstellungKnospeCh, "2999-12-31").conditionals();
+    assert_eq!(running.get(keys::KNOSPE_UMSTELLUNG_LOGO), Some(&true));
+}
+
+#[test]
+fn saved_mono_label_is_judged_as_of_its_own_production_date() {
+    let form = Form {
+        ignore_ingredients: true,
+        mono_quality: MonoQuality::UmstellungKnospeCh,
+        mono_umstellung_bis: "2025-01-01".to_string(),
+        production_date: "2024-06-01".to_string(),
+        product_subtitle: "Weizenmehl".to_string(),
+        ..Form::default()
+    };
+    let input: Input = form.clone().into();
+    assert_eq!(input.production_date, Some(chrono::NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()));
+    // The conversion ended long ago by the wall clock, but not on the label's day.
+    let output = calculator_for(Configuration::Knospe).execute(form.into());
+    assert_eq!(output.conditionals().get(keys::KNOSPE_UMSTELLUNG_LOGO), Some(&true));
+}
@@ -0,0 +1,148 @@
+use super::*;
+use crate::fish_origin::{FaoArea, FishOrigin, FishProduction, FishingGear};
+
+fn fish_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::AP7_5_FischFangort])
+}
+
+fn sea_catch(area: Option<FaoArea>, gear: Option<FishingGear>) -> FishOrigin {
+    FishOrigin {
+        production: Some(FishProduction::Meeresfang),
+        fao_area: area,
+        gear,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn sea_catch_renders_fao_area_and_gear() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(
+            IngredientBuilder::new("Kabeljau", 500.0)
+                .category("Meeresfische")
+                .fish_origin(sea_catch(Some(FaoArea::Fao27), Some(FishingGear::Schleppnetze)))
+                .build(),
+        )
+        .build();
+    let output = fish_calculator().execute(input);
+    assert_eq!(output.label, "Kabeljau (gefangen, Nordostatlantik FAO 27, Schleppnetze)");
+    assert!(!output.validation_messages.keys().any(|k| k.starts_with("ingredients[0]")));
+}
+
+#[test]
+fn sea_catch_needs_fao_area_and_gear_but_no_country() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(
+            IngredientBuilder::new("Kabeljau", 500.0)
+                .category("Meeresfische")
+                .fish_origin(sea_catch(None, None))
+                .build(),
+        )
+        .build();
+    let output = fish_calculator().execute(input);
+    assert!(output.validation_messages["ingredients[0][fao_area]"]
+        .iter()
+        .any(|m| m == "FAO-Fanggebiet ist erforderlich für Fisch aus Meeresfang."));
+    assert!(output.validation_messages.contains_key("ingredients[0][fishing_gear]"));
+    assert!(!output.validation_messages.contains_key("ingredients[0][fangort]"));
+}
+
+#[test]
+fn inland_fishing_renders_water_body_country_and_gear() {
+    let input = InputBuilder::new()
+        .ingredient(
+            IngredientBuilder::new("Egli", 300.0)
+                .category("Süsswasserfische")
+                .fangort(Country::CH)
+                .fish_origin(FishOrigin {
+                    production: Some(FishProduction::Binnenfischerei),
+                    water_body: Some("Bodensee".to_string()),
+                    gear: Some(FishingGear::Kiemennetze),
+                    ..Default::default()
+                })
+                .build(),
+        )
+        .build();
+    let output = fish_calculator().execute(input);
+    assert_eq!(output.label, "Egli (aus Binnenfischerei, Bodensee, CH, Kiemennetze und ähnliche Netze)");
+}
+
+#[test]
+fn inland_fishing_needs_water_body() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(
+            IngredientBuilder::new("Egli", 300.0)
+                .category("Süsswasserfische")
+                .fangort(Country::CH)
+                .fish_origin(FishOrigin {
+                    production: Some(FishProduction::Binnenfischerei),
+                    water_body: Some("  ".to_string()),
+                    gear: Some(FishingGear::HakenUndLangleinen),
+                    ..Default::default()
+                })
+                .build(),
+        )
+        .build();
+    let output = fish_calculator().execute(input);
+    assert!(output.validation_messages.contains_key("ingredients[0][water_body]"));
+    assert!(!output.validation_messages.contains_key("ingredients[0][fishing_gear]"));
+}
+
+#[test]
+fn aquaculture_needs_country_and_no_gear() {
+    let aquaculture = FishOrigin {
+        production: Some(FishProduction::Aquakultur),
+        ..Default::default()
+    };
+    let missing = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(
+            IngredientBuilder::new("Lachs", 200.0)
+                .category("Meeresfische")
+                .fish_origin(aquaculture.clone())
+                .build(),
+        )
+        .build();
+    let output = fish_calculator().execute(missing);
+    assert!(output.validation_messages["ingredients[0][fangort]"]
+        .iter()
+        .any(|m| m == "Land der Aufzucht ist erforderlich für Fisch aus Aquakultur."));
+    assert!(!output.validation_messages.contains_key("ingredients[0][fishing_gear]"));
+
+    let complete = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(
+            IngredientBuilder::new("Lachs", 200.0)
+                .category("Meeresfische")
+                .fangort(Country::NO)
+                .fish_origin(aquaculture)
+                .build(),
+        )
+        .build();
+    let output = fish_calculator().execute(complete);
+    assert_eq!(output.label, "Lachs (aus Aquakultur, NO)");
+    assert!(!output.validation_messages.keys().any(|k| k.starts_with("ingredients[0]")));
+}
+
+#[test]
+fn production_method_is_required() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Lachs", 200.0).category("Fisch").fangort(Country::NO).build())
+        .build();
+    let output = fish_calculator().execute(input);
+    assert!(output.validation_messages.contains_key("ingredients[0][fish_production]"));
+    // The country alone still renders as before.
+    assert_eq!(output.label, "Lachs (NO)");
+}
+
+#[test]
+fn fao_area_serializes_as_its_code() {
+    let origin = sea_catch(Some(FaoArea::Fao37), Some(FishingGear::Dredgen));
+    let json = serde_json::to_string(&origin).unwrap();
+    assert_eq!(json, r#"{"production":"meeresfang","fao_area":"37","gear":"dredgen"}"#);
+    assert_eq!(serde_json::from_str::<FishOrigin>(&json).unwrap(), origin);
+}
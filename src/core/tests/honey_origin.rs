@@ -0,0 +1,101 @@
+use super::*;
+
+fn honey_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent, RuleDef::AP7_7_HerkunftHonig])
+}
+
+fn honey(origins: Vec<Country>) -> Ingredient {
+    IngredientBuilder::new("Honig", 500.0).category("Honig").origins(origins).build()
+}
+
+#[test]
+fn blend_of_eu_and_non_eu_honey_uses_the_blend_wording() {
+    let input = InputBuilder::new()
+        .origin_aggregation(OriginAggregation::EuNichtEu)
+        .ingredient(honey(vec![Country::MX, Country::DE]))
+        .build();
+    let output = honey_calculator().execute(input);
+    assert_eq!(output.label, "Honig (Mischung von Honig aus EU- und Nicht-EU-Ländern)");
+}
+
+#[test]
+fn naming_every_country_stays_allowed() {
+    let input = InputBuilder::new()
+        .ingredient(honey(vec![Country::MX, Country::DE]))
+        .build();
+    let output = honey_calculator().execute(input);
+    assert_eq!(output.label, "Honig (MX, DE)");
+}
+
+#[test]
+fn diverse_laender_is_not_used_for_honey() {
+    let input = InputBuilder::new()
+        .origin_aggregation(OriginAggregation::DiverseLaender)
+        .ingredient(honey(vec![Country::DE, Country::FR, Country::IT]))
+        .build();
+    let output = honey_calculator().execute(input);
+    assert_eq!(output.label, "Honig (Mischung von Honig aus EU-Ländern)");
+}
+
+#[test]
+fn switzerland_counts_as_non_eu_in_a_blend() {
+    let input = InputBuilder::new()
+        .ingredient(
+            IngredientBuilder::new("Honig", 500.0)
+                .category("Honig")
+                .origins(vec![Country::CH, Country::AR])
+                .origin_aggregation(OriginAggregation::EuNichtEu)
+                .build(),
+        )
+        .build();
+    let output = honey_calculator().execute(input);
+    assert_eq!(output.label, "Honig (Mischung von Honig aus Nicht-EU-Ländern)");
+}
+
+#[test]
+fn honey_ingredient_needs_a_country() {
+    for origins in [None, Some(vec![Country::Import])] {
+        let mut ing = IngredientBuilder::new("Honig", 100.0).category("Honig").build();
+        ing.origins = origins;
+        let input = InputBuilder::new()
+            .vollstaendig()
+            .ingredient(IngredientBuilder::new("Weizenmehl", 900.0).origin(Country::CH).build())
+            .ingredient(ing)
+            .build();
+        let output = honey_calculator().execute(input);
+        assert!(output.validation_messages["ingredients[1][origin]"]
+            .iter()
+            .any(|m| m == "Ursprungsland ist erforderlich für Honig."));
+    }
+}
+
+#[test]
+fn mono_honey_gets_an_origin_statement() {
+    let input = InputBuilder::new()
+        .einzelzutat()
+        .origin_aggregation(OriginAggregation::EuNichtEu)
+        .ingredient(honey(vec![Country::MX, Country::GT]))
+        .build();
+    let output = honey_calculator().execute(input);
+    assert_eq!(output.label, "");
+    let statement = output.verdicts.honey_origin_statement.expect("statement for mono honey");
+    assert_eq!(statement.text(), "Herkunft: Mischung von Honig aus Nicht-EU-Ländern");
+
+    let input = InputBuilder::new()
+        .einzelzutat()
+        .ingredient(honey(vec![Country::CH]))
+        .build();
+    let output = honey_calculator().execute(input);
+    assert_eq!(output.verdicts.honey_origin_statement.unwrap().text(), "Herkunft: CH");
+}
+
+#[test]
+fn mono_honey_without_origin_is_flagged_without_a_recipe() {
+    let input = InputBuilder::new()
+        .einzelzutat()
+        .ingredient(IngredientBuilder::new("", 100.0).category("Honig").build())
+        .build();
+    let output = honey_calculator().execute(input);
+    assert!(output.verdicts.honey_origin_statement.is_none());
+    assert!(output.validation_messages.contains_key("ingredients[0][origin]"));
+}
@@ -0,0 +1,220 @@
+use super::*;
+
+fn order_calculator() -> Calculator {
+    calculator_with(vec![
+        RuleDef::AP1_4_ManuelleEingabeTotal,
+        RuleDef::AP1_5_ReihenfolgeEndprodukt,
+    ])
+}
+
+fn label_names(output: &Output) -> Vec<String> {
+    output.label.split(", ").map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn evaporated_water_moves_down_the_list() {
+    // Bread: 500 g flour, 400 g water, 10 g salt; 750 g after baking.
+    // Water in the finished product: 750 − 510 = 240 g.
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Weizenmehl", 500.0).build())
+        .ingredient(IngredientBuilder::new("Wasser", 400.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Salz", 10.0).agricultural(false).build())
+        .total(750.0)
+        .build();
+    let weights = finished_product_weights(&input.ingredients, 750.0);
+    assert_eq!(weights, vec![500.0, 240.0, 10.0]);
+    let output = order_calculator().execute(input);
+    assert_eq!(label_names(&output), vec!["Weizenmehl", "Wasser", "Salz"]);
+}
+
+#[test]
+fn water_overtaken_after_evaporation() {
+    // 300 g water, 250 g sugar, 250 g fruit, reduced to 600 g:
+    // 100 g water remain, so it ends up last.
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Wasser", 300.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Zucker", 250.0).build())
+        .ingredient(IngredientBuilder::new("Aprikosen", 250.0).build())
+        .total(600.0)
+        .build();
+    let output = order_calculator().execute(input);
+    assert_eq!(label_names(&output)[2], "Wasser");
+}
+
+#[test]
+fn added_water_up_to_five_percent_is_omitted() {
+    // 1000 g recipe with 100 g water dried to 950 g: 50 g water = 5.3% → listed.
+    let listed = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Hackfleisch", 900.0).build())
+        .ingredient(IngredientBuilder::new("Wasser", 100.0).agricultural(false).build())
+        .total(950.0)
+        .build();
+    assert!(order_calculator().execute(listed).label.contains("Wasser"));
+
+    // Dried to 940 g: 40 g water = 4.3% → omitted.
+    let omitted = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Hackfleisch", 900.0).build())
+        .ingredient(IngredientBuilder::new("Wasser", 100.0).agricultural(false).build())
+        .total(940.0)
+        .build();
+    let output = order_calculator().execute(omitted);
+    assert!(!output.label.contains("Wasser"), "label: {}", output.label);
+}
+
+#[test]
+fn reconstituted_concentrate_ranks_by_weight_before_concentration() {
+    // 100 g milk powder (factor 8 → 800 g milk) reconstituted with 700 g water,
+    // plus 200 g sugar. The milk outranks the sugar; the reconstitution water
+    // is part of the milk, so no added water is left to declare.
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Zucker", 200.0).build())
+        .ingredient(IngredientBuilder::new("Vollmilchpulver", 100.0).allergen().reconstitution(8.0).build())
+        .ingredient(IngredientBuilder::new("Wasser", 700.0).agricultural(false).build())
+        .build();
+    let output = order_calculator().execute(input);
+    assert_eq!(label_names(&output), vec!["<b>Vollmilchpulver</b>", "Zucker"]);
+}
+
+#[test]
+fn added_water_stays_when_the_concentrate_outweighs_the_product() {
+    // 50 g bouillon powder (factor 20 → 1000 g) with 900 g water: the
+    // reconstituted weight exceeds the 950 g product, but nothing evaporated,
+    // so the water stays on the list with its full weight.
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Bouillonpulver", 50.0).reconstitution(20.0).build())
+        .ingredient(IngredientBuilder::new("Wasser", 900.0).agricultural(false).build())
+        .build();
+    assert_eq!(finished_product_weights(&input.ingredients, 950.0), vec![1000.0, 900.0]);
+    let output = order_calculator().execute(input);
+    assert_eq!(label_names(&output), vec!["Bouillonpulver", "Wasser"]);
+}
+
+#[test]
+fn without_the_rule_the_recipe_weight_decides() {
+    let calculator = calculator_with(vec![RuleDef::AP1_4_ManuelleEingabeTotal]);
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Wasser", 300.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Zucker", 250.0).build())
+        .ingredient(IngredientBuilder::new("Aprikosen", 250.0).build())
+        .total(600.0)
+        .build();
+    let output = calculator.execute(input);
+    assert_eq!(label_names(&output)[0], "Wasser");
+}
+
+// --- AP1.6: free order below 2%, collective names ---
+
+fn free_order_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::AP1_6_FreieReihenfolgeUnter2Prozent])
+}
+
+fn tail_recipe() -> InputBuilder {
+    InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Tomaten", 900.0).build())
+        .ingredient(IngredientBuilder::new("Zwiebeln", 50.0).build())
+        .ingredient(IngredientBuilder::new("Salz", 10.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Olivenöl", 15.0).build())
+        .ingredient(IngredientBuilder::new("Zucker", 25.0).build())
+}
+
+#[test]
+fn tail_below_two_percent_keeps_the_recipe_order() {
+    let output = free_order_calculator().execute(tail_recipe().freie_reihenfolge().build());
+    // Salz (1%) and Olivenöl (1.5%) stay as entered; Zucker (2.5%) is above
+    // the line and still ranks by weight.
+    assert_eq!(label_names(&output), vec!["Tomaten", "Zwiebeln", "Zucker", "Salz", "Olivenöl"]);
+}
+
+#[test]
+fn without_the_switch_the_tail_is_sorted_by_weight() {
+    let output = free_order_calculator().execute(tail_recipe().build());
+    assert_eq!(label_names(&output), vec!["Tomaten", "Zwiebeln", "Zucker", "Olivenöl", "Salz"]);
+}
+
+#[test]
+fn spices_below_two_percent_are_grouped() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Kartoffeln", 950.0).build())
+        .ingredient(IngredientBuilder::new("Paprika", 15.0).collective(CollectiveName::Gewuerze).build())
+        .ingredient(IngredientBuilder::new("Salz", 20.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Kreuzkümmel", 10.0).collective(CollectiveName::Gewuerze).build())
+        .ingredient(IngredientBuilder::new("Petersilie", 5.0).collective(CollectiveName::Kraeuter).build())
+        .build();
+    let output = free_order_calculator().execute(input);
+    assert_eq!(label_names(&output), vec!["Kartoffeln", "Salz", "Gewürze", "Kräuter"]);
+}
+
+#[test]
+fn collective_name_above_two_percent_is_rejected() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Kartoffeln", 950.0).build())
+        .ingredient(IngredientBuilder::new("Paprika", 50.0).collective(CollectiveName::Gewuerze).build())
+        .build();
+    let output = free_order_calculator().execute(input);
+    assert!(output.validation_messages.contains_key("ingredients[1][collective_name]"));
+    // Not grouped: it stays visible under its own name.
+    assert!(output.label.contains("Paprika"), "label: {}", output.label);
+}
+
+#[test]
+fn allergen_never_hides_behind_a_collective_name() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Kartoffeln", 990.0).build())
+        .ingredient(IngredientBuilder::new("Senfsaat", 10.0).allergen().collective(CollectiveName::Gewuerze).build())
+        .build();
+    let output = free_order_calculator().execute(input);
+    assert!(output.validation_messages.contains_key("ingredients[1][collective_name]"));
+    assert!(output.label.contains("<b>Senfsaat</b>"), "label: {}", output.label);
+}
+
+#[test]
+fn collective_name_is_only_for_spices_and_herbs() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Kartoffeln", 985.0).build())
+        .ingredient(IngredientBuilder::new("Linsenmehl", 15.0).category("Hülsenfrüchte").collective(CollectiveName::Gewuerze).build())
+        .build();
+    let output = free_order_calculator().execute(input);
+    assert_eq!(
+        output.validation_messages["ingredients[1][collective_name]"],
+        vec!["Die Sammelbezeichnungen «Gewürze» und «Kräuter» sind nur für Gewürze und Kräuter zulässig.".to_string()]
+    );
+    assert!(output.label.contains("Linsenmehl"), "label: {}", output.label);
+
+    let spice = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Kartoffeln", 985.0).build())
+        .ingredient(IngredientBuilder::new("Paprika", 15.0).category("Salz, Gewürze und Aromen").collective(CollectiveName::Gewuerze).build())
+        .build();
+    assert!(free_order_calculator().execute(spice).validation_messages.is_empty());
+}
+
+#[test]
+fn grouped_spices_keep_the_origins_of_their_members() {
+    let calculator = calculator_with(vec![RuleDef::AP1_6_FreieReihenfolgeUnter2Prozent, RuleDef::Knospe_AlleZutatenHerkunft]);
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Kartoffeln", 970.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new("Paprika", 15.0).origin(Country::ES).collective(CollectiveName::Gewuerze).build())
+        .ingredient(IngredientBuilder::new("Kreuzkümmel", 10.0).origin(Country::IN).collective(CollectiveName::Gewuerze).build())
+        .ingredient(IngredientBuilder::new("Pfeffer", 5.0).origin(Country::IN).collective(CollectiveName::Gewuerze).build())
+        .build();
+    let output = calculator.execute(input);
+    assert_eq!(output.label, "Kartoffeln (CH), Gewürze (ES, IN)");
+}
+
+#[test]
+fn exactly_two_percent_is_not_below_the_line() {
+    // 20 g of 1000 g: neither reordered nor allowed under «Gewürze».
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .freie_reihenfolge()
+        .ingredient(IngredientBuilder::new("Kartoffeln", 965.0).build())
+        .ingredient(IngredientBuilder::new("Salz", 15.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Paprika", 20.0).collective(CollectiveName::Gewuerze).build())
+        .build();
+    let output = free_order_calculator().execute(input);
+    assert!(output.validation_messages.contains_key("ingredients[2][collective_name]"));
+    assert_eq!(label_names(&output), vec!["Kartoffeln", "Paprika", "Salz"]);
+}
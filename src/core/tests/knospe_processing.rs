@@ -0,0 +1,99 @@
+use super::*;
+
+const STEPS: &str = "ingredients[0][processing_steps]";
+
+fn processing_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::Knospe_Verarbeitungsschritte])
+}
+
+fn with_steps(name: &str, category: &str, steps: Vec<&str>) -> Input {
+    InputBuilder::new()
+        .vollstaendig()
+        .ingredient(
+            IngredientBuilder::new_agri(name, 1000.0)
+                .category(category)
+                .processing_steps(steps)
+                .origin(Country::CH)
+                .build(),
+        )
+        .build()
+}
+
+#[test]
+fn whitelisted_steps_pass() {
+    let output = processing_calculator().execute(with_steps("Milch", "Milch", vec!["pasteurisiert", "mikrofiltriert"]));
+    assert!(!output.validation_messages.contains_key(STEPS), "{:?}", output.validation_messages);
+}
+
+#[test]
+fn steps_match_in_french_and_ignore_case() {
+    let output = processing_calculator().execute(with_steps("Lait", "Milch", vec!["Pasteurisé"]));
+    assert!(!output.validation_messages.contains_key(STEPS), "{:?}", output.validation_messages);
+}
+
+#[test]
+fn wildsammlung_is_always_allowed() {
+    let output = processing_calculator().execute(with_steps("Milch", "Milch", vec!["aus zertifizierter Wildsammlung"]));
+    assert!(!output.validation_messages.contains_key(STEPS));
+}
+
+#[test]
+fn forbidden_step_is_flagged() {
+    let output = processing_calculator().execute(with_steps("Milch", "Milch", vec!["pasteurisiert", "bestrahlt"]));
+    let messages = &output.validation_messages[STEPS];
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].contains("«bestrahlt» ist bei «Milch» für Knospe-Produkte nicht zulässig"), "{}", messages[0]);
+}
+
+#[test]
+fn approval_requiring_step_is_flagged() {
+    let output = processing_calculator().execute(with_steps("Hafer", "Hülsenfrüchte", vec!["extrudiert"]));
+    let messages = &output.validation_messages[STEPS];
+    assert!(messages[0].contains("bewilligungspflichtig"), "{}", messages[0]);
+}
+
+#[test]
+fn unlisted_step_needs_manual_review_but_does_not_block() {
+    let output = processing_calculator().execute(with_steps("Milch", "Milch", vec!["extrudiert"]));
+    assert!(!output.validation_messages.contains_key(STEPS), "{:?}", output.validation_messages);
+    assert_eq!(output.verdicts.processing_review, vec!["Milch".to_string()]);
+}
+
+#[test]
+fn ordinary_step_missing_from_the_list_does_not_block() {
+    let output = processing_calculator().execute(with_steps("Linsen", "Hülsenfrüchte", vec!["gemahlen"]));
+    assert!(output.validation_messages.is_empty(), "{:?}", output.validation_messages);
+    assert_eq!(output.verdicts.processing_review, vec!["Linsen".to_string()]);
+}
+
+#[test]
+fn composite_components_are_checked_under_the_parent() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(
+            IngredientBuilder::new("Joghurtzubereitung", 1000.0)
+                .children(vec![
+                    IngredientBuilder::new_agri("Milch", 900.0).category("Milch").processing_steps(vec!["ionisiert"]).build(),
+                    IngredientBuilder::new_agri("Erdbeeren", 100.0).category("Früchte frisch").build(),
+                ])
+                .build(),
+        )
+        .build();
+    let output = processing_calculator().execute(input);
+    assert!(output.validation_messages[STEPS][0].contains("«ionisiert»"));
+}
+
+#[test]
+fn unmapped_category_needs_manual_review() {
+    let output = processing_calculator().execute(with_steps("Vitaminmischung", "Nahrungsergänzungsmittel", vec!["bestrahlt"]));
+    assert!(!output.validation_messages.contains_key(STEPS));
+    assert_eq!(output.verdicts.processing_review, vec!["Vitaminmischung".to_string()]);
+}
+
+#[test]
+fn other_configurations_do_not_check_processing() {
+    let calculator = calculator_with(vec![RuleDef::AP1_1_ZutatMengeValidierung]);
+    let output = calculator.execute(with_steps("Milch", "Milch", vec!["bestrahlt"]));
+    assert!(!output.validation_messages.contains_key(STEPS));
+    assert!(output.verdicts.processing_review.is_empty());
+}
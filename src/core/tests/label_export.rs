@@ -0,0 +1,62 @@
+// Druckfertige Etikette: der formatunabhängige `LabelDocument` wird aus dem
+// echten `Form` und dem Calculator-Ergebnis gebaut, damit PDF, SVG und
+// Thermodrucker dieselben Angaben bekommen wie die Vorschau.
+
+use super::*;
+use crate::label_document::{LabelDocument, LabelLogo, TextSize};
+use crate::label_layout::{layout, LabelSize};
+use crate::pages::label_page::{Form, MonoQuality};
+use crate::shared::Configuration;
+
+fn knospe_weizenmehl() -> (Form, crate::core::Output) {
+    rust_i18n::set_locale("de-CH");
+    let form = Form {
+        ignore_ingredients: true,
+        mono_quality: MonoQuality::KnospeCh,
+        product_title: "Hofmehl".to_string(),
+        product_subtitle: "Weizenmehl".to_string(),
+        rezeptur_vollstaendig: true,
+        producer_name: "Hof Muster".to_string(),
+        producer_zip: "3000".to_string(),
+        producer_city: "Bern".to_string(),
+        certification_body: "CH-BIO-006".to_string(),
+        ..Form::default()
+    };
+    let output = calculator_for(Configuration::Knospe).execute(form.clone().into());
+    (form, output)
+}
+
+#[test]
+fn knospe_label_carries_logo_heading_address_and_certification() {
+    let (form, output) = knospe_weizenmehl();
+    let document = LabelDocument::new(&form, &output, true);
+
+    assert_eq!(document.logo, Some(LabelLogo::Knospe));
+    let heading = &document.sections[0];
+    assert_eq!(heading[0].size, TextSize::Title);
+    assert_eq!(heading[0].text(), "Hofmehl");
+    assert_eq!(heading[1].size, TextSize::Designation);
+    assert_eq!(heading[1].text(), "Weizenmehl Bio");
+
+    let texts: Vec<String> = document.sections.iter().flatten().map(|l| l.text()).collect();
+    assert!(texts.contains(&"Hof Muster".to_string()));
+    assert!(texts.contains(&"3000 Bern".to_string()));
+    assert_eq!(texts.last().map(String::as_str), Some("Bio-Zertifizierung: CH-BIO-006"));
+}
+
+#[test]
+fn certification_line_is_left_out_outside_bio_and_knospe() {
+    let (form, output) = knospe_weizenmehl();
+    let document = LabelDocument::new(&form, &output, false);
+    assert!(document.sections.iter().flatten().all(|l| !l.text().starts_with("Bio-Zertifizierung")));
+}
+
+#[test]
+fn short_label_fits_the_default_size_at_full_body_size() {
+    let (form, output) = knospe_weizenmehl();
+    let document = LabelDocument::new(&form, &output, true);
+    let label = layout(&document, LabelSize::default(), "de-CH");
+    assert!(!label.overflow);
+    assert!(label.logo.is_some());
+    assert!(label.runs.iter().any(|r| r.text.contains("Weizenmehl Bio")));
+}
@@ -0,0 +1,151 @@
+use super::*;
+use crate::mandatory_warnings::WarningKind;
+
+fn kinds(output: &Output) -> Vec<WarningKind> {
+    output.verdicts.mandatory_warnings.iter().map(|w| w.kind).collect()
+}
+
+#[test]
+fn azo_dye_names_the_dye_in_the_warning() {
+    let calculator = calculator_with(vec![RuleDef::AP3_1_PflichthinweiseZutaten]);
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Zucker", 500.0).build())
+        .ingredient(IngredientBuilder::new("Farbstoff E 110", 1.0).agricultural(false).build())
+        .build();
+    let output = calculator.execute(input);
+    assert_eq!(kinds(&output), vec![WarningKind::AzoDyes]);
+    assert_eq!(
+        output.verdicts.mandatory_warnings[0].text(),
+        "Farbstoff E 110: Kann Aktivität und Aufmerksamkeit bei Kindern beeinträchtigen."
+    );
+}
+
+#[test]
+fn aspartame_triggers_sweetener_and_phenylalanine() {
+    let calculator = calculator_with(vec![RuleDef::AP3_1_PflichthinweiseZutaten]);
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Wasser", 990.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Aspartam", 1.0).agricultural(false).build())
+        .build();
+    let output = calculator.execute(input);
+    assert_eq!(kinds(&output), vec![WarningKind::Sweeteners, WarningKind::Phenylalanine]);
+}
+
+#[test]
+fn polyols_need_more_than_ten_percent_for_the_laxative_warning() {
+    let calculator = calculator_with(vec![RuleDef::AP3_1_PflichthinweiseZutaten]);
+    // 6% Sorbit + 6% Xylit = 12% polyols: the threshold applies to the sum.
+    let over = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Kakaomasse", 88.0).build())
+        .ingredient(IngredientBuilder::new("Sorbit", 6.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Xylit", 6.0).agricultural(false).build())
+        .build();
+    assert!(kinds(&calculator.execute(over)).contains(&WarningKind::Laxative));
+
+    let under = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Kakaomasse", 95.0).build())
+        .ingredient(IngredientBuilder::new("Sorbit", 5.0).agricultural(false).build())
+        .build();
+    let output = calculator.execute(under);
+    assert!(!kinds(&output).contains(&WarningKind::Laxative));
+    // Still a sweetener, whatever its share.
+    assert!(kinds(&output).contains(&WarningKind::Sweeteners));
+}
+
+#[test]
+fn polyol_shares_come_from_the_weight_bearing_node() {
+    let calculator = calculator_with(vec![RuleDef::AP3_1_PflichthinweiseZutaten]);
+    // The parent weight counts; the children only describe it (no amounts
+    // of their own, or overridden). 150 g of 1000 g = 15%.
+    let without_amounts = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Kakaomasse", 850.0).build())
+        .ingredient(
+            IngredientBuilder::new("Sorbit", 150.0)
+                .agricultural(false)
+                .children(vec![
+                    IngredientBuilder::new("Sorbit", 0.0).agricultural(false).build(),
+                    IngredientBuilder::new("Wasser", 0.0).agricultural(false).build(),
+                ])
+                .build(),
+        )
+        .build();
+    assert!(kinds(&calculator.execute(without_amounts)).contains(&WarningKind::Laxative));
+
+    let overridden = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Kakaomasse", 850.0).build())
+        .ingredient(
+            IngredientBuilder::new("Sorbit", 150.0)
+                .agricultural(false)
+                .override_children()
+                .children(vec![
+                    IngredientBuilder::new("Sorbit", 70.0).agricultural(false).build(),
+                    IngredientBuilder::new("Wasser", 30.0).agricultural(false).build(),
+                ])
+                .build(),
+        )
+        .build();
+    assert!(kinds(&calculator.execute(overridden)).contains(&WarningKind::Laxative));
+}
+
+#[test]
+fn caffeine_threshold_uses_the_manual_total() {
+    let calculator = calculator_with(vec![
+        RuleDef::AP1_4_ManuelleEingabeTotal,
+        RuleDef::AP3_1_PflichthinweiseZutaten,
+    ]);
+    // 0.32 g caffeine in 1000 g = 320 mg/kg, above the 150 mg/l line.
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Wasser", 2000.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Koffein", 0.32).agricultural(false).build())
+        .total(1000.0)
+        .build();
+    assert_eq!(kinds(&calculator.execute(input)), vec![WarningKind::Caffeine]);
+
+    let diluted = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Wasser", 2000.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Koffein", 0.1).agricultural(false).build())
+        .build();
+    assert!(kinds(&calculator.execute(diluted)).is_empty());
+}
+
+#[test]
+fn sub_ingredients_of_a_composite_trigger_warnings() {
+    let calculator = calculator_with(vec![RuleDef::AP3_1_PflichthinweiseZutaten]);
+    let input = InputBuilder::new()
+        .ingredient(
+            IngredientBuilder::new("Glasur", 0.0)
+                .children(vec![
+                    IngredientBuilder::new("Zucker", 40.0).build(),
+                    IngredientBuilder::new("Tartrazin", 0.5).agricultural(false).build(),
+                ])
+                .build(),
+        )
+        .build();
+    let output = calculator.execute(input);
+    assert_eq!(kinds(&output), vec![WarningKind::AzoDyes]);
+    assert_eq!(output.verdicts.mandatory_warnings[0].substances, vec!["Tartrazin".to_string()]);
+}
+
+#[test]
+fn no_warnings_without_the_rule() {
+    let calculator = calculator_with(vec![]);
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Aspartam", 1.0).build())
+        .build();
+    assert!(calculator.execute(input).verdicts.mandatory_warnings.is_empty());
+}
+
+#[test]
+fn every_configuration_adds_mandatory_warnings() {
+    for config in [
+        crate::shared::Configuration::Conventional,
+        crate::shared::Configuration::Bio,
+        crate::shared::Configuration::Knospe,
+    ] {
+        let calculator = calculator_for(config);
+        let input = InputBuilder::new()
+            .ingredient(IngredientBuilder::new("Aspartam", 1.0).build())
+            .build();
+        assert!(!calculator.execute(input).verdicts.mandatory_warnings.is_empty());
+    }
+}
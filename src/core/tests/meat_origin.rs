@@ -0,0 +1,94 @@
+use super::*;
+
+fn meat_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::AP7_4_FleischHerkunftDetails])
+}
+
+#[test]
+fn pork_requires_rearing_and_slaughter_place() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Schweinefleisch", 300.0).category("Schwein").build())
+        .build();
+    let output = meat_calculator().execute(input);
+    let rearing = &output.validation_messages["ingredients[0][aufzucht_ort]"];
+    let slaughter = &output.validation_messages["ingredients[0][schlachtungs_ort]"];
+    assert!(rearing.iter().any(|m| m == "Aufzuchtort ist erforderlich für Schweinefleisch-Zutaten."));
+    assert!(slaughter.iter().any(|m| m == "Schlachtungsort ist erforderlich für Schweinefleisch-Zutaten."));
+}
+
+#[test]
+fn poultry_renders_reared_and_slaughtered() {
+    let input = InputBuilder::new()
+        .ingredient(
+            IngredientBuilder::new("Pouletbrust", 500.0)
+                .category("Geflügel")
+                .aufzucht(Country::HU)
+                .schlachtung(Country::AT)
+                .build(),
+        )
+        .build();
+    let output = meat_calculator().execute(input);
+    assert_eq!(output.label, "Pouletbrust (Aufgezogen in: HU, Geschlachtet in: AT)");
+}
+
+#[test]
+fn lamb_and_goat_are_covered() {
+    for (name, category) in [("Lammfleisch", "Lamm, Schaf"), ("Gitzifleisch", "Ziege")] {
+        let input = InputBuilder::new()
+            .vollstaendig()
+            .ingredient(IngredientBuilder::new(name, 300.0).category(category).build())
+            .build();
+        let output = meat_calculator().execute(input);
+        assert!(output.validation_messages.contains_key("ingredients[0][aufzucht_ort]"), "{name}");
+        assert!(output.validation_messages.contains_key("ingredients[0][schlachtungs_ort]"), "{name}");
+    }
+}
+
+#[test]
+fn sheep_and_goat_dairy_needs_no_meat_details() {
+    for (name, category) in [("Schafmilch", "Schafmilch"), ("Ziegenkäse", "Ziegenkäse")] {
+        let input = InputBuilder::new()
+            .vollstaendig()
+            .ingredient(IngredientBuilder::new(name, 300.0).category(category).build())
+            .build();
+        let output = meat_calculator().execute(input);
+        assert!(!output.validation_messages.contains_key("ingredients[0][aufzucht_ort]"), "{name}");
+        assert!(!output.validation_messages.contains_key("ingredients[0][schlachtungs_ort]"), "{name}");
+    }
+}
+
+#[test]
+fn beef_keeps_the_birthplace_wording() {
+    let input = InputBuilder::new()
+        .ingredient(
+            IngredientBuilder::new("Kalbfleisch", 500.0)
+                .category("Kalb")
+                .aufzucht(Country::CH)
+                .schlachtung(Country::CH)
+                .build(),
+        )
+        .build();
+    let output = meat_calculator().execute(input);
+    assert_eq!(output.label, "Kalbfleisch (Geburtsort: CH, Geschlachtet in: CH)");
+}
+
+#[test]
+fn details_replace_the_plain_origin() {
+    let calculator = calculator_with(vec![
+        RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent,
+        RuleDef::AP7_4_FleischHerkunftDetails,
+    ]);
+    let input = InputBuilder::new()
+        .ingredient(
+            IngredientBuilder::new("Schweinefleisch", 800.0)
+                .category("Schwein")
+                .origin(Country::CH)
+                .aufzucht(Country::CH)
+                .schlachtung(Country::CH)
+                .build(),
+        )
+        .build();
+    let output = calculator.execute(input);
+    assert_eq!(output.label, "Schweinefleisch (Aufgezogen in: CH, Geschlachtet in: CH)");
+}
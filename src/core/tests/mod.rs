@@ -6,7 +6,7 @@ pub(super) use crate::model::{lookup_agricultural, Country};
 pub(super) fn setup_simple_calculator() -> Calculator {
     rust_i18n::set_locale("de-CH");
     let rule_defs = vec![];
-    Calculator { rule_defs }
+    Calculator { rule_defs, configuration: None }
 }
 
 /// Calculator with exactly the given rules active.
@@ -16,7 +16,7 @@ pub(super) fn setup_simple_calculator() -> Calculator {
 /// c.registerRuleDefs(vec![..]);` pair that appeared ~140 times.
 pub(super) fn calculator_with(rule_defs: Vec<RuleDef>) -> Calculator {
     rust_i18n::set_locale("de-CH");
-    Calculator { rule_defs }
+    Calculator { rule_defs, configuration: None }
 }
 
 pub(super) fn calculator_for(config: crate::shared::Configuration) -> Calculator {
@@ -74,12 +74,31 @@ impl IngredientBuilder {
     pub fn aufzucht(mut self, country: Country) -> Self { self.0.aufzucht_ort = Some(country); self }
     pub fn schlachtung(mut self, country: Country) -> Self { self.0.schlachtungs_ort = Some(country); self }
     pub fn fangort(mut self, country: Country) -> Self { self.0.fangort = Some(country); self }
+    pub fn fish_origin(mut self, origin: crate::fish_origin::FishOrigin) -> Self { self.0.fish_origin = Some(origin); self }
+    pub fn egg_housing(mut self, housing: crate::production_methods::EggHousing) -> Self { self.0.egg_housing = Some(housing); self }
+    pub fn performance_enhancers(mut self, enhancers: crate::production_methods::PerformanceEnhancers) -> Self { self.0.performance_enhancers = Some(enhancers); self }
+    pub fn mountain_provenance(mut self, provenance: crate::mountain_designation::MountainProvenance) -> Self { self.0.mountain_provenance = Some(provenance); self }
+    pub fn certificate(mut self, supplier: &str, valid_until: &str) -> Self {
+        self.0.supplier_certificate = Some(crate::supplier_certificate::SupplierCertificate {
+            supplier: supplier.to_string(),
+            number: String::new(),
+            valid_until: valid_until.to_string(),
+        });
+        self
+    }
     pub fn agricultural(mut self, val: bool) -> Self { self.0.is_agricultural = val; self }
     pub fn bio(mut self) -> Self { self.0.is_bio = Some(true); self }
     pub fn bio_ch(mut self) -> Self { self.0.bio_ch = Some(true); self }
     pub fn umstellbetrieb(mut self) -> Self { self.0.aus_umstellbetrieb = Some(true); self }
+    pub fn umstellung_bis(mut self, date: &str) -> Self { self.0.umstellung_bis = Some(date.to_string()); self }
+    pub fn processing_aid(mut self) -> Self { self.0.is_processing_aid = Some(true); self }
+    pub fn reconstitution(mut self, factor: f64) -> Self { self.0.reconstitution_factor = Some(factor); self }
+    pub fn collective(mut self, name: CollectiveName) -> Self { self.0.collective_name = Some(name); self }
+    pub fn compound_exemption(mut self, exemption: CompoundExemption) -> Self { self.0.compound_exemption = Some(exemption); self }
+    pub fn origin_aggregation(mut self, aggregation: OriginAggregation) -> Self { self.0.origin_aggregation = Some(aggregation); self }
     pub fn erlaubte_ausnahme_bio(mut self) -> Self { self.0.erlaubte_ausnahme_bio = Some(true); self }
     pub fn erlaubte_ausnahme_knospe(mut self) -> Self { self.0.erlaubte_ausnahme_knospe = Some(true); self }
+    pub fn erlaubte_ausnahme_knospe_details(mut self, details: &str) -> Self { self.0.erlaubte_ausnahme_knospe_details = Some(details.to_string()); self }
     pub fn sub_components(mut self, subs: Vec<SubIngredient>) -> Self { self.0.sub_components = Some(subs); self }
     pub fn children(mut self, kids: Vec<Ingredient>) -> Self { self.0.children = Some(kids); self }
     pub fn processing_steps(mut self, steps: Vec<&str>) -> Self { self.0.processing_steps = Some(steps.iter().map(|s| s.to_string()).collect()); self }
@@ -100,7 +119,15 @@ impl InputBuilder {
     pub fn total(mut self, t: f64) -> Self { self.0.total = Some(t); self }
     pub fn vollstaendig(mut self) -> Self { self.0.rezeptur_vollstaendig = true; self }
     pub fn einzelzutat(mut self) -> Self { self.0.ignore_ingredients = true; self }
+    pub fn freie_reihenfolge(mut self) -> Self { self.0.freie_reihenfolge_unter_2_prozent = true; self }
+    pub fn title(mut self, title: &str) -> Self { self.0.product_title = title.to_string(); self }
+    pub fn subtitle(mut self, subtitle: &str) -> Self { self.0.product_subtitle = subtitle.to_string(); self }
+    pub fn origin_aggregation(mut self, aggregation: OriginAggregation) -> Self { self.0.origin_aggregation = aggregation; self }
+    pub fn origin_style(mut self, style: OriginStyle) -> Self { self.0.origin_style = style; self }
     pub fn certification_body(mut self, body: &str) -> Self { self.0.certification_body = Some(body.to_string()); self }
+    pub fn production_date(mut self, date: &str) -> Self { self.0.production_date = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok(); self }
+    pub fn best_before(mut self, date: &str) -> Self { self.0.best_before = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok(); self }
+    pub fn protected_designation_certificate(mut self, certificate: &str) -> Self { self.0.protected_designation_certificate = Some(certificate.to_string()); self }
     pub fn build(self) -> Input { self.0 }
 }
 
@@ -121,3 +148,24 @@ mod saved_ingredients;
 mod declaration_name;
 mod mono_quality;
 mod conditional_invariants;
+mod mandatory_warnings;
+mod processing_aids;
+mod ingredient_order;
+mod compound_exemption;
+mod origin_emphasis;
+mod swissness;
+mod origin_aggregation;
+mod origin_style;
+mod meat_origin;
+mod fish_origin;
+mod production_methods;
+mod honey_origin;
+mod regional_origin;
+mod mountain_designation;
+mod protected_designations;
+mod knospe_processing;
+mod recipe_declaration;
+mod supplier_certificates;
+mod conversion_end;
+mod certification_bodies;
+mod label_export;
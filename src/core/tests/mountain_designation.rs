@@ -0,0 +1,135 @@
+use super::*;
+use crate::mountain_designation::{MountainDesignation, MountainProvenance};
+use crate::swiss_regions::SwissRegion;
+use crate::verdicts::{MountainBlockReason, MountainVerdict};
+
+fn mountain_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::BAlV_BergAlpBezeichnung])
+}
+
+fn from(name: &str, amount: f64, provenance: MountainProvenance) -> IngredientBuilder {
+    IngredientBuilder::new_agri(name, amount).origin(Country::CH).mountain_provenance(provenance)
+}
+
+#[test]
+fn title_without_designation_has_no_verdict() {
+    let input = InputBuilder::new()
+        .title("Käse")
+        .ingredient(IngredientBuilder::new_agri("Milch", 1000.0).origin(Country::CH).build())
+        .build();
+    let output = mountain_calculator().execute(input);
+    assert_eq!(output.verdicts.mountain_designation, None);
+    assert!(!output.validation_messages.contains_key("product_title"));
+}
+
+#[test]
+fn berg_with_ninety_percent_from_the_mountain_area_is_allowed() {
+    let input = InputBuilder::new()
+        .title("Bergbrot")
+        .ingredient(from("Weizenmehl", 900.0, MountainProvenance::Berggebiet).build())
+        .ingredient(IngredientBuilder::new_agri("Sonnenblumenkerne", 100.0).origin(Country::FR).build())
+        .ingredient(IngredientBuilder::new("Wasser", 500.0).agricultural(false).build())
+        .build();
+    let output = mountain_calculator().execute(input);
+    assert_eq!(
+        output.verdicts.mountain_designation,
+        Some(MountainVerdict::Allowed { designation: MountainDesignation::Berg, share: 90.0 })
+    );
+    assert!(!output.validation_messages.contains_key("product_title"));
+}
+
+#[test]
+fn berg_below_ninety_percent_is_flagged_on_the_title() {
+    let input = InputBuilder::new()
+        .subtitle("Bergkäse")
+        .ingredient(from("Rahm", 800.0, MountainProvenance::Berggebiet).build())
+        .ingredient(IngredientBuilder::new_agri("Haselnüsse", 200.0).origin(Country::IT).build())
+        .build();
+    let output = mountain_calculator().execute(input);
+    assert!(matches!(
+        output.verdicts.mountain_designation,
+        Some(MountainVerdict::NotAllowed { designation: MountainDesignation::Berg, reasons, .. })
+            if reasons == vec![MountainBlockReason::ShareBelow90]
+    ));
+    assert!(output.validation_messages["product_title"][0].contains("Berg"));
+}
+
+#[test]
+fn summering_area_counts_for_berg_but_mountain_area_not_for_alp() {
+    let ingredients = || {
+        vec![
+            from("Weizenmehl", 500.0, MountainProvenance::Soemmerungsgebiet).build(),
+            from("Dinkelmehl", 500.0, MountainProvenance::Berggebiet).build(),
+        ]
+    };
+    let berg = mountain_calculator().execute(InputBuilder::new().title("Bergbrot").ingredients(ingredients()).build());
+    assert!(matches!(berg.verdicts.mountain_designation, Some(MountainVerdict::Allowed { share, .. }) if share == 100.0));
+
+    let alp = mountain_calculator().execute(InputBuilder::new().title("Alpbrot").ingredients(ingredients()).build());
+    assert!(matches!(
+        alp.verdicts.mountain_designation,
+        Some(MountainVerdict::NotAllowed { designation: MountainDesignation::Alp, share, .. }) if share == 50.0
+    ));
+}
+
+#[test]
+fn milk_must_come_entirely_from_the_area() {
+    let input = InputBuilder::new()
+        .title("Alpbutter-Zopf")
+        .ingredient(from("Weizenmehl", 950.0, MountainProvenance::Soemmerungsgebiet).build())
+        .ingredient(IngredientBuilder::new_agri("Butter", 50.0).category("Butter").origin(Country::CH).build())
+        .build();
+    let output = mountain_calculator().execute(input);
+    assert_eq!(
+        output.verdicts.mountain_designation,
+        Some(MountainVerdict::NotAllowed {
+            designation: MountainDesignation::Alp,
+            share: 95.0,
+            reasons: vec![MountainBlockReason::MilkOutsideArea { ingredients: vec!["Butter".to_string()] }],
+        })
+    );
+}
+
+#[test]
+fn milk_outside_the_area_is_named_once() {
+    let input = InputBuilder::new()
+        .title("Bergkäse")
+        .ingredient(IngredientBuilder::new_agri("Milch", 450.0).category("Milch").origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new_agri("Rahm", 100.0).category("Rahm").origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new_agri("Milch", 450.0).category("Milch").origin(Country::CH).build())
+        .build();
+    let output = mountain_calculator().execute(input);
+    assert!(matches!(
+        output.verdicts.mountain_designation,
+        Some(MountainVerdict::NotAllowed { reasons, .. })
+            if reasons.contains(&MountainBlockReason::MilkOutsideArea {
+                ingredients: vec!["Milch".to_string(), "Rahm".to_string()],
+            })
+    ));
+}
+
+#[test]
+fn zone_origin_counts_as_provenance() {
+    let input = InputBuilder::new()
+        .title("Alpkäse")
+        .ingredient(
+            IngredientBuilder::new_agri("Milch", 1000.0)
+                .category("Milch")
+                .origin(Country::Region(SwissRegion::Soemmerungsgebiet))
+                .build(),
+        )
+        .build();
+    let output = mountain_calculator().execute(input);
+    assert!(matches!(output.verdicts.mountain_designation, Some(MountainVerdict::Allowed { .. })));
+}
+
+#[test]
+fn without_the_rule_there_is_no_verdict() {
+    let input = InputBuilder::new()
+        .title("Bergkäse")
+        .ingredient(IngredientBuilder::new_agri("Milch", 1000.0).origin(Country::DE).build())
+        .build();
+    let output = calculator_with(vec![]).execute(input);
+    assert_eq!(output.verdicts.mountain_designation, None);
+    assert!(!output.validation_messages.contains_key("product_title"));
+}
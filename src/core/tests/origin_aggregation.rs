@@ -0,0 +1,96 @@
+use super::*;
+
+fn origin_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent])
+}
+
+fn blend() -> IngredientBuilder {
+    IngredientBuilder::new("Honig", 1000.0)
+        .origins(vec![Country::DE, Country::FR, Country::IT, Country::ES, Country::AT])
+}
+
+#[test]
+fn country_groups() {
+    assert_eq!(Country::DE.groups(), vec![CountryGroup::Eu]);
+    assert_eq!(Country::NO.groups(), vec![CountryGroup::Efta, CountryGroup::NichtEu]);
+    assert_eq!(Country::CH.groups(), vec![CountryGroup::Efta, CountryGroup::NichtEu]);
+    assert_eq!(Country::BR.groups(), vec![CountryGroup::NichtEu]);
+    assert!(Country::Import.groups().is_empty());
+}
+
+#[test]
+fn countries_are_listed_by_default() {
+    let input = InputBuilder::new().ingredient(blend().build()).build();
+    let output = origin_calculator().execute(input);
+    assert_eq!(output.label, "Honig (DE, FR, IT, ES, AT)");
+}
+
+#[test]
+fn label_wide_choice_groups_eu_countries() {
+    let input = InputBuilder::new()
+        .origin_aggregation(OriginAggregation::EuNichtEu)
+        .ingredient(blend().build())
+        .build();
+    let output = origin_calculator().execute(input);
+    assert_eq!(output.label, "Honig (EU)");
+}
+
+#[test]
+fn mixed_groups_and_switzerland_stays_named() {
+    let input = InputBuilder::new()
+        .origin_aggregation(OriginAggregation::EuNichtEu)
+        .ingredient(IngredientBuilder::new("Honig", 1000.0).origins(vec![Country::CH, Country::DE, Country::MX]).build())
+        .build();
+    let output = origin_calculator().execute(input);
+    assert_eq!(output.label, "Honig (CH, EU/Nicht-EU)");
+}
+
+#[test]
+fn diverse_laender_needs_three_countries() {
+    let three = InputBuilder::new()
+        .origin_aggregation(OriginAggregation::DiverseLaender)
+        .ingredient(blend().build())
+        .build();
+    assert_eq!(origin_calculator().execute(three).label, "Honig (diverse Länder)");
+
+    let two = InputBuilder::new()
+        .origin_aggregation(OriginAggregation::DiverseLaender)
+        .ingredient(IngredientBuilder::new("Honig", 1000.0).origins(vec![Country::DE, Country::MX]).build())
+        .build();
+    assert_eq!(origin_calculator().execute(two).label, "Honig (EU/Nicht-EU)");
+}
+
+#[test]
+fn single_country_is_always_named() {
+    let input = InputBuilder::new()
+        .origin_aggregation(OriginAggregation::EuNichtEu)
+        .ingredient(IngredientBuilder::new("Honig", 1000.0).origin(Country::DE).build())
+        .build();
+    assert_eq!(origin_calculator().execute(input).label, "Honig (DE)");
+}
+
+#[test]
+fn ingredient_choice_overrides_the_label() {
+    let input = InputBuilder::new()
+        .origin_aggregation(OriginAggregation::EuNichtEu)
+        .ingredient(blend().origin_aggregation(OriginAggregation::Laender).build())
+        .build();
+    let output = origin_calculator().execute(input);
+    assert_eq!(output.label, "Honig (DE, FR, IT, ES, AT)");
+}
+
+#[test]
+fn meat_origin_is_never_aggregated() {
+    let calculator = calculator_with(vec![RuleDef::AP7_3_HerkunftFleischUeber20Prozent]);
+    let input = InputBuilder::new()
+        .origin_aggregation(OriginAggregation::EuNichtEu)
+        .ingredient(
+            IngredientBuilder::new("Schweinefleisch", 1000.0)
+                .category("Fleisch und Innereien")
+                .origins(vec![Country::DE, Country::AT])
+                .build(),
+        )
+        .build();
+    let output = calculator.execute(input);
+    assert!(output.label.contains("(DE, AT)"), "label: {}", output.label);
+}
@@ -0,0 +1,99 @@
+use super::*;
+
+fn statements(output: &Output) -> Vec<String> {
+    output.verdicts.primary_origin_statements.iter().map(|s| s.text()).collect()
+}
+
+#[test]
+fn swiss_title_with_foreign_primary_ingredient_needs_statement() {
+    let calculator = calculator_with(vec![RuleDef::AP7_6_HerkunftPrimaerzutat]);
+    let input = InputBuilder::new()
+        .title("Schweizer Apfelmus")
+        .ingredient(IngredientBuilder::new("Äpfel", 900.0).origin(Country::DE).build())
+        .ingredient(IngredientBuilder::new("Zucker", 100.0).origin(Country::CH).build())
+        .build();
+    let output = calculator.execute(input);
+    assert_eq!(statements(&output), vec!["Äpfel aus: DE"]);
+}
+
+#[test]
+fn statement_spells_origins_like_the_ingredient_list() {
+    let calculator = calculator_with(vec![
+        RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent,
+        RuleDef::AP7_6_HerkunftPrimaerzutat,
+    ]);
+    let input = InputBuilder::new()
+        .title("Schweizer Apfelmus")
+        .origin_style(OriginStyle::Full)
+        .ingredient(IngredientBuilder::new("Äpfel", 900.0).origin(Country::DE).build())
+        .ingredient(IngredientBuilder::new("Zucker", 100.0).origin(Country::CH).build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(output.label.starts_with("Äpfel (Deutschland)"), "{}", output.label);
+    assert_eq!(statements(&output), vec!["Äpfel aus: Deutschland"]);
+}
+
+#[test]
+fn swiss_primary_ingredient_needs_no_statement() {
+    let calculator = calculator_with(vec![RuleDef::AP7_6_HerkunftPrimaerzutat]);
+    let input = InputBuilder::new()
+        .title("Schweizer Apfelmus")
+        .ingredient(IngredientBuilder::new("Äpfel", 900.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new("Zucker", 100.0).origin(Country::DE).build())
+        .build();
+    assert!(statements(&calculator.execute(input)).is_empty());
+}
+
+#[test]
+fn without_emphasis_nothing_is_required() {
+    let calculator = calculator_with(vec![RuleDef::AP7_6_HerkunftPrimaerzutat]);
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .title("Apfelmus")
+        .ingredient(IngredientBuilder::new("Äpfel", 900.0).build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(statements(&output).is_empty());
+    assert!(output.validation_messages.is_empty());
+}
+
+#[test]
+fn namensgebende_ingredient_counts_as_primary() {
+    let calculator = calculator_with(vec![RuleDef::AP7_6_HerkunftPrimaerzutat]);
+    let input = InputBuilder::new()
+        .title("Schweizer Erdbeerjoghurt")
+        .ingredient(IngredientBuilder::new("Joghurt", 850.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new("Erdbeeren", 150.0).origins(vec![Country::ES, Country::IT]).namensgebend().build())
+        .build();
+    let output = calculator.execute(input);
+    assert_eq!(statements(&output), vec!["Erdbeeren aus: ES, IT"]);
+}
+
+#[test]
+fn unknown_primary_origin_is_a_validation_error() {
+    let calculator = calculator_with(vec![RuleDef::AP7_6_HerkunftPrimaerzutat]);
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .title("Schweizer Apfelmus")
+        .ingredient(IngredientBuilder::new("Äpfel", 900.0).build())
+        .ingredient(IngredientBuilder::new("Zucker", 100.0).build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(output.validation_messages.contains_key("ingredients[0][origin]"));
+    assert!(!output.validation_messages.contains_key("ingredients[1][origin]"));
+}
+
+#[test]
+fn bio_suisse_mark_is_no_swiss_emphasis() {
+    let calculator = calculator_with(vec![RuleDef::AP7_6_HerkunftPrimaerzutat]);
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .title("Apfelmus")
+        .subtitle("Bio Suisse")
+        .ingredient(IngredientBuilder::new("Äpfel", 900.0).origin(Country::DE).build())
+        .ingredient(IngredientBuilder::new("Zucker", 100.0).origin(Country::CH).build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(statements(&output).is_empty());
+    assert!(output.validation_messages.is_empty());
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+fn origin_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent])
+}
+
+fn honey(style: OriginStyle) -> Input {
+    InputBuilder::new()
+        .origin_style(style)
+        .ingredient(IngredientBuilder::new("Honig", 1000.0).origins(vec![Country::CH, Country::US]).build())
+        .build()
+}
+
+#[test]
+fn codes_by_default() {
+    assert_eq!(origin_calculator().execute(honey(OriginStyle::Code)).label, "Honig (CH, US)");
+}
+
+#[test]
+fn short_names_in_the_label_language() {
+    assert_eq!(origin_calculator().execute(honey(OriginStyle::Short)).label, "Honig (Schweiz, USA)");
+}
+
+#[test]
+fn full_names_in_the_label_language() {
+    assert_eq!(
+        origin_calculator().execute(honey(OriginStyle::Full)).label,
+        "Honig (Schweiz, Vereinigte Staaten)"
+    );
+}
+
+#[test]
+fn knospe_swiss_marker_follows_the_style() {
+    let calculator = calculator_with(vec![RuleDef::Knospe_90_99_Percent_CH_ShowOrigin]);
+    let input = InputBuilder::new()
+        .origin_style(OriginStyle::Full)
+        .ingredient(IngredientBuilder::new("Hafer", 950.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new("Rosinen", 50.0).origin(Country::TR).build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(output.label.contains("Hafer (Schweiz)"), "label: {}", output.label);
+}
+
+#[test]
+fn beef_details_follow_the_style() {
+    let calculator = calculator_with(vec![RuleDef::AP7_4_FleischHerkunftDetails]);
+    let input = InputBuilder::new()
+        .origin_style(OriginStyle::Full)
+        .ingredient(
+            IngredientBuilder::new("Rindfleisch", 1000.0)
+                .category("Rind")
+                .aufzucht(Country::AT)
+                .schlachtung(Country::CH)
+                .build(),
+        )
+        .build();
+    let output = calculator.execute(input);
+    assert!(output.label.contains("Geburtsort: Österreich"), "label: {}", output.label);
+    assert!(output.label.contains("Geschlachtet in: Schweiz"), "label: {}", output.label);
+}
+
+#[test]
+fn fish_catch_location_follows_the_style() {
+    let calculator = calculator_with(vec![RuleDef::AP7_5_FischFangort]);
+    let input = InputBuilder::new()
+        .origin_style(OriginStyle::Short)
+        .ingredient(IngredientBuilder::new("Lachs", 1000.0).category("Fisch").fangort(Country::NO).build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(output.label.contains("Lachs (Norwegen)"), "label: {}", output.label);
+}
+
+#[test]
+fn grouped_origins_name_switzerland_in_the_style() {
+    let input = InputBuilder::new()
+        .origin_style(OriginStyle::Short)
+        .origin_aggregation(OriginAggregation::EuNichtEu)
+        .ingredient(IngredientBuilder::new("Honig", 1000.0).origins(vec![Country::CH, Country::DE, Country::FR]).build())
+        .build();
+    assert_eq!(origin_calculator().execute(input).label, "Honig (Schweiz, EU)");
+}
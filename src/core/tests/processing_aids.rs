@@ -0,0 +1,82 @@
+use super::*;
+
+#[test]
+fn processing_aid_is_left_out_of_the_list() {
+    let calculator = calculator_with(vec![]);
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Mehl", 600.0).build())
+        .ingredient(IngredientBuilder::new("Wasser", 390.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Trennöl", 10.0).processing_aid().build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(!output.label.contains("Trennöl"), "label: {}", output.label);
+    assert!(output.label.contains("Mehl"));
+    // Still part of the recipe weight.
+    assert_eq!(output.total_amount, 1000.0);
+}
+
+#[test]
+fn allergenic_processing_aid_stays_declared() {
+    let calculator = calculator_with(vec![]);
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Mehl", 900.0).build())
+        .ingredient(IngredientBuilder::new("Sojalecithin", 10.0).allergen().processing_aid().build())
+        .build();
+    let output = calculator.execute(input);
+    assert!(output.label.contains("<b>Sojalecithin</b>"), "label: {}", output.label);
+}
+
+#[test]
+fn carry_over_inside_composite_is_dropped_from_the_parentheses() {
+    let calculator = calculator_with(vec![RuleDef::AP2_1_ZusammegesetztOutput]);
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Zucker", 500.0).build())
+        .ingredient(
+            IngredientBuilder::new("Schokolade", 500.0)
+                .children(vec![
+                    IngredientBuilder::new("Kakaomasse", 300.0).build(),
+                    IngredientBuilder::new("Zucker", 195.0).build(),
+                    IngredientBuilder::new("Antioxidationsmittel E 306", 5.0)
+                        .agricultural(false)
+                        .processing_aid()
+                        .build(),
+                ])
+                .build(),
+        )
+        .build();
+    let output = calculator.execute(input);
+    assert!(output.label.contains("Schokolade (Kakaomasse, Zucker)"), "label: {}", output.label);
+    assert!(!output.label.contains("E 306"));
+}
+
+#[test]
+fn composite_of_only_processing_aids_gets_no_parentheses() {
+    let ing = IngredientBuilder::new("Backpapierbeschichtung", 1.0)
+        .children(vec![IngredientBuilder::new("Silikon", 1.0).agricultural(false).processing_aid().build()])
+        .build();
+    assert_eq!(ing.composite_name(), "Backpapierbeschichtung");
+}
+
+#[test]
+fn non_bio_processing_aid_does_not_block_bio_composite() {
+    let composite = IngredientBuilder::new_agri("Brot", 1000.0)
+        .children(vec![
+            IngredientBuilder::new_agri("Weizenmehl", 990.0).bio().bio_ch().build(),
+            IngredientBuilder::new_agri("Trennöl", 10.0).processing_aid().build(),
+        ])
+        .build();
+    assert_eq!(composite.computed_bio_status(), Some(true));
+    assert!(!composite.has_undeclared_non_bio());
+}
+
+#[test]
+fn carry_over_sweetener_triggers_no_warning() {
+    let calculator = calculator_with(vec![RuleDef::AP3_1_PflichthinweiseZutaten]);
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Wasser", 900.0).agricultural(false).build())
+        .ingredient(IngredientBuilder::new("Sorbit", 100.0).agricultural(false).processing_aid().build())
+        .build();
+    let output = calculator.execute(input);
+    // A carry-over without function in the end product triggers no warning.
+    assert!(output.verdicts.mandatory_warnings.is_empty());
+}
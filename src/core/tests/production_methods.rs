@@ -0,0 +1,169 @@
+use super::*;
+use crate::production_methods::{EggHousing, PerformanceEnhancers, ProductionMethodKind};
+
+fn ldv_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::LDV_NichtZugelasseneProduktionsmethoden])
+}
+
+fn statements(output: &Output) -> Vec<String> {
+    output.verdicts.production_method_statements.iter().map(|s| s.text()).collect()
+}
+
+#[test]
+fn cage_eggs_get_the_statement() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Weizenmehl", 500.0).build())
+        .ingredient(
+            IngredientBuilder::new("Vollei", 200.0)
+                .category("Eier")
+                .origin(Country::PL)
+                .egg_housing(EggHousing::Kaefighaltung)
+                .build(),
+        )
+        .build();
+    let output = ldv_calculator().execute(input);
+    assert_eq!(statements(&output), vec!["Vollei: Eier aus in der Schweiz nicht zugelassener Käfighaltung"]);
+}
+
+#[test]
+fn permitted_housing_needs_no_statement() {
+    let input = InputBuilder::new()
+        .ingredient(
+            IngredientBuilder::new("Vollei", 200.0)
+                .category("Eier")
+                .origin(Country::DE)
+                .egg_housing(EggHousing::Bodenhaltung)
+                .build(),
+        )
+        .build();
+    let output = ldv_calculator().execute(input);
+    assert!(output.verdicts.production_method_statements.is_empty());
+}
+
+#[test]
+fn imported_eggs_need_the_housing() {
+    let imported = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Vollei", 200.0).category("Eier").origin(Country::FR).build())
+        .build();
+    let output = ldv_calculator().execute(imported);
+    assert!(output.validation_messages["ingredients[0][egg_housing]"]
+        .iter()
+        .any(|m| m == "Haltungsform ist erforderlich für importierte Eier."));
+
+    let swiss = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Vollei", 200.0).category("Eier").origin(Country::CH).build())
+        .build();
+    let output = ldv_calculator().execute(swiss);
+    assert!(!output.validation_messages.contains_key("ingredients[0][egg_housing]"));
+}
+
+#[test]
+fn imported_eggs_inside_a_composite_need_the_housing() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new("Weizenmehl", 300.0).origin(Country::CH).build())
+        .ingredient(
+            IngredientBuilder::new("Eierteigwaren", 700.0)
+                .children(vec![
+                    IngredientBuilder::new("Hartweizengriess", 500.0).origin(Country::CH).build(),
+                    IngredientBuilder::new("Vollei", 200.0).category("Eier").origin(Country::FR).build(),
+                ])
+                .build(),
+        )
+        .build();
+    let output = ldv_calculator().execute(input);
+    assert_eq!(
+        output.validation_messages["ingredients[1][egg_housing]"],
+        vec!["Haltungsform ist erforderlich für die importierten Eier «Vollei».".to_string()]
+    );
+}
+
+#[test]
+fn meat_with_performance_enhancers_gets_the_statement() {
+    let input = InputBuilder::new()
+        .ingredient(
+            IngredientBuilder::new("Rindfleisch", 300.0)
+                .category("Rind")
+                .origin(Country::US)
+                .performance_enhancers(PerformanceEnhancers::HormoneUndAntibiotika)
+                .build(),
+        )
+        .ingredient(
+            IngredientBuilder::new("Schweinefleisch", 200.0)
+                .category("Schwein")
+                .origin(Country::DE)
+                .performance_enhancers(PerformanceEnhancers::Keine)
+                .build(),
+        )
+        .build();
+    let output = ldv_calculator().execute(input);
+    assert_eq!(
+        statements(&output),
+        vec!["Rindfleisch: kann mit Hormonen und/oder Antibiotika sowie anderen antimikrobiellen Leistungsförderern erzeugt worden sein"]
+    );
+}
+
+#[test]
+fn meat_reared_abroad_needs_the_information() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(
+            IngredientBuilder::new("Rindfleisch", 300.0)
+                .category("Rind")
+                .aufzucht(Country::BR)
+                .schlachtung(Country::CH)
+                .build(),
+        )
+        .build();
+    let output = ldv_calculator().execute(input);
+    assert!(output.validation_messages.contains_key("ingredients[0][performance_enhancers]"));
+}
+
+#[test]
+fn statements_collect_nested_ingredients_once() {
+    let teig = IngredientBuilder::new("Teig", 500.0)
+        .children(vec![
+            IngredientBuilder::new("Mehl", 300.0).build(),
+            IngredientBuilder::new("Eier", 200.0)
+                .category("Eier")
+                .origin(Country::UA)
+                .egg_housing(EggHousing::Kaefighaltung)
+                .build(),
+        ])
+        .build();
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(teig)
+        .ingredient(
+            IngredientBuilder::new("Eier", 100.0)
+                .category("Eier")
+                .origin(Country::UA)
+                .egg_housing(EggHousing::Kaefighaltung)
+                .build(),
+        )
+        .build();
+    let output = ldv_calculator().execute(input);
+    let found = &output.verdicts.production_method_statements;
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].kind, ProductionMethodKind::Kaefighaltung);
+    assert_eq!(found[0].ingredients, vec!["Eier".to_string()]);
+    // The composite itself is not asked for a housing.
+    assert!(!output.validation_messages.contains_key("ingredients[0][egg_housing]"));
+}
+
+#[test]
+fn rule_inactive_means_no_statement() {
+    let input = InputBuilder::new()
+        .ingredient(
+            IngredientBuilder::new("Vollei", 200.0)
+                .category("Eier")
+                .origin(Country::PL)
+                .egg_housing(EggHousing::Kaefighaltung)
+                .build(),
+        )
+        .build();
+    let output = calculator_with(vec![]).execute(input);
+    assert!(output.verdicts.production_method_statements.is_empty());
+}
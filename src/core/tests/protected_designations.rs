@@ -0,0 +1,85 @@
+use super::*;
+
+const CERTIFICATE: &str = "protected_designation_certificate";
+
+fn aop_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::AOP_IGP_GeschuetzteBezeichnung])
+}
+
+fn cheese() -> Ingredient {
+    IngredientBuilder::new_agri("Milch", 1000.0).origin(Country::CH).build()
+}
+
+#[test]
+fn protected_name_in_title_asks_for_the_certificate() {
+    let input = InputBuilder::new().title("Gruyère").ingredient(cheese()).build();
+    let output = aop_calculator().execute(input);
+    let message = &output.validation_messages[CERTIFICATE][0];
+    assert!(message.contains("«Gruyère» ist als AOP geschützt"), "{message}");
+    assert!(message.contains("«Hartkäse»"), "{message}");
+}
+
+#[test]
+fn igp_name_in_subtitle_is_found_too() {
+    let input = InputBuilder::new()
+        .title("Hausgemacht")
+        .subtitle("Bündnerfleisch, geschnitten")
+        .ingredient(IngredientBuilder::new_agri("Rindfleisch", 1000.0).origin(Country::CH).build())
+        .build();
+    let output = aop_calculator().execute(input);
+    let message = &output.validation_messages[CERTIFICATE][0];
+    assert!(message.contains("als IGP geschützt"), "{message}");
+    assert!(message.contains("«Trockenfleisch»"), "{message}");
+}
+
+#[test]
+fn other_spellings_and_languages_are_recognised() {
+    for title in ["Greyerzer Fondue", "Viande des Grisons", "St.Galler Kalbsbratwurst", "Emmentalerkäse"] {
+        let input = InputBuilder::new().title(title).ingredient(cheese()).build();
+        let output = aop_calculator().execute(input);
+        assert!(output.validation_messages.contains_key(CERTIFICATE), "{title} not recognised");
+    }
+}
+
+#[test]
+fn certificate_satisfies_the_check() {
+    let input = InputBuilder::new()
+        .title("Gruyère AOP")
+        .protected_designation_certificate("OIC-12345")
+        .ingredient(cheese())
+        .build();
+    let output = aop_calculator().execute(input);
+    assert!(!output.validation_messages.contains_key(CERTIFICATE));
+}
+
+#[test]
+fn blank_certificate_does_not_count() {
+    let input = InputBuilder::new()
+        .title("Sbrinz")
+        .protected_designation_certificate("  ")
+        .ingredient(cheese())
+        .build();
+    let output = aop_calculator().execute(input);
+    assert!(output.validation_messages.contains_key(CERTIFICATE));
+}
+
+#[test]
+fn region_name_alone_is_not_protected() {
+    let input = InputBuilder::new().title("Bergkäse aus dem Emmental").ingredient(cheese()).build();
+    let output = aop_calculator().execute(input);
+    assert!(!output.validation_messages.contains_key(CERTIFICATE));
+}
+
+#[test]
+fn check_runs_without_a_recipe() {
+    let input = InputBuilder::new().title("Tête de Moine").einzelzutat().build();
+    let output = aop_calculator().execute(input);
+    assert!(output.validation_messages.contains_key(CERTIFICATE));
+}
+
+#[test]
+fn without_the_rule_there_is_no_check() {
+    let input = InputBuilder::new().title("Gruyère").ingredient(cheese()).build();
+    let output = calculator_with(vec![]).execute(input);
+    assert!(!output.validation_messages.contains_key(CERTIFICATE));
+}
@@ -0,0 +1,108 @@
+use super::*;
+use crate::recipe_declaration::{DeclaredQuality, RecipeDeclaration};
+
+fn knospe_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::Knospe_ShowBioSuisseLogo, RuleDef::Bio_Knospe_EingabeIstBio])
+}
+
+fn muesli() -> Input {
+    InputBuilder::new()
+        .title("Birchermüesli")
+        .certification_body("CH-BIO-006")
+        .ingredient(
+            IngredientBuilder::new_agri("Haferflocken", 600.0)
+                .bio()
+                .origin(Country::CH)
+                .processing_steps(vec!["geröstet"])
+                .build(),
+        )
+        .ingredient(IngredientBuilder::new_agri("Rosinen", 300.0).bio().origin(Country::TR).build())
+        .ingredient(
+            IngredientBuilder::new_agri("Vanille", 100.0)
+                .erlaubte_ausnahme_knospe()
+                .erlaubte_ausnahme_knospe_details("Keine Bio-Ware in Knospe-Qualität erhältlich")
+                .origin(Country::MG)
+                .build(),
+        )
+        .build()
+}
+
+fn declaration(input: Input) -> RecipeDeclaration {
+    let output = knospe_calculator().execute(input.clone());
+    RecipeDeclaration::new(&input, &output, "Hofladen Muster")
+}
+
+#[test]
+fn rows_carry_share_quality_origin_and_justification() {
+    let declaration = declaration(muesli());
+    assert_eq!(declaration.rows.len(), 3);
+    let hafer = &declaration.rows[0];
+    assert_eq!(hafer.share, 60.0);
+    assert_eq!(hafer.quality, DeclaredQuality::Knospe);
+    assert_eq!(hafer.origins, "CH");
+    assert_eq!(hafer.processing_steps, "geröstet");
+    let vanille = &declaration.rows[2];
+    assert_eq!(vanille.quality, DeclaredQuality::ErlaubteAusnahme);
+    assert_eq!(vanille.exception, "Keine Bio-Ware in Knospe-Qualität erhältlich");
+}
+
+#[test]
+fn shares_come_from_the_knospe_verdict() {
+    let declaration = declaration(muesli());
+    assert_eq!(declaration.knospe_share, Some(100.0));
+    // The permitted exception is not certified goods: 600 g of 900 g are Swiss.
+    assert_eq!(declaration.swiss_share.map(|s| s.round()), Some(67.0));
+    assert_eq!(declaration.licensee, "Hofladen Muster");
+    assert_eq!(declaration.certification_body, "CH-BIO-006");
+}
+
+#[test]
+fn composite_components_follow_their_ingredient() {
+    let input = InputBuilder::new()
+        .ingredient(
+            IngredientBuilder::new("Schokolade", 200.0)
+                .children(vec![
+                    IngredientBuilder::new_agri("Kakaomasse", 120.0).bio().build(),
+                    IngredientBuilder::new_agri("Zucker", 80.0).umstellbetrieb().build(),
+                ])
+                .build(),
+        )
+        .ingredient(IngredientBuilder::new_agri("Milch", 800.0).bio().build())
+        .build();
+    let rows = declaration(input).rows;
+    let names: Vec<(usize, &str)> = rows.iter().map(|r| (r.level, r.name.as_str())).collect();
+    assert_eq!(names, vec![(0, "Schokolade"), (1, "Kakaomasse"), (1, "Zucker"), (0, "Milch")]);
+    assert_eq!(rows[2].quality, DeclaredQuality::Umstellung);
+    assert_eq!(rows[2].share, 8.0);
+}
+
+#[test]
+fn csv_has_summary_then_table() {
+    let csv = declaration(muesli()).to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "Produkt;Birchermüesli");
+    assert!(lines.contains(&"Knospe-Anteil;100.0%"), "{csv}");
+    assert!(lines.contains(&"Zutat;Anteil (%);Qualität;Herkunft;Lieferant;Verarbeitung;Begründung Ausnahme"), "{csv}");
+    assert!(lines.contains(&"Haferflocken;60.0;Knospe;CH;;geröstet;"), "{csv}");
+}
+
+#[test]
+fn html_escapes_user_text() {
+    let input = InputBuilder::new()
+        .title("Müesli <Classic> & Co")
+        .ingredient(IngredientBuilder::new_agri("Hafer", 100.0).bio().build())
+        .build();
+    let html = declaration(input).to_html();
+    assert!(html.contains("Müesli &lt;Classic&gt; &amp; Co"));
+    assert!(!html.contains("<Classic>"));
+    assert!(html.contains("<td class=\"num\">100.0</td>"));
+}
+
+#[test]
+fn shares_are_absent_outside_knospe() {
+    let input = muesli();
+    let output = calculator_with(vec![RuleDef::Bio_ShowBioSachbezeichnung]).execute(input.clone());
+    let declaration = RecipeDeclaration::new(&input, &output, "");
+    assert_eq!(declaration.knospe_share, None);
+    assert_eq!(declaration.swiss_share, None);
+}
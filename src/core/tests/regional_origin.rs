@@ -0,0 +1,118 @@
+use super::*;
+use crate::swiss_regions::SwissRegion;
+
+fn knospe_calculator() -> Calculator {
+    calculator_with(vec![
+        RuleDef::Knospe_100_Percent_CH_NoOrigin,
+        RuleDef::Knospe_90_99_Percent_CH_ShowOrigin,
+    ])
+}
+
+#[test]
+fn regions_belong_to_switzerland() {
+    let emmental = Country::Region(SwissRegion::Emmental);
+    assert!(emmental.is_swiss());
+    assert_eq!(emmental.nation(), Country::CH);
+    assert_eq!(emmental.groups(), Country::CH.groups());
+    assert!(!Country::DE.is_swiss());
+    assert_eq!(Country::DE.nation(), Country::DE);
+}
+
+#[test]
+fn region_codes_round_trip() {
+    for region in SwissRegion::CANTONS.into_iter().chain(SwissRegion::REGIONS) {
+        assert_eq!(Country::from_code(region.code()), Some(Country::Region(region)));
+        assert_eq!(parse_country_code(region.code()), Some(Country::Region(region)));
+    }
+    assert_eq!(SwissRegion::from_code("CH-XX"), None);
+}
+
+#[test]
+fn regions_serialize_as_their_code() {
+    let ingredient = IngredientBuilder::new("Käse", 100.0)
+        .origins(vec![Country::Region(SwissRegion::Emmental), Country::DE])
+        .build();
+    let json = serde_json::to_string(&ingredient).unwrap();
+    assert!(json.contains(r#""origins":["CH-EMMENTAL","DE"]"#), "{json}");
+    let back: Ingredient = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.origins, ingredient.origins);
+
+    let qs = qs_to_string(&ingredient).unwrap();
+    let back: Ingredient = qs_from_str(&qs).unwrap();
+    assert_eq!(back.origins, ingredient.origins);
+}
+
+#[test]
+fn region_is_named_on_the_label_in_every_style() {
+    for style in [OriginStyle::Code, OriginStyle::Short, OriginStyle::Full] {
+        let input = InputBuilder::new()
+            .origin_style(style)
+            .ingredient(
+                IngredientBuilder::new_agri("Käse", 1000.0)
+                    .origin(Country::Region(SwissRegion::Emmental))
+                    .build(),
+            )
+            .build();
+        let output = calculator_with(vec![RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent]).execute(input);
+        assert_eq!(output.label, "Käse (Emmental)");
+    }
+}
+
+#[test]
+fn every_region_is_translated() {
+    for region in SwissRegion::CANTONS.into_iter().chain(SwissRegion::REGIONS) {
+        let key = region.translation_key();
+        for locale in ["de-CH", "fr-CH", "it-CH"] {
+            let name = rust_i18n::t!(&key, locale = locale).to_string();
+            assert!(!name.contains("swiss_regions."), "{region:?} untranslated in {locale}");
+        }
+    }
+    let graubuenden = SwissRegion::Gr.translation_key();
+    assert_eq!(rust_i18n::t!(&graubuenden, locale = "fr-CH"), "Grisons");
+}
+
+#[test]
+fn regions_count_as_swiss_agricultural_share() {
+    let ingredients = vec![
+        IngredientBuilder::new_agri("Hafer", 600.0).origin(Country::Region(SwissRegion::Emmental)).build(),
+        IngredientBuilder::new_agri("Weizenmehl", 400.0).origin(Country::Region(SwissRegion::Be)).build(),
+    ];
+    assert_eq!(calculate_swiss_agricultural_percentage(&ingredients), 100.0);
+}
+
+#[test]
+fn knospe_100_percent_band_hides_regional_origin() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new_agri("Hafer", 600.0).origin(Country::Region(SwissRegion::Emmental)).build())
+        .ingredient(IngredientBuilder::new_agri("Weizenmehl", 400.0).origin(Country::CH).build())
+        .build();
+    let output = knospe_calculator().execute(input);
+    assert_eq!(output.label, "Hafer, Weizenmehl");
+}
+
+#[test]
+fn knospe_90_99_band_names_the_region() {
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .ingredient(IngredientBuilder::new_agri("Hafer", 500.0).origin(Country::Region(SwissRegion::Emmental)).build())
+        .ingredient(IngredientBuilder::new_agri("Weizenmehl", 400.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new_agri("Olivenöl", 100.0).origin(Country::EU).build())
+        .build();
+    let output = knospe_calculator().execute(input);
+    assert!(output.label.contains("Hafer (Emmental)"), "{}", output.label);
+    assert!(output.label.contains("Weizenmehl (CH)"), "{}", output.label);
+}
+
+#[test]
+fn grouping_keeps_regions_by_name() {
+    let input = InputBuilder::new()
+        .origin_aggregation(OriginAggregation::EuNichtEu)
+        .ingredient(
+            IngredientBuilder::new("Honig", 1000.0)
+                .origins(vec![Country::Region(SwissRegion::Toggenburg), Country::DE, Country::FR])
+                .build(),
+        )
+        .build();
+    let output = calculator_with(vec![RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent]).execute(input);
+    assert_eq!(output.label, "Honig (Toggenburg, EU)");
+}
@@ -381,6 +381,11 @@ fn test_saved_ingredient_json_preserves_all_optional_fields() {
         aufzucht_ort: Some(Country::CH),
         schlachtungs_ort: Some(Country::DE),
         fangort: Some(Country::AT),
+        fish_origin: Some(crate::fish_origin::FishOrigin {
+            production: Some(crate::fish_origin::FishProduction::Binnenfischerei),
+            water_body: Some("Bodensee".to_string()),
+            ..Default::default()
+        }),
         bio_ch: Some(true),
         erlaubte_ausnahme_bio: Some(true),
         erlaubte_ausnahme_bio_details: Some("Grund".to_string()),
@@ -388,7 +393,21 @@ fn test_saved_ingredient_json_preserves_all_optional_fields() {
         erlaubte_ausnahme_knospe_details: Some("Kein Grund".to_string()),
         processing_steps: Some(vec!["geröstet".to_string(), "gemahlen".to_string()]),
         aus_umstellbetrieb: Some(true),
+        umstellung_bis: Some("2027-01-01".to_string()),
         override_children: Some(true),
+        is_processing_aid: Some(true),
+        reconstitution_factor: Some(8.0),
+        collective_name: Some(CollectiveName::Gewuerze),
+        compound_exemption: Some(CompoundExemption::Standardised),
+        egg_housing: Some(crate::production_methods::EggHousing::Freilandhaltung),
+        performance_enhancers: None,
+        mountain_provenance: Some(crate::mountain_designation::MountainProvenance::Soemmerungsgebiet),
+        supplier_certificate: Some(crate::supplier_certificate::SupplierCertificate {
+            supplier: "Mühle AG".to_string(),
+            number: "CH-BIO-006-1234".to_string(),
+            valid_until: "2027-06-30".to_string(),
+        }),
+        origin_aggregation: None,
         canonical: Some("Kanonisch".to_string()),
     };
 
@@ -409,6 +428,10 @@ fn test_saved_ingredient_json_preserves_all_optional_fields() {
     assert_eq!(i.aufzucht_ort, Some(Country::CH));
     assert_eq!(i.schlachtungs_ort, Some(Country::DE));
     assert_eq!(i.fangort, Some(Country::AT));
+    assert_eq!(i.egg_housing, Some(crate::production_methods::EggHousing::Freilandhaltung));
+    assert_eq!(i.mountain_provenance, Some(crate::mountain_designation::MountainProvenance::Soemmerungsgebiet));
+    assert_eq!(i.supplier_certificate.as_ref().map(|c| c.number.as_str()), Some("CH-BIO-006-1234"));
+    assert_eq!(i.fish_origin.as_ref().and_then(|f| f.water_body.as_deref()), Some("Bodensee"));
     assert_eq!(i.bio_ch, Some(true));
     assert_eq!(i.erlaubte_ausnahme_bio, Some(true));
     assert_eq!(i.erlaubte_ausnahme_bio_details, Some("Grund".to_string()));
@@ -416,5 +439,6 @@ fn test_saved_ingredient_json_preserves_all_optional_fields() {
     assert_eq!(i.erlaubte_ausnahme_knospe_details, Some("Kein Grund".to_string()));
     assert_eq!(i.processing_steps, Some(vec!["geröstet".to_string(), "gemahlen".to_string()]));
     assert_eq!(i.aus_umstellbetrieb, Some(true));
+    assert_eq!(i.umstellung_bis.as_deref(), Some("2027-01-01"));
     assert_eq!(i.override_children, Some(true));
 }
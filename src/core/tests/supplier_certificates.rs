@@ -0,0 +1,85 @@
+use super::*;
+
+const CERTIFICATE: &str = "ingredients[0][supplier_certificate]";
+
+fn certificate_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::Bio_Knospe_LieferantenZertifikat])
+}
+
+fn oats(valid_until: &str) -> Ingredient {
+    IngredientBuilder::new_agri("Haferflocken", 500.0)
+        .bio()
+        .bio_ch()
+        .certificate("Mühle AG", valid_until)
+        .build()
+}
+
+#[test]
+fn certificate_expiring_before_the_label_date_is_flagged() {
+    let input = InputBuilder::new().best_before("2027-03-01").ingredient(oats("2026-12-31")).build();
+    let output = certificate_calculator().execute(input);
+    let message = &output.validation_messages[CERTIFICATE][0];
+    assert!(message.contains("«Haferflocken» ist nur bis 31.12.2026 gültig"), "{message}");
+    assert!(message.contains("01.03.2027"), "{message}");
+}
+
+#[test]
+fn certificate_valid_through_the_label_date_passes() {
+    let input = InputBuilder::new().best_before("2026-12-31").ingredient(oats("2026-12-31")).build();
+    let output = certificate_calculator().execute(input);
+    assert!(!output.validation_messages.contains_key(CERTIFICATE));
+}
+
+#[test]
+fn without_a_label_date_nothing_is_compared() {
+    let input = InputBuilder::new().ingredient(oats("2020-01-01")).build();
+    let output = certificate_calculator().execute(input);
+    assert!(!output.validation_messages.contains_key(CERTIFICATE));
+}
+
+#[test]
+fn conventional_ingredient_needs_no_valid_certificate() {
+    let input = InputBuilder::new()
+        .best_before("2027-03-01")
+        .ingredient(IngredientBuilder::new_agri("Zucker", 100.0).certificate("Zucker AG", "2020-01-01").build())
+        .build();
+    let output = certificate_calculator().execute(input);
+    assert!(!output.validation_messages.contains_key(CERTIFICATE));
+}
+
+#[test]
+fn component_certificate_is_reported_under_the_composite() {
+    let input = InputBuilder::new()
+        .best_before("2027-03-01")
+        .ingredient(
+            IngredientBuilder::new("Müesli", 1000.0)
+                .children(vec![oats("2026-06-30"), IngredientBuilder::new_agri("Rosinen", 500.0).bio().build()])
+                .build(),
+        )
+        .build();
+    let output = certificate_calculator().execute(input);
+    assert!(output.validation_messages[CERTIFICATE][0].contains("«Haferflocken»"));
+}
+
+#[test]
+fn expired_certificate_fails_the_knospe_check() {
+    let calculator = calculator_with(vec![
+        RuleDef::Knospe_ShowBioSuisseLogo,
+        RuleDef::Bio_Knospe_LieferantenZertifikat,
+    ]);
+    let input = InputBuilder::new()
+        .vollstaendig()
+        .best_before("2027-03-01")
+        .ingredient(oats("2026-12-31"))
+        .build();
+    let output = calculator.execute(input);
+    assert_eq!(output.verdicts.knospe_check, Some(CheckState::Failed));
+}
+
+#[test]
+fn supplier_appears_in_the_recipe_declaration() {
+    let input = InputBuilder::new().ingredient(oats("2027-12-31")).build();
+    let output = certificate_calculator().execute(input.clone());
+    let declaration = crate::recipe_declaration::RecipeDeclaration::new(&input, &output, "");
+    assert_eq!(declaration.rows[0].supplier, "Mühle AG");
+}
@@ -0,0 +1,173 @@
+use super::*;
+use crate::verdicts::{SwissnessBlockReason, SwissnessVerdict};
+
+fn swissness_calculator() -> Calculator {
+    calculator_with(vec![RuleDef::Swissness_80_Prozent_Rohstoffe])
+}
+
+fn share_of(verdict: &SwissnessVerdict) -> f64 {
+    match verdict {
+        SwissnessVerdict::Allowed { share } | SwissnessVerdict::NotAllowed { share, .. } => *share,
+    }
+}
+
+#[test]
+fn eighty_percent_swiss_raw_materials_allow_the_swiss_cross() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Weizenmehl", 800.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new("Sonnenblumenöl", 200.0).origin(Country::FR).build())
+        .ingredient(IngredientBuilder::new("Wasser", 500.0).agricultural(false).build())
+        .build();
+    let output = swissness_calculator().execute(input);
+    // Water does not count towards the raw-material weight.
+    assert_eq!(output.verdicts.swissness, Some(SwissnessVerdict::Allowed { share: 80.0 }));
+}
+
+#[test]
+fn below_eighty_percent_is_refused() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Weizenmehl", 700.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new("Sonnenblumenöl", 300.0).origin(Country::FR).build())
+        .build();
+    let output = swissness_calculator().execute(input);
+    assert_eq!(
+        output.verdicts.swissness,
+        Some(SwissnessVerdict::NotAllowed { share: 70.0, reasons: vec![SwissnessBlockReason::ShareBelow80] })
+    );
+}
+
+#[test]
+fn raw_materials_not_available_in_switzerland_are_excluded() {
+    // Chocolate: cocoa butter and cocoa mass cannot come from Switzerland, so
+    // only sugar and milk powder are assessed.
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Zucker", 400.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new("Kakaobutter", 300.0).origin(Country::CI).build())
+        .ingredient(IngredientBuilder::new("Kakaomasse", 200.0).origin(Country::CI).build())
+        .ingredient(IngredientBuilder::new("Vollmilchpulver", 100.0).category("Milch und Milchprodukte").origin(Country::CH).build())
+        .build();
+    let output = swissness_calculator().execute(input);
+    assert_eq!(output.verdicts.swissness, Some(SwissnessVerdict::Allowed { share: 100.0 }));
+}
+
+#[test]
+fn partially_available_raw_materials_count_half() {
+    // 100 g honey from abroad counts as 50 g: 200 / 250 = 80%.
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Haferflocken", 200.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new("Honig", 100.0).origin(Country::DE).build())
+        .build();
+    let output = swissness_calculator().execute(input);
+    assert_eq!(share_of(output.verdicts.swissness.as_ref().unwrap()), 80.0);
+}
+
+#[test]
+fn milk_must_be_entirely_swiss() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Weizenmehl", 900.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new("Butter", 100.0).category("Butter").origins(vec![Country::CH, Country::DE]).build())
+        .build();
+    let output = swissness_calculator().execute(input);
+    assert_eq!(
+        output.verdicts.swissness,
+        Some(SwissnessVerdict::NotAllowed {
+            share: 90.0,
+            reasons: vec![SwissnessBlockReason::MilkNotSwiss { ingredients: vec!["Butter".to_string()] }],
+        })
+    );
+}
+
+#[test]
+fn composite_is_assessed_by_its_leaves() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Äpfel", 600.0).origin(Country::CH).build())
+        .ingredient(
+            IngredientBuilder::new("Streusel", 400.0)
+                .children(vec![
+                    IngredientBuilder::new("Weizenmehl", 200.0).origin(Country::CH).build(),
+                    IngredientBuilder::new("Rohrzucker", 100.0).origin(Country::BR).build(),
+                    IngredientBuilder::new("Palmöl - Palmfett", 100.0).origin(Country::MY).build(),
+                ])
+                .build(),
+        )
+        .build();
+    let output = swissness_calculator().execute(input);
+    assert_eq!(output.verdicts.swissness, Some(SwissnessVerdict::Allowed { share: 100.0 }));
+}
+
+#[test]
+fn only_excluded_raw_materials_leave_nothing_to_assess() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Kaffee", 100.0).origin(Country::BR).build())
+        .build();
+    let output = swissness_calculator().execute(input);
+    assert_eq!(
+        output.verdicts.swissness,
+        Some(SwissnessVerdict::NotAllowed { share: 0.0, reasons: vec![SwissnessBlockReason::NothingToAssess] })
+    );
+}
+
+#[test]
+fn without_the_rule_there_is_no_verdict() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Weizenmehl", 100.0).origin(Country::CH).build())
+        .build();
+    assert_eq!(calculator_with(vec![]).execute(input).verdicts.swissness, None);
+}
+
+#[test]
+fn foreign_milk_is_named_once_across_sub_recipes() {
+    let milk = || IngredientBuilder::new("Milch", 100.0).category("Milch").origin(Country::DE).build();
+    let input = InputBuilder::new()
+        .ingredient(
+            IngredientBuilder::new("Teig", 500.0)
+                .children(vec![
+                    milk(),
+                    IngredientBuilder::new("Butter", 50.0).category("Butter").origin(Country::DE).build(),
+                    IngredientBuilder::new("Weizenmehl", 350.0).origin(Country::CH).build(),
+                ])
+                .build(),
+        )
+        .ingredient(IngredientBuilder::new("Zucker", 300.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new("Creme", 100.0).children(vec![milk()]).build())
+        .build();
+    let output = swissness_calculator().execute(input);
+    let Some(SwissnessVerdict::NotAllowed { reasons, .. }) = output.verdicts.swissness else {
+        panic!("foreign milk must refuse the Swiss cross");
+    };
+    assert!(reasons.contains(&SwissnessBlockReason::MilkNotSwiss { ingredients: vec!["Milch".to_string(), "Butter".to_string()] }), "{reasons:?}");
+}
+
+#[test]
+fn missing_origin_is_reported_instead_of_counted_as_foreign() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Weizenmehl", 800.0).origin(Country::CH).build())
+        .ingredient(IngredientBuilder::new("Sonnenblumenöl", 200.0).build())
+        .ingredient(IngredientBuilder::new("Milch", 100.0).category("Milch").build())
+        .build();
+    let output = swissness_calculator().execute(input);
+    assert_eq!(
+        output.verdicts.swissness,
+        Some(SwissnessVerdict::NotAllowed {
+            share: 100.0,
+            reasons: vec![SwissnessBlockReason::OriginUnknown {
+                ingredients: vec!["Sonnenblumenöl".to_string(), "Milch".to_string()],
+            }],
+        })
+    );
+}
+
+#[test]
+fn no_origin_at_all_asks_for_origins() {
+    let input = InputBuilder::new()
+        .ingredient(IngredientBuilder::new("Weizenmehl", 800.0).build())
+        .build();
+    let output = swissness_calculator().execute(input);
+    assert_eq!(
+        output.verdicts.swissness,
+        Some(SwissnessVerdict::NotAllowed {
+            share: 0.0,
+            reasons: vec![SwissnessBlockReason::OriginUnknown { ingredients: vec!["Weizenmehl".to_string()] }],
+        })
+    );
+}
@@ -32,7 +32,7 @@ fn multiple_validation_errors_on_single_ingredient() {
         RuleDef::AP1_1_ZutatMengeValidierung,
         RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent,
         RuleDef::AP7_3_HerkunftFleischUeber20Prozent,
-        RuleDef::AP7_4_RindfleischHerkunftDetails,
+        RuleDef::AP7_4_FleischHerkunftDetails,
     ]);
 
     let input = InputBuilder::new()
@@ -0,0 +1,285 @@
+//! Herkunft von Fisch und Fischereierzeugnissen.
+//!
+//! Zur Herkunft gehört bei Fisch die Produktionsmethode: Fang auf See mit dem
+//! FAO-Fanggebiet («Nordostatlantik FAO 27»), Fang in Binnengewässern mit
+//! Gewässer und Land, oder Aquakultur mit dem Land der Aufzucht. Bei
+//! Wildfang kommt die Kategorie des Fanggeräts dazu. Das Land steht
+//! weiterhin in `Ingredient::fangort`.
+
+use crate::core::{Ingredient, OriginStyle};
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
+
+/// Wie der Fisch gewonnen wurde.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FishProduction {
+    /// Gefangen auf See — Fanggebiet ist ein FAO-Gebiet.
+    Meeresfang,
+    /// Gefangen in Süsswasser — Gewässer und Land.
+    Binnenfischerei,
+    /// Gezüchtet — Land der Aufzucht.
+    Aquakultur,
+}
+
+impl FishProduction {
+    pub const ALL: [FishProduction; 3] = [
+        FishProduction::Meeresfang,
+        FishProduction::Binnenfischerei,
+        FishProduction::Aquakultur,
+    ];
+
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            FishProduction::Meeresfang => "fish_production.meeresfang",
+            FishProduction::Binnenfischerei => "fish_production.binnenfischerei",
+            FishProduction::Aquakultur => "fish_production.aquakultur",
+        }
+    }
+
+    pub fn is_wild_catch(&self) -> bool {
+        matches!(self, FishProduction::Meeresfang | FishProduction::Binnenfischerei)
+    }
+}
+
+/// FAO-Hauptfanggebiete (Major Fishing Areas) der Meere.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FaoArea {
+    #[serde(rename = "18")]
+    Fao18,
+    #[serde(rename = "21")]
+    Fao21,
+    #[serde(rename = "27")]
+    Fao27,
+    #[serde(rename = "31")]
+    Fao31,
+    #[serde(rename = "34")]
+    Fao34,
+    #[serde(rename = "37")]
+    Fao37,
+    #[serde(rename = "41")]
+    Fao41,
+    #[serde(rename = "47")]
+    Fao47,
+    #[serde(rename = "48")]
+    Fao48,
+    #[serde(rename = "51")]
+    Fao51,
+    #[serde(rename = "57")]
+    Fao57,
+    #[serde(rename = "58")]
+    Fao58,
+    #[serde(rename = "61")]
+    Fao61,
+    #[serde(rename = "67")]
+    Fao67,
+    #[serde(rename = "71")]
+    Fao71,
+    #[serde(rename = "77")]
+    Fao77,
+    #[serde(rename = "81")]
+    Fao81,
+    #[serde(rename = "87")]
+    Fao87,
+    #[serde(rename = "88")]
+    Fao88,
+}
+
+impl FaoArea {
+    pub const ALL: [FaoArea; 19] = [
+        FaoArea::Fao18,
+        FaoArea::Fao21,
+        FaoArea::Fao27,
+        FaoArea::Fao31,
+        FaoArea::Fao34,
+        FaoArea::Fao37,
+        FaoArea::Fao41,
+        FaoArea::Fao47,
+        FaoArea::Fao48,
+        FaoArea::Fao51,
+        FaoArea::Fao57,
+        FaoArea::Fao58,
+        FaoArea::Fao61,
+        FaoArea::Fao67,
+        FaoArea::Fao71,
+        FaoArea::Fao77,
+        FaoArea::Fao81,
+        FaoArea::Fao87,
+        FaoArea::Fao88,
+    ];
+
+    /// FAO-Nummer des Gebiets.
+    pub fn code(&self) -> u8 {
+        match self {
+            FaoArea::Fao18 => 18,
+            FaoArea::Fao21 => 21,
+            FaoArea::Fao27 => 27,
+            FaoArea::Fao31 => 31,
+            FaoArea::Fao34 => 34,
+            FaoArea::Fao37 => 37,
+            FaoArea::Fao41 => 41,
+            FaoArea::Fao47 => 47,
+            FaoArea::Fao48 => 48,
+            FaoArea::Fao51 => 51,
+            FaoArea::Fao57 => 57,
+            FaoArea::Fao58 => 58,
+            FaoArea::Fao61 => 61,
+            FaoArea::Fao67 => 67,
+            FaoArea::Fao71 => 71,
+            FaoArea::Fao77 => 77,
+            FaoArea::Fao81 => 81,
+            FaoArea::Fao87 => 87,
+            FaoArea::Fao88 => 88,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Option<FaoArea> {
+        FaoArea::ALL.into_iter().find(|a| a.code() == code)
+    }
+
+    /// «Nordostatlantik FAO 27» in der aktuellen Sprache.
+    pub fn label(&self) -> String {
+        let key = format!("fao_areas.{}", self.code());
+        t!("fish_origin.fao_area", name = t!(&key), code = self.code()).to_string()
+    }
+}
+
+/// Kategorie des Fanggeräts bei Wildfang.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FishingGear {
+    Wadennetze,
+    Schleppnetze,
+    Kiemennetze,
+    Umschliessungsnetze,
+    HakenUndLangleinen,
+    Dredgen,
+    ReusenUndFallen,
+}
+
+impl FishingGear {
+    pub const ALL: [FishingGear; 7] = [
+        FishingGear::Wadennetze,
+        FishingGear::Schleppnetze,
+        FishingGear::Kiemennetze,
+        FishingGear::Umschliessungsnetze,
+        FishingGear::HakenUndLangleinen,
+        FishingGear::Dredgen,
+        FishingGear::ReusenUndFallen,
+    ];
+
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            FishingGear::Wadennetze => "fishing_gear.wadennetze",
+            FishingGear::Schleppnetze => "fishing_gear.schleppnetze",
+            FishingGear::Kiemennetze => "fishing_gear.kiemennetze",
+            FishingGear::Umschliessungsnetze => "fishing_gear.umschliessungsnetze",
+            FishingGear::HakenUndLangleinen => "fishing_gear.haken_und_langleinen",
+            FishingGear::Dredgen => "fishing_gear.dredgen",
+            FishingGear::ReusenUndFallen => "fishing_gear.reusen_und_fallen",
+        }
+    }
+}
+
+/// Produktionsmethode, Fanggebiet und Fanggerät einer Fischzutat.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct FishOrigin {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub production: Option<FishProduction>,
+    /// Nur bei Meeresfang.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fao_area: Option<FaoArea>,
+    /// Nur bei Binnenfischerei: See oder Fluss («Bodensee»).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub water_body: Option<String>,
+    /// Nur bei Wildfang.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gear: Option<FishingGear>,
+}
+
+impl FishOrigin {
+    fn water_body(&self) -> Option<&str> {
+        self.water_body.as_deref().map(str::trim).filter(|w| !w.is_empty())
+    }
+}
+
+/// Fehlende Angaben zur Fischherkunft, als (Feld, Übersetzungsschlüssel).
+///
+/// Fehlt die Produktionsmethode, wird sie verlangt und das Land (`fangort`)
+/// wie bisher geprüft.
+pub fn missing_fish_details(ingredient: &Ingredient) -> Vec<(&'static str, &'static str)> {
+    let origin = ingredient.fish_origin.clone().unwrap_or_default();
+    let mut missing = Vec::new();
+    let Some(production) = origin.production else {
+        missing.push(("fish_production", "validation.fish_production_required"));
+        if ingredient.fangort.is_none() {
+            missing.push(("fangort", "validation.fish_catch_location_required"));
+        }
+        return missing;
+    };
+    match production {
+        FishProduction::Meeresfang => {
+            if origin.fao_area.is_none() {
+                missing.push(("fao_area", "validation.fish_fao_area_required"));
+            }
+        }
+        FishProduction::Binnenfischerei => {
+            if origin.water_body().is_none() {
+                missing.push(("water_body", "validation.fish_water_body_required"));
+            }
+            if ingredient.fangort.is_none() {
+                missing.push(("fangort", "validation.fish_catch_location_required"));
+            }
+        }
+        FishProduction::Aquakultur => {
+            if ingredient.fangort.is_none() {
+                missing.push(("fangort", "validation.fish_farming_country_required"));
+            }
+        }
+    }
+    if production.is_wild_catch() && origin.gear.is_none() {
+        missing.push(("fishing_gear", "validation.fish_gear_required"));
+    }
+    missing
+}
+
+/// Die Herkunftsangaben in der Klammer hinter dem Fischnamen, z.B.
+/// «gefangen, Nordostatlantik FAO 27, Schleppnetze» oder «aus Aquakultur, NO».
+/// Leer, wenn nichts angegeben ist.
+pub fn fish_origin_parts(ingredient: &Ingredient, origin_style: OriginStyle) -> Vec<String> {
+    let country = ingredient.fangort.as_ref().map(|c| origin_style.name(c));
+    let origin = ingredient.fish_origin.clone().unwrap_or_default();
+    let Some(production) = origin.production else {
+        return country.into_iter().collect();
+    };
+    let mut parts = vec![t!(production.translation_key()).to_string()];
+    match production {
+        FishProduction::Meeresfang => {
+            parts.extend(origin.fao_area.map(|a| a.label()));
+        }
+        FishProduction::Binnenfischerei => {
+            parts.extend(origin.water_body().map(str::to_string));
+            parts.extend(country);
+        }
+        FishProduction::Aquakultur => {
+            parts.extend(country);
+        }
+    }
+    if production.is_wild_catch() {
+        parts.extend(origin.gear.map(|g| t!(g.translation_key()).to_string()));
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fao_codes_are_unique_and_round_trip() {
+        for area in FaoArea::ALL {
+            assert_eq!(FaoArea::from_code(area.code()), Some(area));
+        }
+        assert_eq!(FaoArea::from_code(99), None);
+    }
+}
@@ -0,0 +1,78 @@
+//! Herkunftsangabe bei Honig.
+//!
+//! Honig nennt sein Ursprungsland. Stammt er aus mehreren Ländern, dürfen
+//! statt der Länder nur «Mischung von Honig aus EU-Ländern», «… aus
+//! Nicht-EU-Ländern» oder «… aus EU- und Nicht-EU-Ländern» stehen; «diverse
+//! Länder» oder eine Gruppierung wie «EU/Nicht-EU» genügen nicht. Gilt für
+//! Honig als Zutat wie für Honig als Einzelzutat (Monoprodukt).
+
+use crate::category_service::is_honey_category;
+use crate::core::{declarable_origins, Ingredient, OriginAggregation, OriginStyle};
+use crate::model::Country;
+use rust_i18n::t;
+
+/// Welche Mischungsangabe eine Honigmischung trägt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HoneyBlend {
+    Eu,
+    NichtEu,
+    EuUndNichtEu,
+}
+
+impl HoneyBlend {
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            HoneyBlend::Eu => "honey_origin.blend_eu",
+            HoneyBlend::NichtEu => "honey_origin.blend_nicht_eu",
+            HoneyBlend::EuUndNichtEu => "honey_origin.blend_eu_und_nicht_eu",
+        }
+    }
+
+    /// Die Schweiz ist für die Mischungsangabe ein Nicht-EU-Land.
+    pub fn of(origins: &[&Country]) -> HoneyBlend {
+        let eu = origins.iter().any(|c| c.is_eu());
+        let non_eu = origins.iter().any(|c| !c.is_eu());
+        match (eu, non_eu) {
+            (true, true) => HoneyBlend::EuUndNichtEu,
+            (true, false) => HoneyBlend::Eu,
+            (false, _) => HoneyBlend::NichtEu,
+        }
+    }
+}
+
+pub fn is_honey(ingredient: &Ingredient) -> bool {
+    ingredient.effective_category().is_some_and(|c| is_honey_category(&c))
+}
+
+/// Die Herkunft eines Honigs wie sie auf der Etikette steht: ein Land oder
+/// alle Länder einzeln, sonst die Mischungsangabe.
+pub fn honey_origins(origins: &[&Country], aggregation: OriginAggregation, style: OriginStyle) -> String {
+    if origins.len() < 2 || aggregation == OriginAggregation::Laender {
+        origins.iter().map(|c| style.name(c)).collect::<Vec<_>>().join(", ")
+    } else {
+        t!(HoneyBlend::of(origins).translation_key()).to_string()
+    }
+}
+
+/// «Herkunft: …» für Honig als Einzelzutat, wo es kein Zutatenverzeichnis
+/// gibt, in dem die Herkunft hinter dem Namen stehen könnte.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HoneyOriginStatement {
+    pub origins: Vec<Country>,
+    pub aggregation: OriginAggregation,
+    pub style: OriginStyle,
+}
+
+impl HoneyOriginStatement {
+    /// `None`, wenn der Honig (noch) keine angebbare Herkunft hat.
+    pub fn for_mono_product(honey: &Ingredient, aggregation: OriginAggregation, style: OriginStyle) -> Option<Self> {
+        let origins: Vec<Country> = declarable_origins(&honey.computed_origins()?).into_iter().cloned().collect();
+        (!origins.is_empty()).then_some(HoneyOriginStatement { origins, aggregation, style })
+    }
+
+    /// Text für die Etikette in der aktuellen Sprache.
+    pub fn text(&self) -> String {
+        let origins: Vec<&Country> = self.origins.iter().collect();
+        t!("honey_origin.statement", origins = honey_origins(&origins, self.aggregation, self.style)).to_string()
+    }
+}
@@ -0,0 +1,338 @@
+//! Inhalt der gedruckten Etikette, unabhängig vom Ausgabeformat.
+//!
+//! Die Vorschau setzt die Etikette direkt aus den Formularfeldern und dem
+//! Ergebnis des Calculators zusammen. Für den Export braucht es dieselben
+//! Angaben als Daten: Abschnitte (in der Vorschau durch gepunktete Linien
+//! getrennt) aus Zeilen, Zeilen aus Textstücken mit oder ohne Fettdruck
+//! (Allergene), dazu das Bio-Suisse-Artwork.
+
+use crate::components::{display_money_exact, display_money_rounded, Amount, AmountType, Price};
+use crate::core::Output;
+use crate::pages::label_page::Form;
+use crate::verdicts::{BioVerdict, KnospeVerdict};
+use rust_i18n::t;
+
+/// Schriftgrad einer Zeile, relativ zum Fliesstext.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextSize {
+    /// Produktname.
+    Title,
+    /// Sachbezeichnung.
+    Designation,
+    Body,
+}
+
+impl TextSize {
+    /// Faktor zum Fliesstext, wie in der Vorschau (text-2xl / text-base / text-sm).
+    pub fn scale(&self) -> f32 {
+        match self {
+            TextSize::Title => 1.7,
+            TextSize::Designation => 1.15,
+            TextSize::Body => 1.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span {
+    pub text: String,
+    pub bold: bool,
+}
+
+/// Eine Zeile im Sinne eines Absatzes; der Umbruch auf die Etikettenbreite
+/// geschieht beim Layout. Eine Zeile ohne Textstücke ist eine Leerzeile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Line {
+    pub size: TextSize,
+    pub spans: Vec<Span>,
+}
+
+impl Line {
+    fn body(text: impl Into<String>) -> Line {
+        Line { size: TextSize::Body, spans: vec![Span { text: text.into(), bold: false }] }
+    }
+
+    /// Der Text ohne Auszeichnung.
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|s| s.text.as_str()).collect()
+    }
+}
+
+/// Bio-Suisse-Artwork oben rechts auf der Etikette.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LabelLogo {
+    Knospe,
+    KnospeNoCross,
+    /// Umstellungsknospe mit Umstellungssatz, Schweizer Rohstoffe.
+    UmstellungsknospeSatz,
+    /// Umstellungsknospe mit Umstellungssatz, importierte Rohstoffe.
+    UmstellungsknospeSatzImport,
+}
+
+impl LabelLogo {
+    /// Folgt dem Knospe-Urteil wie die Vorschau.
+    pub fn of(knospe: Option<&KnospeVerdict>) -> Option<LabelLogo> {
+        match knospe {
+            Some(KnospeVerdict::Logo { logo, .. }) => Some(match (logo.umstellung, logo.swiss_cross) {
+                (true, true) => LabelLogo::UmstellungsknospeSatz,
+                (true, false) => LabelLogo::UmstellungsknospeSatzImport,
+                (false, true) => LabelLogo::Knospe,
+                (false, false) => LabelLogo::KnospeNoCross,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Die Knospe als Vektorgrafik.
+    pub fn svg(&self) -> Option<&'static str> {
+        match self {
+            LabelLogo::Knospe => Some(include_str!("../assets/logos/bio-suisse-regular.svg")),
+            LabelLogo::KnospeNoCross => Some(include_str!("../assets/logos/bio-suisse-no-cross.svg")),
+            _ => None,
+        }
+    }
+
+    /// Die Umstellungsknospe als Bitmap in der Sprache der Etikette. Die
+    /// Wortbildmarke darf nicht verändert werden, deshalb keine Vektordaten.
+    pub fn png(&self, locale: &str) -> Option<&'static [u8]> {
+        match (self, locale) {
+            (LabelLogo::UmstellungsknospeSatz, "fr-CH") => Some(include_bytes!("../assets/logos/umstellungsknospe-satz-fr-CH.png")),
+            (LabelLogo::UmstellungsknospeSatz, "it-CH") => Some(include_bytes!("../assets/logos/umstellungsknospe-satz-it-CH.png")),
+            (LabelLogo::UmstellungsknospeSatz, _) => Some(include_bytes!("../assets/logos/umstellungsknospe-satz-de-CH.png")),
+            (LabelLogo::UmstellungsknospeSatzImport, "fr-CH") => Some(include_bytes!("../assets/logos/umstellungsknospe-import-satz-fr-CH.png")),
+            (LabelLogo::UmstellungsknospeSatzImport, "it-CH") => Some(include_bytes!("../assets/logos/umstellungsknospe-import-satz-it-CH.png")),
+            (LabelLogo::UmstellungsknospeSatzImport, _) => Some(include_bytes!("../assets/logos/umstellungsknospe-import-satz-de-CH.png")),
+            _ => None,
+        }
+    }
+
+    /// Breite durch Höhe des Artworks.
+    pub fn aspect_ratio(&self, locale: &str) -> f32 {
+        if let Some(png) = self.png(locale) {
+            // IHDR: Breite und Höhe als u32 ab Byte 16.
+            let width = u32::from_be_bytes([png[16], png[17], png[18], png[19]]);
+            let height = u32::from_be_bytes([png[20], png[21], png[22], png[23]]);
+            return width as f32 / height as f32;
+        }
+        let view_box = self.svg().and_then(svg_view_box).unwrap_or([0.0, 0.0, 1.0, 1.0]);
+        view_box[2] / view_box[3]
+    }
+}
+
+/// Die `viewBox` eines SVG-Dokuments als `[x, y, Breite, Höhe]`.
+pub fn svg_view_box(svg: &str) -> Option<[f32; 4]> {
+    let start = svg.find("viewBox=\"")? + "viewBox=\"".len();
+    let end = start + svg[start..].find('"')?;
+    let values: Vec<f32> = svg[start..end].split_whitespace().filter_map(|v| v.parse().ok()).collect();
+    values.try_into().ok()
+}
+
+/// Die Etikette als Daten.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LabelDocument {
+    pub logo: Option<LabelLogo>,
+    /// Abschnitte in der Reihenfolge der Vorschau; leere Abschnitte fehlen.
+    pub sections: Vec<Vec<Line>>,
+}
+
+/// Die Zutatenliste des Calculators ist HTML mit `<b>` für Allergene und
+/// `<br>` vor den Legenden.
+fn ingredient_lines(html: &str) -> Vec<Line> {
+    let mut lines = Vec::new();
+    for (i, part) in html.split("<br>").enumerate() {
+        let mut spans = Vec::new();
+        if i == 0 {
+            spans.push(Span { text: format!("{} ", t!("preview.zutaten")), bold: false });
+        }
+        let mut bold = false;
+        let mut rest = part;
+        while !rest.is_empty() {
+            let (text, tag, after) = match rest.find('<') {
+                Some(at) => {
+                    let close = rest[at..].find('>').map(|c| at + c + 1).unwrap_or(rest.len());
+                    (&rest[..at], &rest[at..close], &rest[close..])
+                }
+                None => (rest, "", ""),
+            };
+            if !text.is_empty() {
+                spans.push(Span { text: html_unescape(text), bold });
+            }
+            match tag {
+                "<b>" => bold = true,
+                "</b>" => bold = false,
+                _ => {}
+            }
+            rest = after;
+        }
+        lines.push(Line { size: TextSize::Body, spans });
+    }
+    lines
+}
+
+fn html_unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Menge wie in der Vorschau: aus den Preisen berechnet, sonst wie erfasst.
+fn quantity(form: &Form, unit: &str, base_factor: usize) -> Option<String> {
+    if let Some(amount) = form.price.calculated_amount(base_factor) {
+        return Some(format!("{} {}", amount, unit));
+    }
+    match form.amount {
+        Amount::Single(Some(amount)) if amount > 0 => Some(format!("{} {}", amount, unit)),
+        Amount::Double(Some(netto), Some(brutto)) => Some(format!(
+            "{} {} {}   {} {} {}",
+            t!("preview.nettogewicht"),
+            netto,
+            unit,
+            t!("preview.abtropfgewicht"),
+            brutto,
+            unit
+        )),
+        _ => None,
+    }
+}
+
+/// Preis wie in der Vorschau: bei den Standardmengen nur der Preis, sonst
+/// Grundpreis und Gesamtpreis.
+fn price(form: &Form, unit: &str, base_factor: usize) -> Option<String> {
+    let chf = t!("units.chf");
+    let standard_amount = matches!(form.amount.net(), 1 | 100 | 250 | 500);
+    let (unit_price, total_price) = match form.price {
+        Price::Single(None) => return None,
+        Price::Single(x) | Price::Double(x, _) if standard_amount => {
+            return x.filter(|x| *x > 0).map(|x| format!("{} {}", display_money_rounded(Some(x)), chf));
+        }
+        Price::Single(x) => (x, form.price.calculated_total(form.amount, base_factor)),
+        Price::Double(x, y) => (
+            form.price.calculated_unit_price(form.amount, base_factor).or(x),
+            form.price.calculated_total(form.amount, base_factor).or(y),
+        ),
+    };
+    let reference = if base_factor == 1 { unit.to_string() } else { format!("{} {}", base_factor, unit) };
+    let mut parts = Vec::new();
+    if let Some(unit_price) = unit_price.filter(|x| *x > 0) {
+        parts.push(format!("{} {} {} {}", t!("units.chfPro"), reference, display_money_exact(Some(unit_price)), chf));
+    }
+    if let Some(total_price) = total_price.filter(|x| *x > 0) {
+        parts.push(format!("{} {} {}", t!("preview.preis"), display_money_rounded(Some(total_price)), chf));
+    }
+    (!parts.is_empty()).then(|| parts.join("   "))
+}
+
+impl LabelDocument {
+    /// Baut die Etikette aus dem Formular und dem Ergebnis des Calculators.
+    /// `certification` nur in den Bio- und Knospe-Konfigurationen.
+    pub fn new(form: &Form, output: &Output, certification: bool) -> LabelDocument {
+        let v = &output.verdicts;
+        let mut sections: Vec<Vec<Line>> = Vec::new();
+
+        // Produktname und Sachbezeichnung mit « Bio» und Zusätzen wie
+        // «mit Süssungsmittel».
+        let suffix_allowed = matches!(v.bio, Some(BioVerdict::Allowed { .. }))
+            || matches!(v.knospe, Some(KnospeVerdict::Logo { bio_suffix: true, .. }));
+        let bio_suffix = if suffix_allowed { t!("preview.bio_suffix").to_string() } else { String::new() };
+        let zusatz: String = v
+            .mandatory_warnings
+            .iter()
+            .filter(|w| w.kind.belongs_to_sachbezeichnung())
+            .map(|w| format!(" {}", w.text()))
+            .collect();
+        let mut heading = Vec::new();
+        if !form.product_title.trim().is_empty() {
+            heading.push(Line { size: TextSize::Title, spans: vec![Span { text: form.product_title.trim().to_string(), bold: false }] });
+        }
+        if !form.product_subtitle.trim().is_empty() {
+            let size = if heading.is_empty() { TextSize::Title } else { TextSize::Designation };
+            let text = format!("{}{}{}", form.product_subtitle.trim(), bio_suffix, zusatz);
+            heading.push(Line { size, spans: vec![Span { text, bold: false }] });
+        }
+        sections.push(heading);
+
+        if !form.ignore_ingredients && !output.label.is_empty() {
+            sections.push(ingredient_lines(&output.label));
+        }
+
+        sections.push(
+            v.mandatory_warnings
+                .iter()
+                .filter(|w| !w.kind.belongs_to_sachbezeichnung())
+                .map(|w| Line::body(w.text()))
+                .collect(),
+        );
+        sections.push(v.primary_origin_statements.iter().map(|s| Line::body(s.text())).collect());
+        sections.push(v.production_method_statements.iter().map(|s| Line::body(s.text())).collect());
+        sections.push(v.honey_origin_statement.iter().map(|s| Line::body(s.text())).collect());
+
+        let mut date_and_quantity = Vec::new();
+        if form.date_prefix != t!("label.keinDatum") && !form.date.is_empty() {
+            date_and_quantity.push(Line::body(format!("{} {}", form.date_prefix, form.date)));
+        }
+        let unit = match form.amount_type {
+            AmountType::Weight => form.weight_unit.as_str(),
+            AmountType::Volume => form.volume_unit.as_str(),
+        };
+        let base_factor = form.amount_type.base_factor(&form.weight_unit, &form.volume_unit);
+        date_and_quantity.extend(quantity(form, unit, base_factor).map(Line::body));
+        sections.push(date_and_quantity);
+
+        sections.push(
+            [&form.additional_info, &form.storage_info]
+                .into_iter()
+                .flat_map(|text| text.lines())
+                .filter(|l| !l.trim().is_empty())
+                .map(|l| Line::body(l.trim()))
+                .collect(),
+        );
+
+        let city = [form.producer_zip.trim(), form.producer_city.trim()]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut address: Vec<Line> = [form.producer_name.as_str(), form.producer_address.as_str(), city.as_str()]
+            .into_iter()
+            .flat_map(str::lines)
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| Line::body(l.trim()))
+            .collect();
+        if !form.producer_phone.is_empty() {
+            address.push(Line::body(t!("preview.tel", phone = form.producer_phone)));
+        }
+        if !form.producer_email.is_empty() {
+            address.push(Line::body(t!("preview.email", email = form.producer_email)));
+        }
+        if !form.producer_website.is_empty() {
+            address.push(Line::body(t!("preview.website", website = form.producer_website)));
+        }
+        sections.push(address);
+        sections.push(price(form, unit, base_factor).into_iter().map(Line::body).collect());
+
+        if certification && !form.certification_body.is_empty() {
+            sections.push(vec![Line::body(t!("preview.bio_zertifizierung", body = form.certification_body))]);
+        }
+
+        sections.retain(|s| !s.is_empty());
+        LabelDocument { logo: LabelLogo::of(v.knospe.as_ref()), sections }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ingredient_html_keeps_allergens_bold() {
+        rust_i18n::set_locale("de-CH");
+        let lines = ingredient_lines("Weizenmehl* (<b>Gluten</b>), Salz &amp; Pfeffer<br><br>* aus biologischer Landwirtschaft");
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].text(), "Zutaten: Weizenmehl* (Gluten), Salz & Pfeffer");
+        assert_eq!(lines[0].spans.iter().filter(|s| s.bold).map(|s| s.text.as_str()).collect::<Vec<_>>(), vec!["Gluten"]);
+        assert!(lines[1].spans.is_empty());
+        assert_eq!(lines[2].text(), "* aus biologischer Landwirtschaft");
+    }
+}
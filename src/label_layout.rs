@@ -0,0 +1,444 @@
+//! Satz der Etikette für den Export.
+//!
+//! Bricht die Zeilen der [`LabelDocument`] auf die gewählte Etikettengrösse um
+//! und wählt den grössten Schriftgrad, bei dem alles Platz hat. Gemessen wird
+//! mit den eingebetteten Schriften (DejaVu Sans), damit die Ausgabe auf jedem
+//! Rechner gleich umbricht. Passen muss der Text in der Höhe und das längste
+//! Wort in der Breite. Nach unten begrenzt die LIV die Schrift: x-Höhe
+//! mindestens 1,2 mm, auf Packungen unter 80 cm² 0,9 mm. Reicht auch das nicht,
+//! meldet das Layout einen Überlauf statt die Schrift weiter zu verkleinern.
+//!
+//! Alle Masse in Millimetern, Ursprung oben links.
+
+use crate::label_document::{LabelDocument, LabelLogo, Line, Span};
+use serde::{Deserialize, Serialize};
+use ttf_parser::{Face, GlyphId, OutlineBuilder};
+
+/// Auszüge aus DejaVu Sans, die `build.rs` erzeugt: Latin-1 und die Zeichen
+/// der Übersetzungen und Datentabellen. Andere Zeichen messen und erscheinen
+/// als Ersatzglyphe.
+pub const REGULAR_FONT: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/DejaVuSans.ttf"));
+pub const BOLD_FONT: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/DejaVuSans-Bold.ttf"));
+
+pub const MM_PER_PT: f32 = 25.4 / 72.0;
+
+/// Grösster Schriftgrad des Fliesstexts.
+const MAX_BODY_PT: f32 = 10.0;
+const STEP_PT: f32 = 0.25;
+const LINE_HEIGHT: f32 = 1.2;
+/// Abstand zwischen zwei Abschnitten, in Zeilenhöhen.
+const SECTION_GAP: f32 = 0.5;
+
+/// Etikettengrösse in Millimetern.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LabelSize {
+    pub width_mm: f32,
+    pub height_mm: f32,
+}
+
+impl LabelSize {
+    /// Gängige Etikettenformate, die der Export anbietet.
+    pub const PRESETS: [LabelSize; 7] = [
+        LabelSize { width_mm: 58.0, height_mm: 40.0 },
+        LabelSize { width_mm: 60.0, height_mm: 40.0 },
+        LabelSize { width_mm: 80.0, height_mm: 50.0 },
+        LabelSize { width_mm: 100.0, height_mm: 70.0 },
+        LabelSize { width_mm: 100.0, height_mm: 100.0 },
+        LabelSize { width_mm: 105.0, height_mm: 148.0 },
+        LabelSize { width_mm: 148.0, height_mm: 105.0 },
+    ];
+
+    /// «100 × 70 mm»
+    pub fn name(&self) -> String {
+        format!("{} × {} mm", self.width_mm, self.height_mm)
+    }
+
+    /// Wert für `<select>`, z.B. «100x70».
+    pub fn key(&self) -> String {
+        format!("{}x{}", self.width_mm, self.height_mm)
+    }
+
+    pub fn from_key(key: &str) -> Option<LabelSize> {
+        let (width, height) = key.split_once('x')?;
+        Some(LabelSize { width_mm: width.parse().ok()?, height_mm: height.parse().ok()? })
+    }
+
+    fn margin(&self) -> f32 {
+        (self.width_mm.min(self.height_mm) * 0.05).clamp(1.5, 4.0)
+    }
+
+    /// Kleinste zulässige x-Höhe der Pflichtangaben.
+    fn min_x_height_mm(&self) -> f32 {
+        if self.width_mm * self.height_mm < 8000.0 { 0.9 } else { 1.2 }
+    }
+}
+
+impl Default for LabelSize {
+    fn default() -> Self {
+        LabelSize { width_mm: 100.0, height_mm: 70.0 }
+    }
+}
+
+/// Eine der beiden eingebetteten Schriften.
+pub struct Font {
+    pub data: &'static [u8],
+    face: Face<'static>,
+}
+
+impl Font {
+    pub fn regular() -> Font {
+        Font::parse(REGULAR_FONT)
+    }
+
+    pub fn bold() -> Font {
+        Font::parse(BOLD_FONT)
+    }
+
+    pub fn for_weight(bold: bool) -> Font {
+        if bold { Font::bold() } else { Font::regular() }
+    }
+
+    fn parse(data: &'static [u8]) -> Font {
+        let face = Face::parse(data, 0).expect("bundled font parses");
+        Font { data, face }
+    }
+
+    pub fn glyph_id(&self, c: char) -> u16 {
+        self.face.glyph_index(c).map(|g| g.0).unwrap_or(0)
+    }
+
+    /// Vorschub in Tausendstel Geviert, wie ihn PDF in `/W` erwartet.
+    pub fn advance(&self, glyph_id: u16) -> f32 {
+        let advance = self.face.glyph_hor_advance(GlyphId(glyph_id)).unwrap_or(0);
+        advance as f32 * 1000.0 / self.face.units_per_em() as f32
+    }
+
+    /// Breite eines Texts in Millimetern.
+    pub fn width_mm(&self, text: &str, size_pt: f32) -> f32 {
+        let em: f32 = text.chars().map(|c| self.advance(self.glyph_id(c))).sum();
+        em / 1000.0 * size_pt * MM_PER_PT
+    }
+
+    /// Umriss einer Glyphe in Schrifteinheiten, y nach oben.
+    pub fn outline(&self, glyph_id: u16, builder: &mut dyn OutlineBuilder) {
+        self.face.outline_glyph(GlyphId(glyph_id), builder);
+    }
+
+    pub fn units_per_em(&self) -> f32 {
+        self.face.units_per_em() as f32
+    }
+
+    fn em(&self, units: i16) -> f32 {
+        units as f32 / self.face.units_per_em() as f32
+    }
+
+    pub fn ascender_em(&self) -> f32 {
+        self.em(self.face.ascender())
+    }
+
+    pub fn descender_em(&self) -> f32 {
+        self.em(self.face.descender())
+    }
+
+    pub fn cap_height_em(&self) -> f32 {
+        self.em(self.face.capital_height().unwrap_or(self.face.ascender()))
+    }
+
+    pub fn x_height_em(&self) -> f32 {
+        self.em(self.face.x_height().unwrap_or(self.face.ascender() / 2))
+    }
+
+    /// Begrenzungsrahmen aller Glyphen in Tausendstel Geviert.
+    pub fn bbox(&self) -> [f32; 4] {
+        let b = self.face.global_bounding_box();
+        let scale = 1000.0 / self.face.units_per_em() as f32;
+        [b.x_min as f32 * scale, b.y_min as f32 * scale, b.x_max as f32 * scale, b.y_max as f32 * scale]
+    }
+}
+
+/// Ein Textstück in einer Schrift auf einer Grundlinie.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextRun {
+    pub x: f32,
+    pub baseline: f32,
+    pub size_pt: f32,
+    pub bold: bool,
+    pub text: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LogoPlacement {
+    pub logo: LabelLogo,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LabelLayout {
+    pub size: LabelSize,
+    /// Schriftgrad des Fliesstexts in Punkt.
+    pub body_pt: f32,
+    pub runs: Vec<TextRun>,
+    pub logo: Option<LogoPlacement>,
+    /// Der Inhalt passt auch in der kleinsten zulässigen Schrift nicht.
+    pub overflow: bool,
+}
+
+/// Ein Wort aus Textstücken, die ohne Leerzeichen aneinander hängen, etwa
+/// «(**Gluten**),».
+type Word = Vec<Span>;
+
+fn words(line: &Line) -> Vec<Word> {
+    let mut words = vec![Vec::new()];
+    for span in &line.spans {
+        for (i, piece) in span.text.split(' ').enumerate() {
+            if i > 0 {
+                words.push(Vec::new());
+            }
+            if !piece.is_empty() {
+                words.last_mut().unwrap().push(Span { text: piece.to_string(), bold: span.bold });
+            }
+        }
+    }
+    words.retain(|w| !w.is_empty());
+    words
+}
+
+struct Fonts {
+    regular: Font,
+    bold: Font,
+}
+
+impl Fonts {
+    fn get(&self, bold: bool) -> &Font {
+        if bold { &self.bold } else { &self.regular }
+    }
+
+    fn word_width(&self, word: &Word, size_pt: f32) -> f32 {
+        word.iter().map(|s| self.get(s.bold).width_mm(&s.text, size_pt)).sum()
+    }
+}
+
+/// Setzt den Inhalt bei einem Schriftgrad. Liefert die Textstücke, die
+/// Unterkante des Texts und ob ein Wort breiter ist als seine Zeile.
+fn set_text(document: &LabelDocument, size: LabelSize, logo: Option<&LogoPlacement>, body_pt: f32, fonts: &Fonts) -> (Vec<TextRun>, f32, bool) {
+    let margin = size.margin();
+    let right = size.width_mm - margin;
+    let mut runs: Vec<TextRun> = Vec::new();
+    let mut too_wide = false;
+    let mut y = margin;
+    for (i, section) in document.sections.iter().enumerate() {
+        if i > 0 {
+            y += SECTION_GAP * LINE_HEIGHT * body_pt * MM_PER_PT;
+        }
+        for line in section {
+            let size_pt = body_pt * line.size.scale();
+            let line_height = LINE_HEIGHT * size_pt * MM_PER_PT;
+            let ascent = fonts.regular.ascender_em() * size_pt * MM_PER_PT;
+            let space = fonts.regular.width_mm(" ", size_pt);
+            let widest_space = space.max(fonts.bold.width_mm(" ", size_pt));
+            // Neben dem Logo ist die Zeile kürzer.
+            let width_at = |top: f32| match logo {
+                Some(l) if top < l.y + l.height + margin / 2.0 => l.x - margin / 2.0 - margin,
+                _ => right - margin,
+            };
+            let mut x = 0.0;
+            let mut available = width_at(y);
+            let mut started = false;
+            for word in words(line) {
+                let word_width = fonts.word_width(&word, size_pt);
+                if started && x + widest_space + word_width > available {
+                    y += line_height;
+                    available = width_at(y);
+                    x = 0.0;
+                    started = false;
+                }
+                // Passt schon das erste Wort nicht neben das Logo, beginnt die
+                // Zeile unter dem Logo.
+                while !started && word_width > available && available < right - margin {
+                    y += line_height;
+                    available = width_at(y);
+                }
+                too_wide |= word_width > available;
+                let baseline = y + ascent;
+                let mut pending_space = started;
+                for span in word {
+                    let font = fonts.get(span.bold);
+                    // Stücke gleicher Schrift auf derselben Zeile bilden einen
+                    // Lauf; das Leerzeichen dazwischen hat dann dessen Breite.
+                    match runs.last_mut() {
+                        Some(run) if run.bold == span.bold && run.baseline == baseline && run.size_pt == size_pt => {
+                            if pending_space {
+                                run.text.push(' ');
+                                x += font.width_mm(" ", size_pt);
+                            }
+                            run.text.push_str(&span.text);
+                        }
+                        _ => {
+                            if pending_space {
+                                x += space;
+                            }
+                            runs.push(TextRun { x: margin + x, baseline, size_pt, bold: span.bold, text: span.text.clone() });
+                        }
+                    }
+                    pending_space = false;
+                    x += font.width_mm(&span.text, size_pt);
+                }
+                started = true;
+            }
+            y += line_height;
+        }
+    }
+    (runs, y, too_wide)
+}
+
+/// Platz für das Logo oben rechts: die Knospe klein, die Umstellungsknospe
+/// mit Umstellungssatz breit.
+fn place_logo(logo: LabelLogo, size: LabelSize, locale: &str) -> LogoPlacement {
+    let margin = size.margin();
+    let ratio = logo.aspect_ratio(locale);
+    let width = match logo {
+        LabelLogo::Knospe | LabelLogo::KnospeNoCross => (size.width_mm * 0.22).min(18.0),
+        _ => (size.width_mm * 0.5).min(50.0),
+    };
+    let width = width.min((size.height_mm * 0.3) * ratio);
+    LogoPlacement { logo, x: size.width_mm - margin - width, y: margin, width, height: width / ratio }
+}
+
+/// Setzt die Etikette im grössten Schriftgrad, der passt.
+pub fn layout(document: &LabelDocument, size: LabelSize, locale: &str) -> LabelLayout {
+    let fonts = Fonts { regular: Font::regular(), bold: Font::bold() };
+    let logo = document.logo.map(|l| place_logo(l, size, locale));
+    let bottom = size.height_mm - size.margin();
+    let logo_bottom = logo.map(|l| l.y + l.height).unwrap_or(0.0);
+    let min_pt = (size.min_x_height_mm() / fonts.regular.x_height_em() / MM_PER_PT / STEP_PT).ceil() * STEP_PT;
+
+    let mut body_pt = MAX_BODY_PT;
+    loop {
+        let (runs, text_bottom, too_wide) = set_text(document, size, logo.as_ref(), body_pt, &fonts);
+        let fits = text_bottom.max(logo_bottom) <= bottom && !too_wide;
+        if fits || body_pt - STEP_PT < min_pt {
+            return LabelLayout { size, body_pt, runs, logo, overflow: !fits };
+        }
+        body_pt -= STEP_PT;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::label_document::TextSize;
+
+    fn document(text: &str) -> LabelDocument {
+        LabelDocument {
+            logo: None,
+            sections: vec![vec![Line { size: TextSize::Body, spans: vec![Span { text: text.to_string(), bold: false }] }]],
+        }
+    }
+
+    fn assert_within_margins(layout: &LabelLayout) {
+        let fonts = Fonts { regular: Font::regular(), bold: Font::bold() };
+        let right = layout.size.width_mm - layout.size.margin();
+        for run in &layout.runs {
+            let end = run.x + fonts.get(run.bold).width_mm(&run.text, run.size_pt);
+            assert!(end <= right + 0.01, "«{}» ends at {end} mm, margin at {right} mm", run.text);
+        }
+    }
+
+    #[test]
+    fn short_text_keeps_the_largest_size() {
+        let layout = layout(&document("Salz"), LabelSize::default(), "de-CH");
+        assert_eq!(layout.body_pt, MAX_BODY_PT);
+        assert!(!layout.overflow);
+        assert_eq!(layout.runs.len(), 1);
+    }
+
+    #[test]
+    fn long_text_shrinks_and_wraps_within_the_margins() {
+        let text = "Weizenmehl, Wasser, Roggenmehl, Sauerteig, Salz, Hefe. ".repeat(12);
+        let size = LabelSize { width_mm: 60.0, height_mm: 40.0 };
+        let layout = layout(&document(&text), size, "de-CH");
+        assert!(layout.body_pt < MAX_BODY_PT);
+        assert!(!layout.overflow);
+        assert_within_margins(&layout);
+        for run in &layout.runs {
+            assert!(run.baseline <= size.height_mm);
+        }
+    }
+
+    #[test]
+    fn long_words_shrink_until_they_fit_the_width() {
+        let size = LabelSize { width_mm: 60.0, height_mm: 40.0 };
+        for title in ["Rindstrockenfleisch", "Bergblumenwiesenhonig"] {
+            let document = LabelDocument {
+                logo: Some(LabelLogo::Knospe),
+                sections: vec![
+                    vec![Line { size: TextSize::Title, spans: vec![Span { text: title.to_string(), bold: false }] }],
+                    vec![Line { size: TextSize::Body, spans: vec![Span { text: "Zutaten: Honig".to_string(), bold: false }] }],
+                ],
+            };
+            let layout = layout(&document, size, "de-CH");
+            assert!(layout.body_pt < MAX_BODY_PT, "{title}");
+            assert!(!layout.overflow, "{title}");
+            assert_within_margins(&layout);
+        }
+    }
+
+    #[test]
+    fn a_first_word_too_wide_beside_the_logo_starts_below_it() {
+        let mut document = document("Bergblumenwiesenhonig aus dem Emmental");
+        document.logo = Some(LabelLogo::Knospe);
+        let layout = layout(&document, LabelSize { width_mm: 60.0, height_mm: 40.0 }, "de-CH");
+        let logo = layout.logo.unwrap();
+        let fonts = Fonts { regular: Font::regular(), bold: Font::bold() };
+        let first = &layout.runs[0];
+        assert!(first.text.starts_with("Bergblumenwiesenhonig"), "{}", first.text);
+        // Beside the logo the word would run into it; below it the full width is free.
+        assert!(fonts.regular.width_mm("Bergblumenwiesenhonig", first.size_pt) > logo.x - layout.size.margin() * 1.5);
+        assert!(first.baseline - fonts.regular.ascender_em() * first.size_pt * MM_PER_PT >= logo.y + logo.height);
+        assert_within_margins(&layout);
+    }
+
+    #[test]
+    fn a_word_too_wide_at_the_legal_minimum_overflows() {
+        let layout = layout(&document(&"Honig".repeat(30)), LabelSize { width_mm: 60.0, height_mm: 40.0 }, "de-CH");
+        assert!(layout.overflow);
+    }
+
+    #[test]
+    fn overflow_stops_at_the_legal_minimum() {
+        let text = "Zutat ".repeat(2000);
+        let layout = layout(&document(&text), LabelSize { width_mm: 60.0, height_mm: 40.0 }, "de-CH");
+        assert!(layout.overflow);
+        let x_height = layout.body_pt * MM_PER_PT * Font::regular().x_height_em();
+        assert!(x_height >= 0.9, "x-height {x_height} mm");
+    }
+
+    struct Points(usize);
+
+    impl OutlineBuilder for Points {
+        fn move_to(&mut self, _: f32, _: f32) { self.0 += 1 }
+        fn line_to(&mut self, _: f32, _: f32) { self.0 += 1 }
+        fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) { self.0 += 1 }
+        fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) { self.0 += 1 }
+        fn close(&mut self) {}
+    }
+
+    #[test]
+    fn bundled_fonts_are_subsets_that_draw_the_label_characters() {
+        for (data, font) in [(REGULAR_FONT, Font::regular()), (BOLD_FONT, Font::bold())] {
+            assert!(data.len() < 200_000, "{} bytes", data.len());
+            for c in "Zutaten: Crème fraîche, Œufs – Weizenmehl (CH) … 5 % «Bio» €".chars().filter(|c| !c.is_whitespace()) {
+                let glyph = font.glyph_id(c);
+                assert_ne!(glyph, 0, "{c}");
+                let mut points = Points(0);
+                font.outline(glyph, &mut points);
+                assert!(points.0 > 0, "{c}");
+            }
+            assert_eq!(font.glyph_id('ж'), 0);
+            assert!(subsetter::subset(data, 0, subsetter::Profile::pdf(&[0, font.glyph_id('é')])).is_ok());
+        }
+    }
+}
@@ -0,0 +1,345 @@
+//! PDF-Export der Etikette.
+//!
+//! Schreibt das [`LabelLayout`] in Originalgrösse als PDF. Die beiden Schriften
+//! werden als Teilmenge der verwendeten Glyphen eingebettet (CID-Schrift mit
+//! `Identity-H`, dazu eine ToUnicode-Tabelle, damit der Text kopierbar und
+//! durchsuchbar bleibt). Die Knospe kommt als Vektorgrafik auf die Seite, die
+//! Umstellungsknospe als Bitmap mit Transparenzmaske.
+
+use crate::label_document::{svg_view_box, LabelLogo};
+use crate::label_layout::{Font, LabelLayout, MM_PER_PT};
+use crate::label_render::{decode_png, svg_paths, walk_svg_path, Bitmap, PathSink};
+use pdf_writer::types::{CidFontType, FontFlags, SystemInfo, UnicodeCmap};
+use pdf_writer::{Content, Filter, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+use std::collections::{BTreeMap, HashMap};
+
+const REGULAR: Name = Name(b"F1");
+const BOLD: Name = Name(b"F2");
+
+fn pt(mm: f32) -> f32 {
+    mm / MM_PER_PT
+}
+
+/// Ein Etikett auf der Seite, Position der oberen linken Ecke in mm.
+#[derive(Clone, Copy, Debug)]
+pub struct Placement<'a> {
+    pub layout: &'a LabelLayout,
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Verwendete Glyphen einer Schrift, mit dem Zeichen für die ToUnicode-Tabelle.
+#[derive(Default)]
+struct GlyphSet(BTreeMap<u16, char>);
+
+impl GlyphSet {
+    /// Kodiert den Text als zwei Byte pro Glyphe (`Identity-H`).
+    fn encode(&mut self, font: &Font, text: &str) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(text.len() * 2);
+        for c in text.chars() {
+            let glyph = font.glyph_id(c);
+            self.0.entry(glyph).or_insert(c);
+            bytes.extend_from_slice(&glyph.to_be_bytes());
+        }
+        bytes
+    }
+}
+
+/// Name der Bitmap eines Logos in den Ressourcen der Seite.
+fn image_name(logo: LabelLogo) -> Name<'static> {
+    match logo {
+        LabelLogo::UmstellungsknospeSatzImport => Name(b"ImImport"),
+        _ => Name(b"Im"),
+    }
+}
+
+impl PathSink for Content {
+    fn move_to(&mut self, x: f32, y: f32) {
+        Content::move_to(self, x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        Content::line_to(self, x, y);
+    }
+
+    fn cubic_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        Content::cubic_to(self, x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.close_path();
+    }
+}
+
+fn compress(data: &[u8]) -> Vec<u8> {
+    miniz_oxide::deflate::compress_to_vec_zlib(data, 6)
+}
+
+/// Präfix für eingebettete Teilmengen («ABCDEF+»), aus den Glyphen abgeleitet,
+/// damit dieselbe Etikette dasselbe PDF ergibt.
+fn subset_tag(glyphs: &GlyphSet) -> String {
+    let mut hash: u32 = 2166136261;
+    for glyph in glyphs.0.keys() {
+        for byte in glyph.to_be_bytes() {
+            hash = (hash ^ byte as u32).wrapping_mul(16777619);
+        }
+    }
+    (0..6).map(|i| (b'A' + ((hash >> (i * 5)) % 26) as u8) as char).collect()
+}
+
+struct Writer {
+    pdf: Pdf,
+    next: i32,
+}
+
+impl Writer {
+    fn alloc(&mut self) -> Ref {
+        self.next += 1;
+        Ref::new(self.next)
+    }
+
+    /// Bettet eine Schrift als CID-Schrift ein und liefert die Referenz auf
+    /// das Type0-Font-Objekt.
+    fn embed_font(&mut self, font: &Font, base_name: &str, glyphs: &GlyphSet, bold: bool) -> Ref {
+        let type0 = self.alloc();
+        let cid = self.alloc();
+        let descriptor = self.alloc();
+        let file = self.alloc();
+        let cmap = self.alloc();
+
+        let name = format!("{}+{}", subset_tag(glyphs), base_name);
+        let ids: Vec<u16> = glyphs.0.keys().copied().collect();
+        let subset = subsetter::subset(font.data, 0, subsetter::Profile::pdf(&ids)).unwrap_or_else(|_| font.data.to_vec());
+
+        self.pdf
+            .type0_font(type0)
+            .base_font(Name(name.as_bytes()))
+            .encoding_predefined(Name(b"Identity-H"))
+            .descendant_font(cid)
+            .to_unicode(cmap);
+
+        let system_info = SystemInfo { registry: Str(b"Adobe"), ordering: Str(b"Identity"), supplement: 0 };
+        let mut cid_font = self.pdf.cid_font(cid);
+        cid_font
+            .subtype(CidFontType::Type2)
+            .base_font(Name(name.as_bytes()))
+            .system_info(system_info)
+            .font_descriptor(descriptor)
+            .default_width(0.0)
+            .cid_to_gid_map_predefined(Name(b"Identity"));
+        let mut widths = cid_font.widths();
+        for glyph in &ids {
+            widths.consecutive(*glyph, [font.advance(*glyph)]);
+        }
+        widths.finish();
+        cid_font.finish();
+
+        let [x_min, y_min, x_max, y_max] = font.bbox();
+        self.pdf
+            .font_descriptor(descriptor)
+            .name(Name(name.as_bytes()))
+            .flags(FontFlags::NON_SYMBOLIC)
+            .bbox(Rect::new(x_min, y_min, x_max, y_max))
+            .italic_angle(0.0)
+            .ascent(font.ascender_em() * 1000.0)
+            .descent(font.descender_em() * 1000.0)
+            .cap_height(font.cap_height_em() * 1000.0)
+            .stem_v(if bold { 140.0 } else { 80.0 })
+            .font_file2(file);
+
+        let compressed = compress(&subset);
+        self.pdf
+            .stream(file, &compressed)
+            .filter(Filter::FlateDecode)
+            .pair(Name(b"Length1"), subset.len() as i32);
+
+        let mut unicode = UnicodeCmap::new(Name(b"Custom"), system_info);
+        for (glyph, c) in &glyphs.0 {
+            unicode.pair(*glyph, *c);
+        }
+        let unicode = unicode.finish();
+        self.pdf.cmap(cmap, &unicode).name(Name(b"Custom")).system_info(system_info);
+        type0
+    }
+
+    /// Bettet die Bitmap eines Logos mit Transparenzmaske ein.
+    fn embed_bitmap(&mut self, bitmap: &Bitmap) -> Ref {
+        let image = self.alloc();
+        let mask = bitmap.alpha.as_ref().map(|alpha| {
+            let mask = self.alloc();
+            let compressed = compress(alpha);
+            let mut xobject = self.pdf.image_xobject(mask, &compressed);
+            xobject.filter(Filter::FlateDecode);
+            xobject.width(bitmap.width as i32).height(bitmap.height as i32).bits_per_component(8);
+            xobject.color_space().device_gray();
+            mask
+        });
+        let compressed = compress(&bitmap.rgb);
+        let mut xobject = self.pdf.image_xobject(image, &compressed);
+        xobject.filter(Filter::FlateDecode);
+        xobject.width(bitmap.width as i32).height(bitmap.height as i32).bits_per_component(8).interpolate(true);
+        xobject.color_space().device_rgb();
+        if let Some(mask) = mask {
+            xobject.s_mask(mask);
+        }
+        image
+    }
+}
+
+/// Schreibt die Etiketten auf Seiten der Grösse `page_mm` (Breite, Höhe).
+/// Jede innere Liste ist eine Seite.
+pub fn labels_pdf(page_mm: (f32, f32), pages: &[Vec<Placement>], title: &str, locale: &str) -> Vec<u8> {
+    let regular = Font::regular();
+    let bold = Font::bold();
+    let mut regular_glyphs = GlyphSet::default();
+    let mut bold_glyphs = GlyphSet::default();
+    let mut images: HashMap<LabelLogo, (Name<'static>, Option<Bitmap>)> = HashMap::new();
+    let (page_width, page_height) = (pt(page_mm.0), pt(page_mm.1));
+
+    // Erst die Inhalte, damit die verwendeten Glyphen feststehen.
+    let mut contents = Vec::new();
+    for placements in pages {
+        let mut content = Content::new();
+        for placement in placements {
+            let layout = placement.layout;
+            content.set_fill_gray(0.0);
+            for run in &layout.runs {
+                let (font, glyphs, name) = if run.bold {
+                    (&bold, &mut bold_glyphs, BOLD)
+                } else {
+                    (&regular, &mut regular_glyphs, REGULAR)
+                };
+                let encoded = glyphs.encode(font, &run.text);
+                content
+                    .begin_text()
+                    .set_font(name, run.size_pt)
+                    .set_text_matrix([1.0, 0.0, 0.0, 1.0, pt(placement.x + run.x), page_height - pt(placement.y + run.baseline)])
+                    .show(Str(&encoded))
+                    .end_text();
+            }
+            let Some(logo) = layout.logo else { continue };
+            let (x, y) = (pt(placement.x + logo.x), page_height - pt(placement.y + logo.y + logo.height));
+            let (width, height) = (pt(logo.width), pt(logo.height));
+            if let Some(svg) = logo.logo.svg() {
+                let [vx, vy, vw, vh] = svg_view_box(svg).unwrap_or([0.0, 0.0, 1.0, 1.0]);
+                content.save_state();
+                // viewBox → Platz auf der Seite, y nach oben.
+                content.transform([width / vw, 0.0, 0.0, -height / vh, x - vx * width / vw, y + height + vy * height / vh]);
+                for (fill, d) in svg_paths(svg) {
+                    content.set_fill_rgb(fill[0], fill[1], fill[2]);
+                    walk_svg_path(d, &mut content);
+                    content.fill_nonzero();
+                }
+                content.restore_state();
+            } else if let Some(png) = logo.logo.png(locale) {
+                let (name, bitmap) = images.entry(logo.logo).or_insert_with(|| {
+                    let bitmap = decode_png(png);
+                    if bitmap.is_none() {
+                        tracing::warn!("Failed to decode logo {:?}", logo.logo);
+                    }
+                    (image_name(logo.logo), bitmap)
+                });
+                if bitmap.is_some() {
+                    content.save_state();
+                    content.transform([width, 0.0, 0.0, height, x, y]);
+                    content.x_object(*name);
+                    content.restore_state();
+                }
+            }
+        }
+        contents.push(content.finish());
+    }
+
+    let mut writer = Writer { pdf: Pdf::new(), next: 0 };
+    let catalog = writer.alloc();
+    let page_tree = writer.alloc();
+    let info = writer.alloc();
+    let regular_ref = (!regular_glyphs.0.is_empty()).then(|| writer.embed_font(&regular, "DejaVuSans", &regular_glyphs, false));
+    let bold_ref = (!bold_glyphs.0.is_empty()).then(|| writer.embed_font(&bold, "DejaVuSans-Bold", &bold_glyphs, true));
+    let image_refs: Vec<(Name, Ref)> = images
+        .values()
+        .filter_map(|(name, bitmap)| bitmap.as_ref().map(|b| (*name, b)))
+        .map(|(name, bitmap)| (name, writer.embed_bitmap(bitmap)))
+        .collect();
+
+    let mut page_refs = Vec::new();
+    for content in &contents {
+        let page = writer.alloc();
+        let content_ref = writer.alloc();
+        let mut page_writer = writer.pdf.page(page);
+        page_writer
+            .media_box(Rect::new(0.0, 0.0, page_width, page_height))
+            .parent(page_tree)
+            .contents(content_ref);
+        let mut resources = page_writer.resources();
+        let mut fonts = resources.fonts();
+        if let Some(r) = regular_ref {
+            fonts.pair(REGULAR, r);
+        }
+        if let Some(r) = bold_ref {
+            fonts.pair(BOLD, r);
+        }
+        fonts.finish();
+        let mut x_objects = resources.x_objects();
+        for (name, r) in &image_refs {
+            x_objects.pair(*name, *r);
+        }
+        x_objects.finish();
+        resources.finish();
+        page_writer.finish();
+        let compressed = compress(content);
+        writer.pdf.stream(content_ref, &compressed).filter(Filter::FlateDecode);
+        page_refs.push(page);
+    }
+
+    writer.pdf.pages(page_tree).kids(page_refs.iter().copied()).count(page_refs.len() as i32);
+    writer.pdf.catalog(catalog).pages(page_tree);
+    writer.pdf.document_info(info).title(TextStr(title));
+    writer.pdf.finish()
+}
+
+/// Eine Etikette in Originalgrösse auf einer Seite.
+pub fn label_pdf(layout: &LabelLayout, title: &str, locale: &str) -> Vec<u8> {
+    let size = (layout.size.width_mm, layout.size.height_mm);
+    labels_pdf(size, &[vec![Placement { layout, x: 0.0, y: 0.0 }]], title, locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::label_document::{LabelDocument, Line, Span, TextSize};
+    use crate::label_layout::{layout, LabelSize};
+
+    fn document(logo: Option<LabelLogo>) -> LabelDocument {
+        let span = |text: &str, bold| Span { text: text.to_string(), bold };
+        LabelDocument {
+            logo,
+            sections: vec![
+                vec![Line { size: TextSize::Title, spans: vec![span("Dinkelbrot Bio", false)] }],
+                vec![Line { size: TextSize::Body, spans: vec![span("Zutaten: ", false), span("Dinkelmehl", true), span("* (", false), span("Gluten", true), span("), Wasser, Meersalz", false)] }],
+                vec![Line { size: TextSize::Body, spans: vec![span("Mindestens haltbar bis 31.12.2026", false)] }],
+            ],
+        }
+    }
+
+    #[test]
+    fn text_is_extractable_with_embedded_fonts() {
+        let layout = layout(&document(Some(LabelLogo::Knospe)), LabelSize::default(), "de-CH");
+        let pdf = label_pdf(&layout, "Dinkelbrot", "de-CH");
+        let text = pdf_extract::extract_text_from_mem(&pdf).unwrap();
+        assert!(text.contains("Dinkelbrot Bio"), "{text}");
+        assert!(text.contains("Gluten"), "{text}");
+        let contains = |needle: &[u8]| pdf.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(b"/FontFile2"));
+        assert!(contains(b"+DejaVuSans-Bold"));
+        assert!(contains(b"/MediaBox [0 0 283.46"), "100 mm wide");
+    }
+
+    #[test]
+    fn same_label_gives_the_same_file() {
+        let layout = layout(&document(Some(LabelLogo::UmstellungsknospeSatz)), LabelSize::default(), "fr-CH");
+        let pdf = label_pdf(&layout, "Dinkelbrot", "fr-CH");
+        assert_eq!(pdf, label_pdf(&layout, "Dinkelbrot", "fr-CH"));
+        assert!(pdf.windows(6).any(|w| w == b"/SMask"), "Umstellungsknospe is embedded with its alpha channel");
+    }
+}
@@ -0,0 +1,168 @@
+//! Zeichnen der Logos für die Exportformate.
+//!
+//! PDF und Thermodruck brauchen die Artworks nicht als Datei, sondern als
+//! Zeichenbefehle: die Pfade der Knospe-SVGs und die Pixel der
+//! Umstellungsknospe-PNGs. Beides wird hier zerlegt; was daraus wird, bestimmt
+//! das Format über [`PathSink`] bzw. [`Bitmap`].
+
+fn attribute<'a>(element: &'a str, name: &str) -> Option<&'a str> {
+    let start = element.find(&format!(" {}=\"", name))? + name.len() + 3;
+    let end = start + element[start..].find('"')?;
+    Some(&element[start..end])
+}
+
+/// Die Pfade eines SVG-Logos: Füllfarbe und `d`-Attribut.
+pub fn svg_paths(svg: &str) -> Vec<([f32; 3], &str)> {
+    let mut paths = Vec::new();
+    for element in svg.split("<path").skip(1) {
+        let (Some(fill), Some(d)) = (attribute(element, "fill"), attribute(element, "d")) else {
+            continue;
+        };
+        let channel = |i: usize| u8::from_str_radix(fill.get(1 + 2 * i..3 + 2 * i).unwrap_or("00"), 16).unwrap_or(0) as f32 / 255.0;
+        paths.push(([channel(0), channel(1), channel(2)], d));
+    }
+    paths
+}
+
+/// Empfänger der Befehle aus [`walk_svg_path`].
+pub trait PathSink {
+    fn move_to(&mut self, x: f32, y: f32);
+    fn line_to(&mut self, x: f32, y: f32);
+    fn cubic_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32);
+    fn close(&mut self);
+}
+
+/// Zerlegt SVG-Pfaddaten in Befehle und Zahlen.
+fn path_tokens(d: &str) -> Vec<Result<char, f32>> {
+    let mut tokens = Vec::new();
+    let bytes = d.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+        if c.is_ascii_alphabetic() && c != 'e' && c != 'E' {
+            tokens.push(Ok(c));
+            i += 1;
+        } else if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' {
+            let start = i;
+            i += 1;
+            let mut dot = c == '.';
+            while i < bytes.len() {
+                let n = bytes[i] as char;
+                let exponent_sign = (n == '-' || n == '+') && matches!(bytes[i - 1], b'e' | b'E');
+                if n.is_ascii_digit() || n == 'e' || n == 'E' || exponent_sign || (n == '.' && !dot) {
+                    dot |= n == '.';
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Err(d[start..i].parse().unwrap_or(0.0)));
+        } else {
+            i += 1;
+        }
+    }
+    tokens
+}
+
+/// Gibt SVG-Pfaddaten (M, L, H, V, C, S, Z, absolut und relativ) in
+/// Koordinaten der viewBox an `sink` weiter.
+pub fn walk_svg_path(d: &str, sink: &mut impl PathSink) {
+    let tokens = path_tokens(d);
+    let mut i = 0;
+    let mut command = 'M';
+    let (mut x, mut y) = (0.0_f32, 0.0_f32);
+    let (mut start_x, mut start_y) = (0.0, 0.0);
+    let mut last_control: Option<(f32, f32)> = None;
+    let numbers = |i: &mut usize, n: usize| -> Option<Vec<f32>> {
+        let values: Vec<f32> = tokens.get(*i..*i + n)?.iter().map(|t| t.err()).collect::<Option<_>>()?;
+        *i += n;
+        Some(values)
+    };
+    while i < tokens.len() {
+        if let Ok(c) = tokens[i] {
+            command = c;
+            i += 1;
+            if c == 'z' || c == 'Z' {
+                sink.close();
+                (x, y) = (start_x, start_y);
+                last_control = None;
+            }
+            continue;
+        }
+        let relative = command.is_ascii_lowercase();
+        let (ox, oy) = if relative { (x, y) } else { (0.0, 0.0) };
+        match command.to_ascii_uppercase() {
+            'M' => {
+                let Some(v) = numbers(&mut i, 2) else { break };
+                (x, y) = (ox + v[0], oy + v[1]);
+                (start_x, start_y) = (x, y);
+                sink.move_to(x, y);
+                // Weitere Koordinatenpaare nach M sind Linien.
+                command = if relative { 'l' } else { 'L' };
+                last_control = None;
+            }
+            'L' => {
+                let Some(v) = numbers(&mut i, 2) else { break };
+                (x, y) = (ox + v[0], oy + v[1]);
+                sink.line_to(x, y);
+                last_control = None;
+            }
+            'H' => {
+                let Some(v) = numbers(&mut i, 1) else { break };
+                x = ox + v[0];
+                sink.line_to(x, y);
+                last_control = None;
+            }
+            'V' => {
+                let Some(v) = numbers(&mut i, 1) else { break };
+                y = oy + v[0];
+                sink.line_to(x, y);
+                last_control = None;
+            }
+            'C' => {
+                let Some(v) = numbers(&mut i, 6) else { break };
+                sink.cubic_to(ox + v[0], oy + v[1], ox + v[2], oy + v[3], ox + v[4], oy + v[5]);
+                last_control = Some((ox + v[2], oy + v[3]));
+                (x, y) = (ox + v[4], oy + v[5]);
+            }
+            'S' => {
+                let Some(v) = numbers(&mut i, 4) else { break };
+                let (x1, y1) = last_control.map(|(cx, cy)| (2.0 * x - cx, 2.0 * y - cy)).unwrap_or((x, y));
+                sink.cubic_to(x1, y1, ox + v[0], oy + v[1], ox + v[2], oy + v[3]);
+                last_control = Some((ox + v[0], oy + v[1]));
+                (x, y) = (ox + v[2], oy + v[3]);
+            }
+            _ => i += 1,
+        }
+    }
+}
+
+/// Ein dekodiertes PNG: RGB-Samples und Alphakanal, beide unkomprimiert.
+pub struct Bitmap {
+    pub width: u32,
+    pub height: u32,
+    pub rgb: Vec<u8>,
+    pub alpha: Option<Vec<u8>>,
+}
+
+pub fn decode_png(data: &[u8]) -> Option<Bitmap> {
+    let mut decoder = png::Decoder::new(data);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().ok()?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).ok()?;
+    let pixels = &buf[..info.buffer_size()];
+    let (rgb, alpha): (Vec<u8>, Option<Vec<u8>>) = match info.color_type {
+        png::ColorType::Rgba => (
+            pixels.chunks(4).flat_map(|p| [p[0], p[1], p[2]]).collect(),
+            Some(pixels.chunks(4).map(|p| p[3]).collect()),
+        ),
+        png::ColorType::Rgb => (pixels.to_vec(), None),
+        png::ColorType::GrayscaleAlpha => (
+            pixels.chunks(2).flat_map(|p| [p[0], p[0], p[0]]).collect(),
+            Some(pixels.chunks(2).map(|p| p[1]).collect()),
+        ),
+        _ => (pixels.iter().flat_map(|g| [*g, *g, *g]).collect(), None),
+    };
+    Some(Bitmap { width: info.width, height: info.height, rgb, alpha })
+}
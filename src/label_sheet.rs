@@ -0,0 +1,182 @@
+//! Mehrfachdruck auf A4-Etikettenbögen für Bürodrucker.
+//!
+//! Ein Bogen ist ein Raster gleich grosser Etiketten. Gedruckt wird zeilenweise
+//! von links oben; auf einem angebrochenen Bogen beginnt der Druck bei der
+//! ersten freien Position. Die Kalibrierung verschiebt den ganzen Druck um
+//! einige Millimeter, weil kaum ein Drucker den Bogen genau gleich einzieht.
+//! Masse der Vorlagen nach den Datenblättern der Hersteller.
+
+use crate::label_layout::{LabelLayout, LabelSize};
+use crate::label_pdf::{labels_pdf, Placement};
+use serde::{Deserialize, Serialize};
+
+/// A4 hoch, in mm.
+pub const A4_MM: (f32, f32) = (210.0, 297.0);
+
+/// Ein Etikettenbogen im Format A4.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SheetTemplate {
+    pub manufacturer: &'static str,
+    /// Artikelnummer, z.B. «L7163».
+    pub code: &'static str,
+    pub label: LabelSize,
+    pub columns: usize,
+    pub rows: usize,
+    /// Linke obere Ecke der ersten Etikette.
+    pub left_mm: f32,
+    pub top_mm: f32,
+    /// Von Etikette zu Etikette, also Etikette plus Steg.
+    pub pitch_x_mm: f32,
+    pub pitch_y_mm: f32,
+}
+
+impl SheetTemplate {
+    pub const ALL: [SheetTemplate; 7] = [
+        SheetTemplate { manufacturer: "Avery", code: "L7160", label: LabelSize { width_mm: 63.5, height_mm: 38.1 }, columns: 3, rows: 7, left_mm: 7.25, top_mm: 15.15, pitch_x_mm: 66.04, pitch_y_mm: 38.1 },
+        SheetTemplate { manufacturer: "Avery", code: "L7163", label: LabelSize { width_mm: 99.1, height_mm: 38.1 }, columns: 2, rows: 7, left_mm: 4.65, top_mm: 15.15, pitch_x_mm: 101.6, pitch_y_mm: 38.1 },
+        SheetTemplate { manufacturer: "Avery", code: "L7165", label: LabelSize { width_mm: 99.1, height_mm: 67.7 }, columns: 2, rows: 4, left_mm: 4.65, top_mm: 13.1, pitch_x_mm: 101.6, pitch_y_mm: 67.7 },
+        SheetTemplate { manufacturer: "Avery", code: "L7166", label: LabelSize { width_mm: 99.1, height_mm: 93.1 }, columns: 2, rows: 3, left_mm: 4.65, top_mm: 8.85, pitch_x_mm: 101.6, pitch_y_mm: 93.1 },
+        SheetTemplate { manufacturer: "Avery Zweckform", code: "3475", label: LabelSize { width_mm: 70.0, height_mm: 36.0 }, columns: 3, rows: 8, left_mm: 0.0, top_mm: 4.5, pitch_x_mm: 70.0, pitch_y_mm: 36.0 },
+        SheetTemplate { manufacturer: "Avery Zweckform", code: "3483", label: LabelSize { width_mm: 105.0, height_mm: 148.0 }, columns: 2, rows: 2, left_mm: 0.0, top_mm: 0.5, pitch_x_mm: 105.0, pitch_y_mm: 148.0 },
+        SheetTemplate { manufacturer: "Herma", code: "4360", label: LabelSize { width_mm: 70.0, height_mm: 36.0 }, columns: 3, rows: 8, left_mm: 0.0, top_mm: 4.5, pitch_x_mm: 70.0, pitch_y_mm: 36.0 },
+    ];
+
+    /// «Avery L7163 – 2 × 7, 99.1 × 38.1 mm»
+    pub fn name(&self) -> String {
+        format!("{} {} – {} × {}, {}", self.manufacturer, self.code, self.columns, self.rows, self.label.name())
+    }
+
+    /// Wert für `<select>`.
+    pub fn key(&self) -> String {
+        format!("{}-{}", self.manufacturer, self.code)
+    }
+
+    pub fn from_key(key: &str) -> Option<SheetTemplate> {
+        SheetTemplate::ALL.into_iter().find(|t| t.key() == key)
+    }
+
+    pub fn per_sheet(&self) -> usize {
+        self.columns * self.rows
+    }
+
+    /// Linke obere Ecke der Etikette an `slot` (ab 0, zeilenweise).
+    fn position(&self, slot: usize) -> (f32, f32) {
+        let (row, column) = (slot / self.columns, slot % self.columns);
+        (self.left_mm + column as f32 * self.pitch_x_mm, self.top_mm + row as f32 * self.pitch_y_mm)
+    }
+}
+
+impl Default for SheetTemplate {
+    fn default() -> Self {
+        SheetTemplate::ALL[2]
+    }
+}
+
+/// Verschiebung des ganzen Drucks in mm, positiv nach rechts und nach unten.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SheetOffset {
+    pub x_mm: f32,
+    pub y_mm: f32,
+}
+
+/// Was auf welche Bögen gedruckt wird.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SheetJob {
+    pub template: SheetTemplate,
+    pub copies: usize,
+    /// Erste freie Position auf dem ersten Bogen, ab 1.
+    pub start: usize,
+    pub offset: SheetOffset,
+}
+
+impl SheetJob {
+    /// Linke obere Ecken der Etiketten, Bogen für Bogen. Mindestens eine
+    /// Etikette, damit das PDF nicht leer ist.
+    pub fn positions(&self) -> Vec<Vec<(f32, f32)>> {
+        let per_sheet = self.template.per_sheet();
+        let first = self.start.clamp(1, per_sheet) - 1;
+        let mut sheets: Vec<Vec<(f32, f32)>> = Vec::new();
+        for slot in first..first + self.copies.max(1) {
+            if sheets.is_empty() || slot % per_sheet == 0 {
+                sheets.push(Vec::new());
+            }
+            let (x, y) = self.template.position(slot % per_sheet);
+            sheets.last_mut().unwrap().push((x + self.offset.x_mm, y + self.offset.y_mm));
+        }
+        sheets
+    }
+
+    pub fn sheets(&self) -> usize {
+        (self.start.clamp(1, self.template.per_sheet()) - 1 + self.copies.max(1)).div_ceil(self.template.per_sheet())
+    }
+}
+
+/// Die Bögen als PDF. `layout` muss in der Etikettengrösse der Vorlage gesetzt
+/// sein.
+pub fn sheet_pdf(layout: &LabelLayout, job: &SheetJob, title: &str, locale: &str) -> Vec<u8> {
+    let pages: Vec<Vec<Placement>> = job
+        .positions()
+        .into_iter()
+        .map(|sheet| sheet.into_iter().map(|(x, y)| Placement { layout, x, y }).collect())
+        .collect();
+    labels_pdf(A4_MM, &pages, title, locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::label_document::{LabelDocument, Line, Span, TextSize};
+    use crate::label_layout::layout;
+
+    fn l7163() -> SheetTemplate {
+        SheetTemplate::from_key("Avery-L7163").unwrap()
+    }
+
+    #[test]
+    fn templates_fit_on_a4() {
+        for t in SheetTemplate::ALL {
+            let right = t.left_mm + (t.columns - 1) as f32 * t.pitch_x_mm + t.label.width_mm;
+            let bottom = t.top_mm + (t.rows - 1) as f32 * t.pitch_y_mm + t.label.height_mm;
+            assert!(right <= A4_MM.0 + 0.01 && bottom <= A4_MM.1 + 0.01, "{}", t.name());
+            assert!(t.pitch_x_mm >= t.label.width_mm && t.pitch_y_mm >= t.label.height_mm, "{}", t.name());
+        }
+    }
+
+    #[test]
+    fn printing_starts_at_the_first_free_position_and_continues_on_new_sheets() {
+        let job = SheetJob { template: l7163(), copies: 20, start: 5, offset: SheetOffset::default() };
+        let sheets = job.positions();
+        assert_eq!(sheets.iter().map(Vec::len).collect::<Vec<_>>(), vec![10, 10]);
+        assert_eq!(job.sheets(), 2);
+        // Position 5 ist die dritte Zeile links.
+        assert_eq!(sheets[0][0], (4.65, 15.15 + 2.0 * 38.1));
+        assert_eq!(sheets[0][1], (4.65 + 101.6, 15.15 + 2.0 * 38.1));
+        assert_eq!(sheets[1][0], (4.65, 15.15));
+    }
+
+    #[test]
+    fn calibration_shifts_every_label() {
+        let offset = SheetOffset { x_mm: 1.5, y_mm: -0.5 };
+        let job = SheetJob { template: l7163(), copies: 3, start: 99, offset };
+        let sheets = job.positions();
+        // Eine zu grosse Startposition gilt als die letzte auf dem Bogen.
+        assert_eq!(sheets[0], vec![(4.65 + 101.6 + 1.5, 15.15 + 6.0 * 38.1 - 0.5)]);
+        assert_eq!(sheets[1][0], (4.65 + 1.5, 15.15 - 0.5));
+    }
+
+    #[test]
+    fn sheet_pdf_repeats_the_label_on_a4_pages() {
+        let template = l7163();
+        let document = LabelDocument {
+            logo: None,
+            sections: vec![vec![Line { size: TextSize::Title, spans: vec![Span { text: "Bergkäse Bio".to_string(), bold: false }] }]],
+        };
+        let layout = layout(&document, template.label, "de-CH");
+        let job = SheetJob { template, copies: 16, start: 1, offset: SheetOffset::default() };
+        let pdf = sheet_pdf(&layout, &job, "Bergkäse", "de-CH");
+        let text = pdf_extract::extract_text_from_mem(&pdf).unwrap();
+        assert_eq!(text.matches("Bergkäse Bio").count(), 16);
+        let count = |needle: &[u8]| pdf.windows(needle.len()).filter(|w| *w == needle).count();
+        assert_eq!(count(b"/MediaBox [0 0 595.2756 841.8898]"), 2, "two A4 sheets");
+    }
+}
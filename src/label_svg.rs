@@ -0,0 +1,130 @@
+//! SVG-Export der Etikette für die Verpackungsgestaltung.
+//!
+//! Schreibt das [`LabelLayout`] in Originalgrösse: `width`/`height` in mm und
+//! eine `viewBox` in mm, damit Illustrator und Inkscape die Etikette ohne
+//! Skalierung platzieren. Der Text bleibt editierbar und ist in DejaVu Sans
+//! gesetzt (siehe `assets/fonts`); wer die Schrift nicht installiert hat,
+//! bekommt einen Ersatz mit anderem Umbruch. Die Knospe wird als Vektorgrafik
+//! eingebettet, die Umstellungsknospe als PNG.
+
+use crate::core::html_escape;
+use crate::label_document::svg_view_box;
+use crate::label_layout::{LabelLayout, MM_PER_PT};
+use base64::Engine;
+use std::fmt::Write;
+
+/// Zahl mit höchstens drei Nachkommastellen, ohne angehängte Nullen.
+fn number(value: f32) -> String {
+    let formatted = format!("{:.3}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    if trimmed == "-0" { "0".to_string() } else { trimmed.to_string() }
+}
+
+/// Der Inhalt eines SVG-Dokuments ohne das äussere `<svg>`-Element.
+fn svg_body(svg: &str) -> &str {
+    let start = svg.find("<svg").and_then(|s| svg[s..].find('>').map(|e| s + e + 1)).unwrap_or(0);
+    let end = svg.rfind("</svg>").unwrap_or(svg.len());
+    svg[start..end].trim()
+}
+
+/// Die Etikette als eigenständiges SVG-Dokument.
+pub fn label_svg(layout: &LabelLayout, title: &str, locale: &str) -> String {
+    let (width, height) = (number(layout.size.width_mm), number(layout.size.height_mm));
+    let mut svg = String::new();
+    let _ = writeln!(svg, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" version="1.1" width="{width}mm" height="{height}mm" viewBox="0 0 {width} {height}">"#
+    );
+    if !title.is_empty() {
+        let _ = writeln!(svg, "  <title>{}</title>", html_escape(title));
+    }
+    let _ = writeln!(svg, r##"  <g font-family="'DejaVu Sans', Verdana, sans-serif" fill="#000000" xml:space="preserve">"##);
+    for run in &layout.runs {
+        let weight = if run.bold { r#" font-weight="bold""# } else { "" };
+        let _ = writeln!(
+            svg,
+            r#"    <text x="{}" y="{}" font-size="{}"{weight}>{}</text>"#,
+            number(run.x),
+            number(run.baseline),
+            number(run.size_pt * MM_PER_PT),
+            html_escape(&run.text)
+        );
+    }
+    let _ = writeln!(svg, "  </g>");
+
+    if let Some(logo) = layout.logo {
+        let (x, y, w, h) = (number(logo.x), number(logo.y), number(logo.width), number(logo.height));
+        if let Some(artwork) = logo.logo.svg() {
+            let [vx, vy, vw, vh] = svg_view_box(artwork).unwrap_or([0.0, 0.0, 1.0, 1.0]);
+            let _ = writeln!(
+                svg,
+                r#"  <svg x="{x}" y="{y}" width="{w}" height="{h}" viewBox="{} {} {} {}">"#,
+                number(vx),
+                number(vy),
+                number(vw),
+                number(vh)
+            );
+            for line in svg_body(artwork).lines() {
+                let _ = writeln!(svg, "    {}", line.trim());
+            }
+            let _ = writeln!(svg, "  </svg>");
+        } else if let Some(png) = logo.logo.png(locale) {
+            let data = base64::engine::general_purpose::STANDARD.encode(png);
+            let _ = writeln!(
+                svg,
+                r#"  <image x="{x}" y="{y}" width="{w}" height="{h}" xlink:href="data:image/png;base64,{data}"/>"#
+            );
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::label_document::{LabelDocument, LabelLogo, Line, Span, TextSize};
+    use crate::label_layout::{layout, LabelSize};
+
+    fn document(logo: Option<LabelLogo>) -> LabelDocument {
+        let span = |text: &str, bold| Span { text: text.to_string(), bold };
+        LabelDocument {
+            logo,
+            sections: vec![
+                vec![Line { size: TextSize::Title, spans: vec![span("Salz & Pfeffer Bio", false)] }],
+                vec![Line { size: TextSize::Body, spans: vec![span("Zutaten: Meersalz, ", false), span("Senf", true)] }],
+            ],
+        }
+    }
+
+    #[test]
+    fn svg_has_true_size_and_live_text() {
+        let layout = layout(&document(Some(LabelLogo::Knospe)), LabelSize { width_mm: 80.0, height_mm: 50.0 }, "de-CH");
+        let svg = label_svg(&layout, "Salz & Pfeffer", "de-CH");
+        assert!(svg.contains(r#"width="80mm" height="50mm" viewBox="0 0 80 50""#), "{svg}");
+        assert!(svg.contains("<title>Salz &amp; Pfeffer</title>"));
+        assert!(svg.contains(">Salz &amp; Pfeffer Bio</text>"), "{svg}");
+        assert!(svg.contains(r#" font-weight="bold">Senf</text>"#), "{svg}");
+        // Die Knospe als verschachteltes SVG mit ihren Pfaden, nicht als Bild.
+        assert!(svg.contains(r##"<path fill="#30a32d""##));
+        assert!(!svg.contains("<image"));
+        assert_eq!(svg.matches("<svg").count(), 2);
+        assert_eq!(svg.matches("</svg>").count(), 2);
+    }
+
+    #[test]
+    fn umstellungsknospe_is_embedded_as_png() {
+        let layout = layout(&document(Some(LabelLogo::UmstellungsknospeSatz)), LabelSize::default(), "it-CH");
+        let svg = label_svg(&layout, "", "it-CH");
+        assert!(svg.contains(r#"xlink:href="data:image/png;base64,iVBORw0KGgo"#));
+        assert!(!svg.contains("<title>"));
+    }
+
+    #[test]
+    fn numbers_are_short_and_stable() {
+        assert_eq!(number(100.0), "100");
+        assert_eq!(number(3.52778), "3.528");
+        assert_eq!(number(-0.0001), "0");
+    }
+}
@@ -0,0 +1,437 @@
+//! Ausgabe für Thermo-Etikettendrucker: ZPL (Zebra) und TSPL (TSC).
+//!
+//! Die Etikette wird in der Auflösung des Druckers schwarzweiss gerastert und
+//! als eine einzige Grafik geschickt. Die Schriften im Drucker taugen dafür
+//! nicht: Sie kennen keinen Fettdruck für die Allergene und laufen breiter
+//! oder schmaler als DejaVu Sans, womit der Umbruch aus dem Layout nicht mehr
+//! stimmen würde. Die Logos werden mitgerastert; was dunkler ist als
+//! [`INK_THRESHOLD`], wird gedruckt.
+
+use crate::label_document::svg_view_box;
+use crate::label_layout::{Font, LabelLayout, MM_PER_PT};
+use crate::label_render::{decode_png, svg_paths, walk_svg_path, PathSink};
+use ttf_parser::OutlineBuilder;
+
+/// Farben mit kleinerer Helligkeit (0–1) werden schwarz gedruckt.
+const INK_THRESHOLD: f32 = 0.6;
+/// Strecken, in die eine Kurve beim Rastern zerlegt wird.
+const CURVE_STEPS: usize = 8;
+
+/// Auflösung des Druckkopfs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PrinterResolution {
+    #[default]
+    Dpi203,
+    Dpi300,
+}
+
+impl PrinterResolution {
+    pub const ALL: [PrinterResolution; 2] = [PrinterResolution::Dpi203, PrinterResolution::Dpi300];
+
+    /// Punkte pro Millimeter, wie sie Zebra und TSC angeben.
+    pub fn dots_per_mm(self) -> f32 {
+        match self {
+            PrinterResolution::Dpi203 => 8.0,
+            PrinterResolution::Dpi300 => 12.0,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PrinterResolution::Dpi203 => "203 dpi",
+            PrinterResolution::Dpi300 => "300 dpi",
+        }
+    }
+
+    /// Wert für `<select>`.
+    pub fn key(self) -> &'static str {
+        match self {
+            PrinterResolution::Dpi203 => "203",
+            PrinterResolution::Dpi300 => "300",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<PrinterResolution> {
+        PrinterResolution::ALL.into_iter().find(|r| r.key() == key)
+    }
+}
+
+/// Einfarbige Bitmap, zeilenweise, acht Punkte pro Byte mit dem höchsten Bit
+/// links. Gesetzte Bits werden gedruckt.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Raster {
+    pub width: usize,
+    pub height: usize,
+    pub bytes_per_row: usize,
+    pub bits: Vec<u8>,
+}
+
+impl Raster {
+    fn new(width: usize, height: usize) -> Raster {
+        let bytes_per_row = width.div_ceil(8);
+        Raster { width, height, bytes_per_row, bits: vec![0; bytes_per_row * height] }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.bits[y * self.bytes_per_row + x / 8] & (0x80 >> (x % 8)) != 0
+    }
+
+    fn set(&mut self, x: usize, y: usize, ink: bool) {
+        let byte = &mut self.bits[y * self.bytes_per_row + x / 8];
+        if ink {
+            *byte |= 0x80 >> (x % 8);
+        } else {
+            *byte &= !(0x80 >> (x % 8));
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[u8] {
+        &self.bits[y * self.bytes_per_row..(y + 1) * self.bytes_per_row]
+    }
+}
+
+/// Sammelt die Kanten eines Umrisses in Druckpunkten und füllt sie nach der
+/// Nonzero-Regel. Nimmt Glyphen (y nach oben) wie SVG-Pfade (y nach unten).
+struct Outline {
+    scale: (f32, f32),
+    offset: (f32, f32),
+    edges: Vec<[f32; 4]>,
+    start: (f32, f32),
+    current: (f32, f32),
+}
+
+impl Outline {
+    fn new(scale: (f32, f32), offset: (f32, f32)) -> Outline {
+        Outline { scale, offset, edges: Vec::new(), start: offset, current: offset }
+    }
+
+    fn point(&self, x: f32, y: f32) -> (f32, f32) {
+        (x * self.scale.0 + self.offset.0, y * self.scale.1 + self.offset.1)
+    }
+
+    fn begin(&mut self, x: f32, y: f32) {
+        self.end();
+        self.start = self.point(x, y);
+        self.current = self.start;
+    }
+
+    fn edge_to(&mut self, to: (f32, f32)) {
+        if to != self.current {
+            self.edges.push([self.current.0, self.current.1, to.0, to.1]);
+        }
+        self.current = to;
+    }
+
+    fn line(&mut self, x: f32, y: f32) {
+        let to = self.point(x, y);
+        self.edge_to(to);
+    }
+
+    fn cubic(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let p0 = self.current;
+        let (p1, p2, p3) = (self.point(x1, y1), self.point(x2, y2), self.point(x, y));
+        for step in 1..=CURVE_STEPS {
+            let t = step as f32 / CURVE_STEPS as f32;
+            let u = 1.0 - t;
+            let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+            self.edge_to((a * p0.0 + b * p1.0 + c * p2.0 + d * p3.0, a * p0.1 + b * p1.1 + c * p2.1 + d * p3.1));
+        }
+    }
+
+    fn quad(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let p0 = self.current;
+        let (p1, p2) = (self.point(x1, y1), self.point(x, y));
+        for step in 1..=CURVE_STEPS {
+            let t = step as f32 / CURVE_STEPS as f32;
+            let u = 1.0 - t;
+            let (a, b, c) = (u * u, 2.0 * u * t, t * t);
+            self.edge_to((a * p0.0 + b * p1.0 + c * p2.0, a * p0.1 + b * p1.1 + c * p2.1));
+        }
+    }
+
+    /// Schliesst den offenen Teilpfad; gefüllt wird er ohnehin geschlossen.
+    fn end(&mut self) {
+        let start = self.start;
+        self.edge_to(start);
+    }
+
+    /// Setzt (oder löscht) alle Punkte, deren Mitte im Umriss liegt.
+    fn fill(mut self, raster: &mut Raster, ink: bool) {
+        self.end();
+        if self.edges.is_empty() || raster.height == 0 {
+            return;
+        }
+        let (min_y, max_y) = self
+            .edges
+            .iter()
+            .fold((f32::MAX, f32::MIN), |(lo, hi), e| (lo.min(e[1]).min(e[3]), hi.max(e[1]).max(e[3])));
+        let first = (min_y - 0.5).ceil().max(0.0) as usize;
+        let last = ((max_y - 0.5).floor().min(raster.height as f32 - 1.0)).max(-1.0) as isize;
+        let mut crossings: Vec<(f32, i32)> = Vec::new();
+        for row in first..(last + 1).max(0) as usize {
+            let y = row as f32 + 0.5;
+            crossings.clear();
+            for &[x0, y0, x1, y1] in &self.edges {
+                if (y0 <= y && y < y1) || (y1 <= y && y < y0) {
+                    crossings.push((x0 + (y - y0) * (x1 - x0) / (y1 - y0), if y1 > y0 { 1 } else { -1 }));
+                }
+            }
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let mut winding = 0;
+            for pair in crossings.windows(2) {
+                winding += pair[0].1;
+                if winding == 0 {
+                    continue;
+                }
+                let from = (pair[0].0 - 0.5).ceil().max(0.0) as usize;
+                let to = ((pair[1].0 - 0.5).ceil().max(0.0) as usize).min(raster.width);
+                for x in from..to {
+                    raster.set(x, row, ink);
+                }
+            }
+        }
+    }
+}
+
+impl PathSink for Outline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.begin(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.line(x, y);
+    }
+
+    fn cubic_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.cubic(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.end();
+    }
+}
+
+impl OutlineBuilder for Outline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.begin(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.line(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.quad(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.cubic(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.end();
+    }
+}
+
+fn prints(rgb: [f32; 3]) -> bool {
+    0.2126 * rgb[0] + 0.7152 * rgb[1] + 0.0722 * rgb[2] < INK_THRESHOLD
+}
+
+/// Rastert das Layout in der Auflösung des Druckers.
+pub fn rasterize(layout: &LabelLayout, resolution: PrinterResolution, locale: &str) -> Raster {
+    let dots = resolution.dots_per_mm();
+    let mut raster = Raster::new(
+        (layout.size.width_mm * dots).round() as usize,
+        (layout.size.height_mm * dots).round() as usize,
+    );
+
+    let (regular, bold) = (Font::regular(), Font::bold());
+    for run in &layout.runs {
+        let font = if run.bold { &bold } else { &regular };
+        let size_mm = run.size_pt * MM_PER_PT;
+        let scale = size_mm / font.units_per_em() * dots;
+        let mut pen = run.x;
+        for c in run.text.chars() {
+            let glyph = font.glyph_id(c);
+            let mut outline = Outline::new((scale, -scale), (pen * dots, run.baseline * dots));
+            font.outline(glyph, &mut outline);
+            outline.fill(&mut raster, true);
+            pen += font.advance(glyph) / 1000.0 * size_mm;
+        }
+    }
+
+    let Some(logo) = layout.logo else { return raster };
+    if let Some(svg) = logo.logo.svg() {
+        let [vx, vy, vw, vh] = svg_view_box(svg).unwrap_or([0.0, 0.0, 1.0, 1.0]);
+        let scale = (logo.width / vw * dots, logo.height / vh * dots);
+        let offset = ((logo.x - vx * logo.width / vw) * dots, (logo.y - vy * logo.height / vh) * dots);
+        for (fill, d) in svg_paths(svg) {
+            let mut outline = Outline::new(scale, offset);
+            walk_svg_path(d, &mut outline);
+            outline.fill(&mut raster, prints(fill));
+        }
+    } else if let Some(bitmap) = logo.logo.png(locale).and_then(decode_png) {
+        // Nächster Nachbar: die Bitmaps sind feiner aufgelöst als jeder Druckkopf.
+        let (left, top) = ((logo.x * dots).round() as usize, (logo.y * dots).round() as usize);
+        let (width, height) = ((logo.width * dots).round() as usize, (logo.height * dots).round() as usize);
+        for y in top..(top + height).min(raster.height) {
+            for x in left..(left + width).min(raster.width) {
+                let sx = (((x - left) as f32 + 0.5) / width as f32 * bitmap.width as f32) as usize;
+                let sy = (((y - top) as f32 + 0.5) / height as f32 * bitmap.height as f32) as usize;
+                let i = sy.min(bitmap.height as usize - 1) * bitmap.width as usize + sx.min(bitmap.width as usize - 1);
+                if bitmap.alpha.as_ref().is_some_and(|a| a[i] < 128) {
+                    continue;
+                }
+                let rgb = [bitmap.rgb[3 * i], bitmap.rgb[3 * i + 1], bitmap.rgb[3 * i + 2]].map(|c| c as f32 / 255.0);
+                raster.set(x, y, prints(rgb));
+            }
+        }
+    } else {
+        tracing::warn!("Failed to decode logo {:?}", logo.logo);
+    }
+    raster
+}
+
+/// Wiederholung eines Hexzeichens in der ZPL-Kompression: G–Y zählen 1–19,
+/// g–z 20–400.
+fn push_zpl_run(out: &mut String, c: char, mut count: usize) {
+    while count > 0 {
+        let n = count.min(419);
+        if n >= 20 {
+            out.push((b'f' + (n / 20) as u8) as char);
+        }
+        if n > 1 && !n.is_multiple_of(20) {
+            out.push((b'F' + (n % 20) as u8) as char);
+        }
+        out.push(c);
+        count -= n;
+    }
+}
+
+/// Eine Bildzeile als komprimiertes ASCII-Hex; «,» füllt den Rest der Zeile
+/// mit Nullen.
+fn zpl_row(row: &[u8]) -> String {
+    let hex: Vec<char> = row.iter().map(|b| format!("{:02X}", b)).collect::<String>().chars().collect();
+    let used = hex.iter().rposition(|&c| c != '0').map_or(0, |i| i + 1);
+    let mut out = String::new();
+    let mut i = 0;
+    while i < used {
+        let run = hex[i..used].iter().take_while(|&&c| c == hex[i]).count();
+        push_zpl_run(&mut out, hex[i], run);
+        i += run;
+    }
+    if used < hex.len() {
+        out.push(',');
+    }
+    out
+}
+
+/// ZPL II für Zebra-Drucker: die Etikette als eine `^GFA`-Grafik. Gleiche
+/// Zeilen hintereinander werden als «:» wiederholt.
+pub fn label_zpl(layout: &LabelLayout, resolution: PrinterResolution, locale: &str) -> String {
+    let raster = rasterize(layout, resolution, locale);
+    let total = raster.bytes_per_row * raster.height;
+    let mut data = String::new();
+    for y in 0..raster.height {
+        if y > 0 && raster.row(y) == raster.row(y - 1) {
+            data.push(':');
+        } else {
+            data.push_str(&zpl_row(raster.row(y)));
+        }
+    }
+    format!(
+        "^XA\n^PW{}\n^LL{}\n^LH0,0\n^FO0,0^GFA,{total},{total},{},{data}^FS\n^PQ1\n^XZ\n",
+        raster.width, raster.height, raster.bytes_per_row
+    )
+}
+
+/// TSPL für TSC-Drucker. `BITMAP` druckt gelöschte Bits, die Bitmap geht
+/// deshalb invertiert und binär hinaus. Der Abstand von 2 mm zwischen den
+/// Etiketten ist der übliche bei gestanzten Rollen.
+pub fn label_tspl(layout: &LabelLayout, resolution: PrinterResolution, locale: &str) -> Vec<u8> {
+    let raster = rasterize(layout, resolution, locale);
+    let mut out = format!(
+        "SIZE {} mm,{} mm\r\nGAP 2 mm,0 mm\r\nCLS\r\nBITMAP 0,0,{},{},0,",
+        layout.size.width_mm, layout.size.height_mm, raster.bytes_per_row, raster.height
+    )
+    .into_bytes();
+    out.extend(raster.bits.iter().map(|b| !b));
+    out.extend_from_slice(b"\r\nPRINT 1,1\r\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::label_document::{LabelDocument, LabelLogo, Line, Span, TextSize};
+    use crate::label_layout::{layout, LabelSize};
+    use std::path::Path;
+
+    fn document(logo: Option<LabelLogo>) -> LabelDocument {
+        let span = |text: &str, bold| Span { text: text.to_string(), bold };
+        LabelDocument {
+            logo,
+            sections: vec![
+                vec![Line { size: TextSize::Title, spans: vec![span("Dinkelbrot Bio", false)] }],
+                vec![Line { size: TextSize::Body, spans: vec![span("Zutaten: ", false), span("Dinkelmehl", true), span("* (", false), span("Gluten", true), span("), Wasser, Meersalz", false)] }],
+                vec![Line { size: TextSize::Body, spans: vec![span("Mindestens haltbar bis 31.12.2026", false)] }],
+            ],
+        }
+    }
+
+    /// Vergleicht mit `tests/golden/<name>`; `UPDATE_GOLDEN=1` schreibt die
+    /// Datei nach einer gewollten Änderung neu.
+    fn assert_golden(name: &str, actual: &[u8]) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(name);
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::write(&path, actual).unwrap();
+            return;
+        }
+        let expected = std::fs::read(&path).unwrap_or_else(|_| panic!("{} is missing, run with UPDATE_GOLDEN=1", path.display()));
+        assert!(expected == actual, "{name} differs from the golden file, run with UPDATE_GOLDEN=1 if the change is intended");
+    }
+
+    #[test]
+    fn zpl_matches_golden_file() {
+        let layout = layout(&document(Some(LabelLogo::Knospe)), LabelSize::default(), "de-CH");
+        assert_golden("label-100x70-203dpi.zpl", label_zpl(&layout, PrinterResolution::Dpi203, "de-CH").as_bytes());
+    }
+
+    #[test]
+    fn tspl_matches_golden_file() {
+        let size = LabelSize { width_mm: 58.0, height_mm: 40.0 };
+        let layout = layout(&document(Some(LabelLogo::UmstellungsknospeSatz)), size, "de-CH");
+        assert_golden("label-58x40-203dpi.tspl", &label_tspl(&layout, PrinterResolution::Dpi203, "de-CH"));
+    }
+
+    #[test]
+    fn raster_has_printer_dimensions_and_ink_for_text_and_logo() {
+        let size = LabelSize { width_mm: 58.0, height_mm: 40.0 };
+        let layout = layout(&document(Some(LabelLogo::Knospe)), size, "fr-CH");
+        let raster = rasterize(&layout, PrinterResolution::Dpi300, "fr-CH");
+        assert_eq!((raster.width, raster.height, raster.bytes_per_row), (696, 480, 87));
+        let logo = layout.logo.unwrap();
+        let (left, top) = ((logo.x * 12.0) as usize, (logo.y * 12.0) as usize);
+        let (width, height) = ((logo.width * 12.0) as usize, (logo.height * 12.0) as usize);
+        let logo_ink = (top..top + height).flat_map(|y| (left..left + width).map(move |x| (x, y))).filter(|&(x, y)| raster.get(x, y)).count();
+        assert!(logo_ink * 5 > width * height, "the green Knospe prints black");
+        let run = &layout.runs[0];
+        let inked = (0..raster.width).filter(|&x| raster.get(x, (run.baseline * 12.0) as usize - 2)).count();
+        assert!(inked > 20, "title glyphs are filled");
+        assert!(!raster.get(0, 0), "margin stays blank");
+    }
+
+    #[test]
+    fn zpl_rows_are_run_length_compressed() {
+        assert_eq!(zpl_row(&[0x00, 0x00]), ",");
+        assert_eq!(zpl_row(&[0xFF, 0xFF, 0x00]), "JF,");
+        assert_eq!(zpl_row(&[0x0F]), "0F");
+        assert_eq!(zpl_row(&[0xAB; 13]), "AB".repeat(13));
+        let mut run = String::new();
+        push_zpl_run(&mut run, 'F', 25);
+        assert_eq!(run, "gKF");
+        run.clear();
+        push_zpl_run(&mut run, '0', 420);
+        assert_eq!(run, "zY00");
+    }
+}
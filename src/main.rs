@@ -16,6 +16,25 @@ pub mod conditional_keys;
 pub mod verdicts;
 mod category_service;
 pub mod faq;
+pub mod mandatory_warnings;
+pub mod origin_emphasis;
+pub mod swissness;
+pub mod fish_origin;
+pub mod production_methods;
+pub mod honey_origin;
+pub mod swiss_regions;
+pub mod mountain_designation;
+pub mod protected_designations;
+pub mod certification_bodies;
+pub mod recipe_declaration;
+pub mod label_document;
+pub mod label_layout;
+pub mod label_pdf;
+pub mod label_render;
+pub mod label_sheet;
+pub mod label_svg;
+pub mod label_thermal;
+pub mod supplier_certificate;
 mod components;
 pub mod core;
 mod model;
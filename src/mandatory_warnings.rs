@@ -0,0 +1,220 @@
+//! Pflichthinweise, die einzelne Zutaten auslösen (Azofarbstoffe,
+//! Süssungsmittel, Aspartam, Polyole, Koffein).
+//!
+//! Welche Zutat welchen Hinweis auslöst, steht als Daten in
+//! `mandatory_warnings.csv`; hier wird nur der Rezepturbaum dagegen geprüft.
+//! Eine Zeile trifft eine Zutat über ihren Namen (bzw. den kanonischen
+//! food_db-Namen) oder über die E-Nummer im Namen («Farbstoff E102»).
+
+use crate::core::Ingredient;
+use rust_i18n::t;
+use serde::Deserialize;
+
+/// Art des Pflichthinweises. Jede Variante hat einen eigenen Text unter
+/// `mandatory_warnings.*`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningKind {
+    /// Azofarbstoffe (E102, E104, E110, E122, E124, E129): Hinweis auf
+    /// Aktivität und Aufmerksamkeit bei Kindern, mit Nennung der Farbstoffe.
+    AzoDyes,
+    /// «mit Süssungsmittel(n)» — gehört zur Sachbezeichnung.
+    Sweeteners,
+    /// Aspartam: «Enthält eine Phenylalaninquelle».
+    Phenylalanine,
+    /// Mehr als 10% zugesetzte Polyole: abführende Wirkung.
+    Laxative,
+    /// Erhöhter Koffeingehalt (über 150 mg/l).
+    Caffeine,
+}
+
+impl WarningKind {
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            WarningKind::AzoDyes => "mandatory_warnings.azo_dyes",
+            WarningKind::Sweeteners => "mandatory_warnings.sweeteners",
+            WarningKind::Phenylalanine => "mandatory_warnings.phenylalanine",
+            WarningKind::Laxative => "mandatory_warnings.laxative",
+            WarningKind::Caffeine => "mandatory_warnings.caffeine",
+        }
+    }
+
+    /// Der Süssungsmittel-Hinweis steht bei der Sachbezeichnung, alle anderen
+    /// als eigener Hinweis unter dem Zutatenverzeichnis.
+    pub fn belongs_to_sachbezeichnung(&self) -> bool {
+        matches!(self, WarningKind::Sweeteners)
+    }
+}
+
+/// Eine Zeile aus `mandatory_warnings.csv`.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct WarningTrigger {
+    pub warning: WarningKind,
+    pub name: String,
+    pub e_number: String,
+    /// Mindestanteil am Endprodukt in Prozent. Leer = jede Menge löst aus.
+    /// Schwellen gelten für die Summe aller Zutaten desselben Hinweises
+    /// (10% Polyole insgesamt, nicht je Polyol).
+    pub min_percent: Option<f64>,
+}
+
+/// Lädt alle Auslöser aus der eingebetteten CSV
+pub fn load_warning_triggers() -> Vec<WarningTrigger> {
+    let csv_data = include_str!("mandatory_warnings.csv");
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv_data.as_bytes());
+
+    rdr.deserialize()
+        .filter_map(|r| match r {
+            Ok(trigger) => Some(trigger),
+            Err(e) => {
+                tracing::warn!("Failed to parse mandatory warning CSV record: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Ein ausgelöster Hinweis mit den Zutaten, die ihn auslösen (in
+/// Rezepturreihenfolge, ohne Duplikate).
+#[derive(Clone, Debug, PartialEq)]
+pub struct MandatoryWarning {
+    pub kind: WarningKind,
+    pub substances: Vec<String>,
+}
+
+impl MandatoryWarning {
+    /// Text für die Etikette in der aktuellen Sprache.
+    pub fn text(&self) -> String {
+        t!(self.kind.translation_key(), substances = self.substances.join(", ")).to_string()
+    }
+}
+
+/// Klein geschrieben, ohne Leerzeichen und Bindestriche, damit «Acesulfam K»,
+/// «Acesulfam-K» und «E 950» der Schreibweise in der CSV entsprechen.
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Ob `haystack` die E-Nummer nennt und nicht bloss eine längere mit
+/// denselben Anfangsziffern (E1020 ist nicht E102).
+fn mentions_e_number(haystack: &str, e_number: &str) -> bool {
+    if e_number.is_empty() {
+        return false;
+    }
+    let needle = normalize(e_number);
+    haystack.match_indices(&needle).any(|(pos, _)| {
+        !haystack[pos + needle.len()..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_digit())
+    })
+}
+
+impl WarningTrigger {
+    fn matches(&self, ingredient: &Ingredient) -> bool {
+        let trigger_name = normalize(&self.name);
+        [Some(ingredient.name.as_str()), ingredient.canonical.as_deref()]
+            .into_iter()
+            .flatten()
+            .map(normalize)
+            .any(|n| n == trigger_name || mentions_e_number(&n, &self.e_number))
+    }
+
+    fn matches_any(&self, ingredients: &[&Ingredient]) -> bool {
+        ingredients.iter().any(|i| self.matches(i))
+    }
+}
+
+/// Die Zutaten, deren Gewicht zählt, in jeder Tiefe: Blätter des
+/// Rezepturbaums. Eine zusammengesetzte Zutat mit eigenem, massgebendem Gewicht
+/// (überschriebene Bestandteile, Bestandteile ohne Menge) ist selbst ein Blatt.
+/// Verarbeitungshilfsstoffe und übertragene Zusatzstoffe sind keine Zutaten.
+fn listed_ingredients(ingredients: &[Ingredient]) -> Vec<&Ingredient> {
+    ingredients.iter().flat_map(|i| i.ingredient_leaves()).collect()
+}
+
+/// Die Pflichthinweise, die die Rezeptur auslöst, in der Reihenfolge der
+/// `WarningKind`-Varianten. `total_amount` ist das Gewicht des Endprodukts
+/// (inkl. manuellem Total), gegen das die Schwellen gerechnet werden.
+pub fn mandatory_warnings(ingredients: &[Ingredient], total_amount: f64) -> Vec<MandatoryWarning> {
+    let triggers = load_warning_triggers();
+    let listed = listed_ingredients(ingredients);
+
+    let kinds = [
+        WarningKind::AzoDyes,
+        WarningKind::Sweeteners,
+        WarningKind::Phenylalanine,
+        WarningKind::Laxative,
+        WarningKind::Caffeine,
+    ];
+
+    kinds
+        .into_iter()
+        .filter_map(|kind| {
+            let rows: Vec<&WarningTrigger> = triggers.iter().filter(|t| t.warning == kind).collect();
+            let matched: Vec<&Ingredient> = listed
+                .iter()
+                .copied()
+                .filter(|ing| rows.iter().any(|t| t.matches(ing)))
+                .collect();
+            if matched.is_empty() {
+                return None;
+            }
+
+            let threshold = rows
+                .iter()
+                .filter(|t| t.matches_any(&matched))
+                .filter_map(|t| t.min_percent)
+                .fold(None, |acc: Option<f64>, p| Some(acc.map_or(p, |a| a.max(p))));
+            if let Some(min_percent) = threshold {
+                let weight: f64 = matched.iter().map(|i| i.computed_amount()).sum();
+                let share = if total_amount > 0.0 { weight / total_amount * 100.0 } else { 0.0 };
+                if share <= min_percent {
+                    return None;
+                }
+            }
+
+            let mut substances: Vec<String> = Vec::new();
+            for ing in matched {
+                if !substances.contains(&ing.name) {
+                    substances.push(ing.name.clone());
+                }
+            }
+            Some(MandatoryWarning { kind, substances })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warning_triggers_load_without_errors() {
+        let csv_rows = include_str!("mandatory_warnings.csv").lines().count() - 1;
+        assert_eq!(load_warning_triggers().len(), csv_rows);
+    }
+
+    #[test]
+    fn every_azo_dye_is_listed() {
+        let triggers = load_warning_triggers();
+        for e in ["E102", "E104", "E110", "E122", "E124", "E129"] {
+            assert!(
+                triggers.iter().any(|t| t.warning == WarningKind::AzoDyes && t.e_number == e),
+                "{e} missing"
+            );
+        }
+    }
+
+    #[test]
+    fn e_number_match_respects_digit_boundary() {
+        assert!(mentions_e_number("farbstoffe102", "E102"));
+        assert!(mentions_e_number("e102", "E 102"));
+        assert!(!mentions_e_number("e1020", "E102"));
+    }
+}
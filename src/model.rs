@@ -1,3 +1,4 @@
+use crate::swiss_regions::SwissRegion;
 use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Eq, Hash, Serialize, Deserialize, Clone, Debug)]
@@ -256,6 +257,28 @@ pub enum Country {
     ZA,
     ZM,
     ZW,
+    /// Kanton, Region oder Zone innerhalb der Schweiz («Emmental», «CH-GR»).
+    /// Serialisiert als blosser Regionscode, daher als letzte Variante.
+    #[serde(untagged)]
+    Region(SwissRegion),
+}
+
+/// Ländergruppe für die zusammengefasste Herkunftsangabe («EU», «Nicht-EU»).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CountryGroup {
+    Eu,
+    Efta,
+    NichtEu,
+}
+
+impl CountryGroup {
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            CountryGroup::Eu => "country_groups.eu",
+            CountryGroup::Efta => "country_groups.efta",
+            CountryGroup::NichtEu => "country_groups.nicht_eu",
+        }
+    }
 }
 
 impl Country {
@@ -265,6 +288,7 @@ impl Country {
             Country::Import => "Import",
             Country::EU => "EU",
             Country::NoOriginRequired => "Keine Herkunftsangabe benötigt",
+            Country::Region(region) => region.display_name(),
             Country::AD => "Andorra",
             Country::AE => "Vereinigte Arabische Emirate",
             Country::AF => "Afghanistan",
@@ -561,7 +585,8 @@ impl Country {
             _ => Country::ISO_COUNTRIES
                 .iter()
                 .find(|c| c.country_code() == code)
-                .cloned(),
+                .cloned()
+                .or_else(|| SwissRegion::from_code(code).map(Country::Region)),
         }
     }
 
@@ -571,6 +596,7 @@ impl Country {
             Country::CH => "countries.switzerland".to_string(),
             Country::NoOriginRequired => "countries.no_origin_required".to_string(),
             Country::Import => "countries.import".to_string(),
+            Country::Region(region) => region.translation_key(),
             other => format!("countries.{}", other.country_code()),
         }
     }
@@ -589,12 +615,25 @@ impl Country {
         }
     }
 
+    /// Short name in the active locale («USA», «VAE») for the countries whose
+    /// name is unwieldy on a label; otherwise the localized name.
+    pub fn short_name(&self) -> String {
+        let key = format!("countries_short.{}", self.country_code());
+        let translated = rust_i18n::t!(&key).to_string();
+        if translated == key || translated.ends_with(&format!(".{key}")) {
+            self.localized_name()
+        } else {
+            translated
+        }
+    }
+
     pub fn country_code(&self) -> &'static str {
         match self {
             Country::CH => "CH",
             Country::EU => "EU",
             Country::NoOriginRequired => "",
             Country::Import => "Import",
+            Country::Region(region) => region.code(),
             Country::AD => "AD",
             Country::AE => "AE",
             Country::AF => "AF",
@@ -852,6 +891,7 @@ impl Country {
             Country::EU => "🇪🇺",
             Country::NoOriginRequired => "",
             Country::Import => "",
+            Country::Region(_) => "🇨🇭",
             Country::AD => "🇦🇩",
             Country::AE => "🇦🇪",
             Country::AF => "🇦🇫",
@@ -1102,6 +1142,48 @@ impl Country {
             Country::ZW => "🇿🇼",
         }
     }
+
+    /// Gruppen, denen das Land für die zusammengefasste Herkunftsangabe
+    /// angehört. Die Schweiz und die übrigen EFTA-Staaten sind «Nicht-EU».
+    /// Die Platzhalter (`NoOriginRequired`, `Import`) gehören keiner Gruppe an.
+    pub fn groups(&self) -> Vec<CountryGroup> {
+        match self {
+            Country::NoOriginRequired | Country::Import => vec![],
+            c if c.is_eu() => vec![CountryGroup::Eu],
+            Country::CH | Country::Region(_) | Country::LI | Country::IS | Country::NO => {
+                vec![CountryGroup::Efta, CountryGroup::NichtEu]
+            }
+            _ => vec![CountryGroup::NichtEu],
+        }
+    }
+
+    /// Das Land, zu dem die Herkunft gehört: für Regionen die Schweiz, sonst
+    /// das Land selbst.
+    pub fn nation(&self) -> Country {
+        match self {
+            Country::Region(_) => Country::CH,
+            other => other.clone(),
+        }
+    }
+
+    /// Schweizer Herkunft, ob als ganzes Land oder als Kanton/Region.
+    pub fn is_swiss(&self) -> bool {
+        matches!(self, Country::CH | Country::Region(_))
+    }
+
+    /// Mitglied der Europäischen Union (oder die Sammelangabe «EU» selbst).
+    pub fn is_eu(&self) -> bool {
+        matches!(
+            self,
+            Country::EU
+                | Country::AT | Country::BE | Country::BG | Country::CY | Country::CZ
+                | Country::DE | Country::DK | Country::EE | Country::ES | Country::FI
+                | Country::FR | Country::GR | Country::HR | Country::HU | Country::IE
+                | Country::IT | Country::LT | Country::LU | Country::LV | Country::MT
+                | Country::NL | Country::PL | Country::PT | Country::RO | Country::SE
+                | Country::SI | Country::SK
+        )
+    }
 }
 
 pub fn lookup_allergen(name: &str) -> bool {
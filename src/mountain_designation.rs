@@ -0,0 +1,181 @@
+//! Berg- und Alpprodukte (Berg- und Alp-Verordnung, BAlV).
+//!
+//! «Berg» und «Alp» in der Bezeichnung sind geschützt. Berg verlangt, dass
+//! mindestens 90% des Gewichts der landwirtschaftlichen Rohstoffe aus dem
+//! Berggebiet stammen (das Sömmerungsgebiet zählt dazu), Alp dasselbe für das
+//! Sömmerungsgebiet. Milch und Milchprodukte müssen vollständig aus dem
+//! jeweiligen Gebiet stammen. Ob eine Zutat von dort kommt, steht in
+//! `Ingredient::mountain_provenance` oder folgt aus der Herkunft, wenn diese
+//! ausschliesslich die Zone Berggebiet bzw. Sömmerungsgebiet nennt.
+
+use crate::category_service::is_dairy_category;
+use crate::core::{agricultural_share, Ingredient};
+use crate::model::Country;
+use crate::swiss_regions::SwissRegion;
+use crate::verdicts::{MountainBlockReason, MountainVerdict};
+use serde::{Deserialize, Serialize};
+
+/// Mindestanteil der Rohstoffe aus dem Gebiet in Prozent.
+pub const MOUNTAIN_THRESHOLD: f64 = 90.0;
+
+/// Woher eine Zutat im Sinne der BAlV stammt.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MountainProvenance {
+    Berggebiet,
+    /// Sömmerungsgebiet (Alp); erfüllt auch die Anforderung an «Berg».
+    Soemmerungsgebiet,
+}
+
+impl MountainProvenance {
+    pub const ALL: [MountainProvenance; 2] = [MountainProvenance::Berggebiet, MountainProvenance::Soemmerungsgebiet];
+
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            MountainProvenance::Berggebiet => "mountain_provenance.berggebiet",
+            MountainProvenance::Soemmerungsgebiet => "mountain_provenance.soemmerungsgebiet",
+        }
+    }
+}
+
+/// Geschützte Bezeichnung, die Titel oder Sachbezeichnung verwenden.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MountainDesignation {
+    Berg,
+    Alp,
+}
+
+/// Wortanfänge, die «Alp» bzw. «Berg» nur zufällig enthalten.
+const NOT_ALP: &[&str] = &["alpaca", "alpaka", "alpen", "alpha", "alphorn", "alpin"];
+const NOT_BERG: &[&str] = &["bergamot", "berger"];
+
+impl MountainDesignation {
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            MountainDesignation::Berg => "mountain_designation.berg",
+            MountainDesignation::Alp => "mountain_designation.alp",
+        }
+    }
+
+    fn of_word(word: &str) -> Option<MountainDesignation> {
+        let word = word.to_lowercase();
+        let alp = (word.starts_with("alp") && !NOT_ALP.iter().any(|w| word.starts_with(w)))
+            || word.starts_with("alpage")
+            || word.starts_with("alpeggio");
+        let berg = (word.starts_with("berg") && !NOT_BERG.iter().any(|w| word.starts_with(w)))
+            || word.starts_with("montagn");
+        if alp {
+            Some(MountainDesignation::Alp)
+        } else if berg {
+            Some(MountainDesignation::Berg)
+        } else {
+            None
+        }
+    }
+
+    /// Die Bezeichnung in Titel oder Sachbezeichnung («Bergkäse», «Alpbutter»,
+    /// «fromage de montagne»). Nennen beide Texte eine, gilt die strengere: Alp.
+    pub fn in_title(title: &str, subtitle: &str) -> Option<MountainDesignation> {
+        let found: Vec<MountainDesignation> = [title, subtitle]
+            .iter()
+            .flat_map(|t| t.split(|c: char| !c.is_alphanumeric()))
+            .filter_map(MountainDesignation::of_word)
+            .collect();
+        found
+            .iter()
+            .find(|d| **d == MountainDesignation::Alp)
+            .or(found.first())
+            .copied()
+    }
+
+    fn accepts(&self, provenance: MountainProvenance) -> bool {
+        match self {
+            MountainDesignation::Berg => true,
+            MountainDesignation::Alp => provenance == MountainProvenance::Soemmerungsgebiet,
+        }
+    }
+}
+
+/// Herkunft einer Zutat aus dem Berg- oder Sömmerungsgebiet: die Angabe an
+/// der Zutat, sonst die Zone, wenn alle Herkünfte sie nennen.
+pub fn provenance(ingredient: &Ingredient) -> Option<MountainProvenance> {
+    if ingredient.mountain_provenance.is_some() {
+        return ingredient.mountain_provenance;
+    }
+    let origins = ingredient.computed_origins().filter(|o| !o.is_empty())?;
+    let zone = |c: &Country| match c {
+        Country::Region(SwissRegion::Berggebiet) => Some(MountainProvenance::Berggebiet),
+        Country::Region(SwissRegion::Soemmerungsgebiet) => Some(MountainProvenance::Soemmerungsgebiet),
+        _ => None,
+    };
+    let zones: Vec<Option<MountainProvenance>> = origins.iter().map(zone).collect();
+    if zones.iter().any(Option::is_none) {
+        None
+    } else if zones.iter().all(|z| *z == Some(MountainProvenance::Soemmerungsgebiet)) {
+        Some(MountainProvenance::Soemmerungsgebiet)
+    } else {
+        Some(MountainProvenance::Berggebiet)
+    }
+}
+
+fn qualifies(ingredient: &Ingredient, designation: MountainDesignation) -> bool {
+    provenance(ingredient).is_some_and(|p| designation.accepts(p))
+}
+
+/// Anteil der landwirtschaftlichen Rohstoffe in Prozent, die die Bezeichnung
+/// tragen dürfen.
+pub fn mountain_share(ingredients: &[Ingredient], designation: MountainDesignation) -> f64 {
+    agricultural_share(ingredients, |_| true, |i| qualifies(i, designation), 0.0)
+}
+
+/// Milchrohstoffe, die nicht aus dem Gebiet stammen.
+fn dairy_outside_area(ingredients: &[Ingredient], designation: MountainDesignation) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let outside = ingredients
+        .iter()
+        .flat_map(|i| i.ingredient_leaves())
+        .filter(|leaf| leaf.effective_category().is_some_and(|c| is_dairy_category(&c)))
+        .filter(|leaf| !qualifies(leaf, designation));
+    for leaf in outside {
+        if !names.contains(&leaf.name) {
+            names.push(leaf.name.clone());
+        }
+    }
+    names
+}
+
+/// Urteil über die im Titel verwendete Bezeichnung.
+pub fn decide_mountain_designation(ingredients: &[Ingredient], designation: MountainDesignation) -> MountainVerdict {
+    let share = mountain_share(ingredients, designation);
+    let mut reasons = Vec::new();
+    if share < MOUNTAIN_THRESHOLD {
+        reasons.push(MountainBlockReason::ShareBelow90);
+    }
+    let dairy = dairy_outside_area(ingredients, designation);
+    if !dairy.is_empty() {
+        reasons.push(MountainBlockReason::MilkOutsideArea { ingredients: dairy });
+    }
+    if reasons.is_empty() {
+        MountainVerdict::Allowed { designation, share }
+    } else {
+        MountainVerdict::NotAllowed { designation, share, reasons }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn designation_is_found_in_compounds_and_languages() {
+        assert_eq!(MountainDesignation::in_title("Bergkäse", ""), Some(MountainDesignation::Berg));
+        assert_eq!(MountainDesignation::in_title("Emmentaler", "Alp-Butter"), Some(MountainDesignation::Alp));
+        assert_eq!(MountainDesignation::in_title("Fromage de montagne", ""), Some(MountainDesignation::Berg));
+        assert_eq!(MountainDesignation::in_title("Bergkäse", "vom Alpbetrieb"), Some(MountainDesignation::Alp));
+        assert_eq!(MountainDesignation::in_title("Alpenmilch-Schokolade", ""), None);
+        assert_eq!(MountainDesignation::in_title("Bergamotte-Tee", ""), None);
+        assert_eq!(MountainDesignation::in_title("Heidelberger Brot", ""), None);
+        assert_eq!(MountainDesignation::in_title("Alpaka-Salami", ""), None);
+        assert_eq!(MountainDesignation::in_title("Alphorn-Biber", ""), None);
+    }
+}
@@ -0,0 +1,132 @@
+//! Herkunftsangabe der Primärzutat bei hervorgehobener Herkunft.
+//!
+//! Hebt die Etikette eine Herkunft hervor (Schweizerkreuz, «Schweizer …» im
+//! Titel), stammt die Primärzutat aber (auch) aus einem anderen Land, muss
+//! deren Herkunft angegeben werden («Rohstoff aus …»). Primärzutaten sind die
+//! Zutaten über 50% und die namensgebenden Zutaten.
+
+use crate::core::{Ingredient, OriginStyle};
+use crate::model::Country;
+use rust_i18n::t;
+
+/// Wortanfänge, mit denen ein Titel die Schweizer Herkunft hervorhebt (klein
+/// geschrieben): «Schweizer», «Swissness», «Berg-Schweizer».
+const SWISS_EMPHASIS: &[&str] = &["schweiz", "swiss", "suisse", "svizzer", "svizzera", "helvet"];
+
+/// Das Zertifizierungszeichen «(Knospe) Bio Suisse» nennt den Verband, nicht
+/// die Herkunft; es wird vor der Suche entfernt.
+const CERTIFICATION_MARKS: &[&str] = &["bio suisse", "bio-suisse", "biosuisse"];
+
+fn mentions_switzerland(text: &str) -> bool {
+    let mut lower = text.to_lowercase().replace('\u{a0}', " ");
+    for mark in CERTIFICATION_MARKS {
+        lower = lower.replace(mark, " ");
+    }
+    lower
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|word| SWISS_EMPHASIS.iter().any(|stem| word.starts_with(stem)))
+}
+
+/// Die hervorgehobene Herkunft, sofern Titel, Untertitel oder das Feld
+/// Produktionsland eine betonen. Das Produktionsland allein («Schweiz»,
+/// «Suisse») ist die Pflichtangabe und keine Hervorhebung; erst ein Zusatz
+/// wie «Schweizer Qualität» ist es.
+pub fn emphasised_country(title: &str, subtitle: &str, production_country: &str) -> Option<Country> {
+    let plain_country = production_country.trim().eq_ignore_ascii_case(&Country::CH.localized_name())
+        || production_country.trim().eq_ignore_ascii_case(Country::CH.display_name());
+    let production_emphasis = !plain_country && mentions_switzerland(production_country);
+    (mentions_switzerland(title) || mentions_switzerland(subtitle) || production_emphasis).then_some(Country::CH)
+}
+
+/// Pflichtangabe «<Zutat> aus <Ländern>» für eine Primärzutat.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrimaryOriginStatement {
+    pub ingredient: String,
+    pub origins: Vec<Country>,
+    /// Schreibweise der Länder wie in der Zutatenliste.
+    pub origin_style: OriginStyle,
+}
+
+impl PrimaryOriginStatement {
+    /// Text für die Etikette in der aktuellen Sprache.
+    pub fn text(&self) -> String {
+        let origins = self
+            .origins
+            .iter()
+            .map(|c| self.origin_style.name(c))
+            .collect::<Vec<_>>()
+            .join(", ");
+        t!("origin_emphasis.statement", ingredient = self.ingredient, origins = origins).to_string()
+    }
+}
+
+/// Ergebnis der Prüfung einer Primärzutat.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PrimaryOriginCheck {
+    /// Herkunft deckt sich mit der Hervorhebung — nichts zu tun.
+    Matches,
+    /// Herkunft weicht ab: die Angabe muss auf die Etikette.
+    Statement(PrimaryOriginStatement),
+    /// Herkunft unbekannt: ohne sie lässt sich die Angabe nicht machen.
+    OriginMissing,
+}
+
+/// Die Primärzutaten (Index auf oberster Ebene): über 50% des Endprodukts
+/// oder namensgebend.
+pub fn primary_ingredients(ingredients: &[Ingredient], total_amount: f64) -> Vec<usize> {
+    ingredients
+        .iter()
+        .enumerate()
+        .filter(|(_, ing)| {
+            ing.is_namensgebend == Some(true)
+                || (total_amount > 0.0 && ing.computed_amount() / total_amount * 100.0 > 50.0)
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Vergleicht die Herkunft einer Primärzutat mit der hervorgehobenen.
+pub fn check_primary_ingredient(ingredient: &Ingredient, emphasised: &Country, origin_style: OriginStyle) -> PrimaryOriginCheck {
+    let mut origins: Vec<Country> = ingredient
+        .computed_origins()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|c| !matches!(c, Country::NoOriginRequired | Country::Import))
+        .collect();
+    if origins.is_empty() {
+        return PrimaryOriginCheck::OriginMissing;
+    }
+    if origins.iter().all(|c| c.nation() == *emphasised) {
+        return PrimaryOriginCheck::Matches;
+    }
+    origins.sort_by_key(|c| c.country_code());
+    origins.dedup();
+    PrimaryOriginCheck::Statement(PrimaryOriginStatement {
+        ingredient: ingredient.name.clone(),
+        origins,
+        origin_style,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_production_country_is_no_emphasis() {
+        rust_i18n::set_locale("de-CH");
+        assert_eq!(emphasised_country("Apfelmus", "", "Schweiz"), None);
+        assert_eq!(emphasised_country("Schweizer Apfelmus", "", "Schweiz"), Some(Country::CH));
+        assert_eq!(emphasised_country("Apfelmus", "", "Schweizer Qualität"), Some(Country::CH));
+        assert_eq!(emphasised_country("Apfelmus", "Swiss made", ""), Some(Country::CH));
+        assert_eq!(emphasised_country("Apfelmus", "Knospe Bio Suisse", ""), None);
+        assert_eq!(emphasised_country("Apfelmus Bio Suisse", "aus der Suisse romande", ""), Some(Country::CH));
+        assert_eq!(emphasised_country("Berg-Schweizer Käse", "", ""), Some(Country::CH));
+    }
+
+    #[test]
+    fn emphasis_needs_a_whole_word() {
+        assert_eq!(emphasised_country("Kaiserschmarrn", "Aschweizer Hof", ""), None);
+        assert_eq!(emphasised_country("Crème Mousse", "Unswissable", ""), None);
+    }
+}
@@ -1,9 +1,18 @@
 use crate::components::*;
-use crate::core::{Calculator, Ingredient, Input, Output};
+use crate::core::{Calculator, Ingredient, Input, OriginAggregation, OriginStyle, Output};
 use crate::model::Country;
 use crate::layout::{CopyLinkContext, ThemeContext};
 use crate::rules::{RuleDef, RuleRegistry};
-use crate::shared::{restore_params_from_session_storage, Configuration, Validations, VerdictsContext};
+use crate::recipe_declaration::RecipeDeclaration;
+use crate::label_document::LabelDocument;
+use crate::label_layout::{layout, LabelSize};
+use crate::label_pdf::label_pdf;
+use crate::label_sheet::{sheet_pdf, SheetJob, SheetOffset, SheetTemplate};
+use crate::label_svg::label_svg;
+use crate::label_thermal::{label_tspl, label_zpl, PrinterResolution};
+use crate::persistence::{get_sheet_offset, save_sheet_offset};
+use crate::protected_designations::{load_protected_designations, protected_designation_in};
+use crate::shared::{download_file, restore_params_from_session_storage, Configuration, ProductionDate, Validations, VerdictsContext};
 use dioxus::prelude::*;
 use rust_i18n::t;
 use serde::{Deserialize, Serialize};
@@ -62,6 +71,14 @@ impl MonoQuality {
         )
     }
 
+    /// Whether the single ingredient comes from a conversion farm.
+    pub fn is_umstellung(&self) -> bool {
+        matches!(
+            self,
+            MonoQuality::BioUmstellung | MonoQuality::UmstellungKnospeCh | MonoQuality::UmstellungKnospeImport
+        )
+    }
+
     /// The single ingredient a mono product implies, so the calculator can run
     /// its normal Bio/Knospe percentage math. `name` stays empty: the label
     /// prints no ingredient list in this mode, only logos and the
@@ -116,6 +133,14 @@ pub struct Form {
     /// Quality of the single ingredient when `ignore_ingredients` is set (DEC-2).
     #[serde(default)]
     pub mono_quality: MonoQuality,
+    /// The single ingredient is honey, which has to name its origin (AP7.7).
+    #[serde(default)]
+    pub mono_honey: bool,
+    /// End of the conversion period of an Umstellung mono product (YYYY-MM-DD).
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub mono_umstellung_bis: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mono_origins: Option<Vec<Country>>,
     #[serde(default)]
     pub product_title: String,
     #[serde(default)]
@@ -128,6 +153,11 @@ pub struct Form {
     pub date_prefix: String,
     #[serde(default)]
     pub date: String,
+    /// Production/label date (YYYY-MM-DD) as of which conversion ends are
+    /// judged. Set once when the form is created, so a saved label renders the
+    /// same later; links from before this field get the day they are opened.
+    #[serde(default = "default_production_date")]
+    pub production_date: String,
     #[serde(default)]
     pub production_country: String,
     #[serde(default)]
@@ -146,9 +176,18 @@ pub struct Form {
     pub producer_city: String,
     #[serde(default)]
     pub certification_body: String,
+    /// AOP/IGP certificate for a protected designation in the title.
+    #[serde(default)]
+    pub protected_designation_certificate: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub manual_total: Option<f64>,
     #[serde(default)]
+    pub freie_reihenfolge_unter_2_prozent: bool,
+    #[serde(default)]
+    pub origin_aggregation: OriginAggregation,
+    #[serde(default)]
+    pub origin_style: OriginStyle,
+    #[serde(default)]
     pub amount_type: AmountType,
     #[serde(default = "default_weight_unit")]
     pub weight_unit: String,
@@ -179,13 +218,28 @@ fn default_date_prefix() -> String {
     t!("label.mindestensHaltbar").to_string()
 }
 
+/// Today in the user's time zone, not UTC: around midnight the two differ.
+fn default_production_date() -> String {
+    chrono::Local::now().date_naive().format("%Y-%m-%d").to_string()
+}
+
 impl From<Form> for Input {
     fn from(val: Form) -> Self {
         // Einzelzutat/Monoprodukt: there is no recipe, but the declared quality
         // still has to drive the Bio/Knospe rules. Feeding it in as a single
         // synthetic ingredient keeps all of core.rs unchanged (DEC-2).
         let ingredients = if val.ignore_ingredients {
-            vec![val.mono_quality.synthetic_ingredient()]
+            let mut mono = val.mono_quality.synthetic_ingredient();
+            if val.mono_quality.is_umstellung() && !val.mono_umstellung_bis.is_empty() {
+                mono.umstellung_bis = Some(val.mono_umstellung_bis);
+            }
+            if val.mono_honey {
+                mono.category = Some("Honig".to_string());
+                if let Some(origins) = val.mono_origins.filter(|o| !o.is_empty()) {
+                    mono.origins = Some(origins);
+                }
+            }
+            vec![mono]
         } else {
             val.ingredients
         };
@@ -199,6 +253,20 @@ impl From<Form> for Input {
             },
             rezeptur_vollstaendig: val.rezeptur_vollstaendig,
             ignore_ingredients: val.ignore_ingredients,
+            freie_reihenfolge_unter_2_prozent: val.freie_reihenfolge_unter_2_prozent,
+            origin_aggregation: val.origin_aggregation,
+            origin_style: val.origin_style,
+            product_title: val.product_title,
+            product_subtitle: val.product_subtitle,
+            production_country: val.production_country,
+            protected_designation_certificate: if val.protected_designation_certificate.is_empty() {
+                None
+            } else {
+                Some(val.protected_designation_certificate)
+            },
+            // The date picker stores the label date as DD.MM.YYYY.
+            best_before: chrono::NaiveDate::parse_from_str(&val.date, "%d.%m.%Y").ok(),
+            production_date: chrono::NaiveDate::parse_from_str(&val.production_date, "%Y-%m-%d").ok(),
         }
     }
 }
@@ -210,12 +278,16 @@ impl Default for Form {
             ingredients: Vec::new(),
             ignore_ingredients: false,
             mono_quality: MonoQuality::default(),
+            mono_honey: false,
+            mono_umstellung_bis: String::new(),
+            mono_origins: None,
             product_title: String::new(),
             product_subtitle: String::new(),
             additional_info: String::new(),
             storage_info: String::new(),
             date_prefix: t!("label.mindestensHaltbar").to_string(),
             date: String::new(),
+            production_date: default_production_date(),
             production_country: t!("countries.switzerland").to_string(),
             producer_name: String::new(),
             producer_address: String::new(),
@@ -225,7 +297,11 @@ impl Default for Form {
             producer_zip: String::new(),
             producer_city: String::new(),
             certification_body: String::new(),
+            protected_designation_certificate: String::new(),
             manual_total: None,
+            freie_reihenfolge_unter_2_prozent: false,
+            origin_aggregation: OriginAggregation::default(),
+            origin_style: OriginStyle::default(),
             amount_type: AmountType::Weight,
             weight_unit: t!("weight_units.g").to_string(),
             volume_unit: t!("volume_units.ml").to_string(),
@@ -274,6 +350,9 @@ pub fn LabelPage(configuration: Configuration) -> Element {
 
     let mut ignore_ingredients = use_signal(|| false);
     let mut mono_quality = use_signal(|| initial_form.read().mono_quality);
+    let mut mono_honey = use_signal(|| initial_form.read().mono_honey);
+    let mut mono_umstellung_bis = use_signal(|| initial_form.read().mono_umstellung_bis.clone());
+    let mut mono_origins = use_signal(|| initial_form.read().mono_origins.clone());
     let mut rezeptur_vollstaendig = use_signal(|| initial_form.read().rezeptur_vollstaendig);
     let mut ingredients: Signal<Vec<Ingredient>> =
         use_signal(|| initial_form.read().ingredients.clone());
@@ -283,6 +362,7 @@ pub fn LabelPage(configuration: Configuration) -> Element {
     let mut storage_info = use_signal(|| initial_form.read().storage_info.clone());
     let mut date_prefix = use_signal(|| initial_form.read().date_prefix.clone());
     let mut date = use_signal(|| initial_form.read().date.clone());
+    let mut production_date = use_signal(|| initial_form.read().production_date.clone());
     let mut production_country = use_signal(|| initial_form.read().production_country.clone());
     let mut producer_name = use_signal(|| initial_form.read().producer_name.clone());
     let mut producer_address = use_signal(|| initial_form.read().producer_address.clone());
@@ -292,7 +372,11 @@ pub fn LabelPage(configuration: Configuration) -> Element {
     let mut producer_zip = use_signal(|| initial_form.read().producer_zip.clone());
     let mut producer_city = use_signal(|| initial_form.read().producer_city.clone());
     let mut certification_body = use_signal(|| initial_form.read().certification_body.clone());
+    let mut protected_designation_certificate = use_signal(|| initial_form.read().protected_designation_certificate.clone());
     let mut manual_total = use_signal(|| initial_form.read().manual_total);
+    let mut freie_reihenfolge = use_signal(|| initial_form.read().freie_reihenfolge_unter_2_prozent);
+    let mut origin_aggregation = use_signal(|| initial_form.read().origin_aggregation);
+    let mut origin_style = use_signal(|| initial_form.read().origin_style);
     let mut amount_type: Signal<AmountType> = use_signal(|| initial_form.read().amount_type.clone());
     let mut weight_unit: Signal<String> = use_signal(|| initial_form.read().weight_unit.clone());
     let mut volume_unit: Signal<String> = use_signal(|| initial_form.read().volume_unit.clone());
@@ -307,6 +391,9 @@ pub fn LabelPage(configuration: Configuration) -> Element {
         if !url_params.read().is_empty() {
             ignore_ingredients.set(form_data.ignore_ingredients);
             mono_quality.set(form_data.mono_quality);
+            mono_honey.set(form_data.mono_honey);
+            mono_umstellung_bis.set(form_data.mono_umstellung_bis.clone());
+            mono_origins.set(form_data.mono_origins.clone());
             rezeptur_vollstaendig.set(form_data.rezeptur_vollstaendig);
             ingredients.set(form_data.ingredients.clone());
             product_title.set(form_data.product_title.clone());
@@ -315,6 +402,7 @@ pub fn LabelPage(configuration: Configuration) -> Element {
             storage_info.set(form_data.storage_info.clone());
             date_prefix.set(form_data.date_prefix.clone());
             date.set(form_data.date.clone());
+            production_date.set(form_data.production_date.clone());
             production_country.set(form_data.production_country.clone());
             producer_name.set(form_data.producer_name.clone());
             producer_address.set(form_data.producer_address.clone());
@@ -324,7 +412,11 @@ pub fn LabelPage(configuration: Configuration) -> Element {
             producer_zip.set(form_data.producer_zip.clone());
             producer_city.set(form_data.producer_city.clone());
             certification_body.set(form_data.certification_body.clone());
+            protected_designation_certificate.set(form_data.protected_designation_certificate.clone());
             manual_total.set(form_data.manual_total);
+            freie_reihenfolge.set(form_data.freie_reihenfolge_unter_2_prozent);
+            origin_aggregation.set(form_data.origin_aggregation);
+            origin_style.set(form_data.origin_style);
             amount_type.set(form_data.amount_type.clone());
             weight_unit.set(form_data.weight_unit.clone());
             volume_unit.set(form_data.volume_unit.clone());
@@ -340,12 +432,16 @@ pub fn LabelPage(configuration: Configuration) -> Element {
         ingredients: ingredients(),
         ignore_ingredients: ignore_ingredients(),
         mono_quality: mono_quality(),
+        mono_honey: mono_honey(),
+        mono_umstellung_bis: mono_umstellung_bis(),
+        mono_origins: mono_origins(),
         product_title: product_title(),
         product_subtitle: product_subtitle(),
         additional_info: additional_info(),
         storage_info: storage_info(),
         date_prefix: date_prefix(),
         date: date(),
+        production_date: production_date(),
         production_country: production_country(),
         producer_name: producer_name(),
         producer_address: producer_address(),
@@ -355,7 +451,11 @@ pub fn LabelPage(configuration: Configuration) -> Element {
         producer_zip: producer_zip(),
         producer_city: producer_city(),
         certification_body: certification_body(),
+        protected_designation_certificate: protected_designation_certificate(),
         manual_total: manual_total(),
+        freie_reihenfolge_unter_2_prozent: freie_reihenfolge(),
+        origin_aggregation: origin_aggregation(),
+        origin_style: origin_style(),
         amount_type: amount_type(),
         weight_unit: weight_unit(),
         volume_unit: volume_unit(),
@@ -392,7 +492,7 @@ pub fn LabelPage(configuration: Configuration) -> Element {
     });
 
     let calc_output: Memo<Output> = use_memo(move || {
-        let mut calc = Calculator::new();
+        let mut calc = Calculator::new(configuration());
         calc.rule_defs = rules();
         let form: Form = current_state.read().clone();
         calc.execute(form.into())
@@ -403,75 +503,88 @@ pub fn LabelPage(configuration: Configuration) -> Element {
 
     use_context_provider(|| Validations(validation_messages));
     use_context_provider(|| VerdictsContext(verdicts));
+    use_context_provider(|| ProductionDate(production_date));
 
     // Calculate derived values for amount and price
     let get_base_factor = use_memo(move || {
-        match (
-            &*amount_type.read(),
-            weight_unit.read().as_str(),
-            volume_unit.read().as_str(),
-        ) {
-            (AmountType::Weight, "mg", _) => 100_usize,
-            (AmountType::Weight, "g", _) => 100_usize,
-            (AmountType::Weight, "kg", _) => 1_usize,
-            (AmountType::Volume, _, "ml") => 100_usize,
-            (AmountType::Volume, _, "cl") => 100_usize,
-            (AmountType::Volume, _, "l") => 1_usize,
-            (_, _, _) => 1_usize,
-        }
+        amount_type.read().base_factor(&weight_unit.read(), &volume_unit.read())
     });
 
-    let calculated_amount = use_memo(move || match price() {
-        Price::Double(Some(unit_price), Some(total_price)) => (
-            true,
-            ((total_price as f64 / unit_price as f64) * get_base_factor() as f64) as usize,
-        ),
-        _ => (false, 0),
+    let calculated_amount = use_memo(move || {
+        price().calculated_amount(get_base_factor()).map_or((false, 0), |x| (true, x))
     });
 
     let calculated_total_price = use_memo(move || {
-        let net_amount = match amount() {
-            Amount::Single(Some(x)) => x,
-            Amount::Double(Some(x), _) => x,
-            _ => 0,
-        };
-        if net_amount == 0 {
-            return (false, 0);
-        }
-        match price() {
-            // Calculate total price when only unit price is provided
-            Price::Double(Some(unit_price), None) => (
-                true,
-                (unit_price as f64 * (net_amount as f64 / get_base_factor() as f64)) as usize,
-            ),
-            // For single price fields, calculate total
-            Price::Single(Some(unit_price)) => (
-                true,
-                (unit_price as f64 * (net_amount as f64 / get_base_factor() as f64)) as usize,
-            ),
-            _ => (false, 0),
-        }
+        price().calculated_total(amount(), get_base_factor()).map_or((false, 0), |x| (true, x))
     });
 
     let calculated_unit_price = use_memo(move || {
-        let net_amount = match amount() {
-            Amount::Single(Some(x)) => x,
-            Amount::Double(Some(x), _) => x,
-            _ => 0,
-        };
-        if net_amount == 0 {
-            return (false, 0);
+        price().calculated_unit_price(amount(), get_base_factor()).map_or((false, 0), |x| (true, x))
+    });
+
+    let has_cert = configuration.read().has_certification_body();
+    let protected_designation = use_memo(move || {
+        if !rules.read().contains(&RuleDef::AOP_IGP_GeschuetzteBezeichnung) {
+            return None;
         }
-        match price() {
-            Price::Double(_, Some(total_price)) => (
-                true,
-                (total_price as f64 / (net_amount as f64 / get_base_factor() as f64)) as usize,
-            ),
-            _ => (false, 0),
+        let registry = load_protected_designations();
+        protected_designation_in(&[&product_title(), &product_subtitle()], &registry).cloned()
+    });
+    // Knospe licence application: the recipe declaration as data URLs, so the
+    // links download the current state without a round trip.
+    let recipe_declaration = use_memo(move || {
+        if configuration() != Configuration::Knospe || ignore_ingredients() {
+            return None;
         }
+        let input: Input = current_state.read().clone().into();
+        let declaration = RecipeDeclaration::new(&input, &calc_output.read(), &producer_name());
+        Some((
+            format!("data:text/csv;charset=utf-8,{}", urlencoding::encode(&declaration.to_csv())),
+            format!("data:text/html;charset=utf-8,{}", urlencoding::encode(&declaration.to_html())),
+        ))
     });
 
-    let has_cert = configuration.read().has_certification_body();
+    // Export: the printable label as data, set at the chosen size. The layout
+    // is kept current so an overflow shows before anyone downloads.
+    let mut label_size = use_signal(LabelSize::default);
+    let label_document = use_memo(move || LabelDocument::new(&current_state.read(), &calc_output.read(), has_cert));
+    let label_layout = use_memo(move || layout(&label_document.read(), label_size(), &rust_i18n::locale()));
+    let export_title = move || [product_title(), product_subtitle()].into_iter().find(|t| !t.is_empty()).unwrap_or_default();
+    let export_pdf = move |_| {
+        let pdf = label_pdf(&label_layout.read(), &export_title(), &rust_i18n::locale());
+        download_file("etikette.pdf", "application/pdf", &pdf);
+    };
+    let export_svg = move |_| {
+        let svg = label_svg(&label_layout.read(), &export_title(), &rust_i18n::locale());
+        download_file("etikette.svg", "image/svg+xml", svg.as_bytes());
+    };
+    let mut printer_resolution = use_signal(PrinterResolution::default);
+    let export_zpl = move |_| {
+        let zpl = label_zpl(&label_layout.read(), printer_resolution(), &rust_i18n::locale());
+        download_file("etikette.zpl", "application/octet-stream", zpl.as_bytes());
+    };
+    let export_tspl = move |_| {
+        let tspl = label_tspl(&label_layout.read(), printer_resolution(), &rust_i18n::locale());
+        download_file("etikette.tspl", "application/octet-stream", &tspl);
+    };
+
+    // Sheets: the same label several times on an A4 label sheet, set at the
+    // template's label size. The calibration belongs to the printer and is
+    // remembered in this browser.
+    let mut sheet_template = use_signal(SheetTemplate::default);
+    let mut sheet_copies = use_signal(|| SheetTemplate::default().per_sheet());
+    let mut sheet_start = use_signal(|| 1_usize);
+    let mut sheet_offset = use_signal(get_sheet_offset);
+    let sheet_layout = use_memo(move || layout(&label_document.read(), sheet_template().label, &rust_i18n::locale()));
+    let sheet_job = move || SheetJob { template: sheet_template(), copies: sheet_copies(), start: sheet_start(), offset: sheet_offset() };
+    let export_sheet = move |_| {
+        let pdf = sheet_pdf(&sheet_layout.read(), &sheet_job(), &export_title(), &rust_i18n::locale());
+        download_file("etiketten-a4.pdf", "application/pdf", &pdf);
+    };
+    let mut set_sheet_offset = move |offset: SheetOffset| {
+        sheet_offset.set(offset);
+        save_sheet_offset(offset);
+    };
 
     rsx! {
         div {
@@ -484,9 +597,13 @@ pub fn LabelPage(configuration: Configuration) -> Element {
                         FormField {
                             label: t!("label.produktname").to_string(),
                             help: Some(t!("help.produktname").to_string()),
-                            TextInput {
-                                placeholder: t!("placeholder.produktname").to_string(),
-                                bound_value: product_title
+                            // BAlV: «Berg» / «Alp» the recipe does not support.
+                            ValidationDisplay {
+                                paths: vec!["product_title".to_string()],
+                                TextInput {
+                                    placeholder: t!("placeholder.produktname").to_string(),
+                                    bound_value: product_title
+                                }
                             }
                         }
                         FormField {
@@ -499,6 +616,21 @@ pub fn LabelPage(configuration: Configuration) -> Element {
                                 required: true
                             }
                         }
+                        // AOP/IGP: a registered name in title or subtitle asks
+                        // for the certificate right below the two fields.
+                        if protected_designation().is_some() {
+                            FormField {
+                                label: t!("label.protected_designation_certificate").to_string(),
+                                help: Some(t!("help.protected_designation_certificate").to_string()),
+                                ValidationDisplay {
+                                    paths: vec!["protected_designation_certificate".to_string()],
+                                    TextInput {
+                                        placeholder: t!("placeholder.protected_designation_certificate").to_string(),
+                                        bound_value: protected_designation_certificate
+                                    }
+                                }
+                            }
+                        }
                         SeparatorLine {}
                         if ingredients.read().is_empty() || ignore_ingredients() {
                             FormField {
@@ -522,7 +654,49 @@ pub fn LabelPage(configuration: Configuration) -> Element {
                                 required: true,
                                 MonoQualitySelect {
                                     quality: mono_quality,
-                                    configuration: configuration
+                                    configuration: configuration,
+                                    conversion_end: mono_umstellung_bis
+                                }
+                            }
+                        }
+                        // AP7.7: honey as a mono product names its origin on the
+                        // label; blends may use the «Mischung von Honig aus …» wording.
+                        if ignore_ingredients() {
+                            FormField {
+                                label: t!("label.mono_honey").to_string(),
+                                help: Some(t!("help.mono_honey").to_string()),
+                                inline_checkbox: true,
+                                CheckboxInput {
+                                    bound_value: mono_honey
+                                }
+                            }
+                            if mono_honey() {
+                                FormField {
+                                    label: t!("origin.herkunft").to_string(),
+                                    required: true,
+                                    ValidationDisplay {
+                                        paths: vec!["ingredients[0][origin]".to_string()],
+                                        MultiCountrySelect {
+                                            values: mono_origins(),
+                                            onchange: move |origins| mono_origins.set(origins),
+                                        }
+                                    }
+                                }
+                                if mono_origins().is_some_and(|o| o.len() > 1) {
+                                    FormField {
+                                        label: t!("label.herkunftZusammenfassen").to_string(),
+                                        select {
+                                            class: "select select-bordered w-full",
+                                            onchange: move |evt| {
+                                                origin_aggregation.set(match evt.data.value().as_str() {
+                                                    "eu_nicht_eu" => OriginAggregation::EuNichtEu,
+                                                    _ => OriginAggregation::Laender,
+                                                });
+                                            },
+                                            option { value: "laender", selected: origin_aggregation() == OriginAggregation::Laender, {t!(OriginAggregation::Laender.translation_key()).to_string()} }
+                                            option { value: "eu_nicht_eu", selected: origin_aggregation() != OriginAggregation::Laender, {t!("honey_origin.mischung_option").to_string()} }
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -535,6 +709,9 @@ pub fn LabelPage(configuration: Configuration) -> Element {
                                     ingredients: ingredients,
                                     validation_messages: validation_messages,
                                     manual_total: manual_total,
+                                    freie_reihenfolge: freie_reihenfolge,
+                                    origin_aggregation: origin_aggregation,
+                                    origin_style: origin_style,
                                     rules: rules,
                                     rezeptur_vollstaendig: rezeptur_vollstaendig
                                 }
@@ -571,6 +748,20 @@ pub fn LabelPage(configuration: Configuration) -> Element {
                                     bound_value: storage_info
                                 }
                             }
+                            FormField {
+                                label: t!("label.produktionsdatum").to_string(),
+                                help: Some(t!("help.produktionsdatum").to_string()),
+                                input {
+                                    r#type: "date",
+                                    class: "input input-bordered w-full",
+                                    value: "{production_date}",
+                                    oninput: move |evt| {
+                                        if !evt.data.value().is_empty() {
+                                            production_date.set(evt.data.value());
+                                        }
+                                    }
+                                }
+                            }
                         }
                         SeparatorLine {}
 
@@ -632,7 +823,163 @@ pub fn LabelPage(configuration: Configuration) -> Element {
                                 required: true,
                                 help: configuration().certification_body_help_key().map(|k| t!(k).to_string()),
                                 CertificationBodySelect {
-                                    bound_value: certification_body
+                                    bound_value: certification_body,
+                                    configuration: configuration,
+                                }
+                            }
+                        }
+                        if let Some((csv_url, html_url)) = recipe_declaration() {
+                            SeparatorLine {}
+                            FormField {
+                                label: t!("label.rezepturmeldung").to_string(),
+                                help: Some(t!("help.rezepturmeldung").to_string()),
+                                div { class: "flex gap-2",
+                                    a { class: "btn btn-sm btn-outline", href: csv_url, download: "rezepturmeldung.csv",
+                                        {t!("buttons.rezepturmeldung_csv").to_string()}
+                                    }
+                                    a { class: "btn btn-sm btn-outline", href: html_url, download: "rezepturmeldung.html",
+                                        {t!("buttons.rezepturmeldung_html").to_string()}
+                                    }
+                                }
+                            }
+                        }
+                        SeparatorLine {}
+                        FormField {
+                            label: t!("label.label_export").to_string(),
+                            help: Some(t!("help.label_export").to_string()),
+                            div { class: "flex flex-wrap items-center gap-2",
+                                select {
+                                    class: "select select-bordered select-sm",
+                                    aria_label: t!("label_export.size").to_string(),
+                                    onchange: move |evt| {
+                                        if let Some(size) = LabelSize::from_key(&evt.value()) {
+                                            label_size.set(size);
+                                        }
+                                    },
+                                    for size in LabelSize::PRESETS {
+                                        option { value: size.key(), selected: size == label_size(), {size.name()} }
+                                    }
+                                }
+                                button { class: "btn btn-sm btn-outline", onclick: export_pdf,
+                                    {t!("buttons.pdf_export").to_string()}
+                                }
+                                button { class: "btn btn-sm btn-outline", onclick: export_svg,
+                                    {t!("buttons.svg_export").to_string()}
+                                }
+                            }
+                            div { class: "mt-2 flex flex-wrap items-center gap-2",
+                                select {
+                                    class: "select select-bordered select-sm",
+                                    aria_label: t!("label_export.resolution").to_string(),
+                                    onchange: move |evt| {
+                                        if let Some(resolution) = PrinterResolution::from_key(&evt.value()) {
+                                            printer_resolution.set(resolution);
+                                        }
+                                    },
+                                    for resolution in PrinterResolution::ALL {
+                                        option { value: resolution.key(), selected: resolution == printer_resolution(), {resolution.name()} }
+                                    }
+                                }
+                                button { class: "btn btn-sm btn-outline", onclick: export_zpl,
+                                    {t!("buttons.zpl_export").to_string()}
+                                }
+                                button { class: "btn btn-sm btn-outline", onclick: export_tspl,
+                                    {t!("buttons.tspl_export").to_string()}
+                                }
+                            }
+                            if label_layout.read().overflow {
+                                div { class: "mt-2 text-sm text-warning",
+                                    {t!("label_export.overflow", size = label_size().name()).to_string()}
+                                }
+                            }
+                        }
+                        SeparatorLine {}
+                        FormField {
+                            label: t!("label.label_sheet").to_string(),
+                            help: Some(t!("help.label_sheet").to_string()),
+                            div { class: "flex flex-wrap items-end gap-2",
+                                label { class: "flex flex-col gap-1",
+                                    span { class: "label-text", {t!("label_sheet.template").to_string()} }
+                                    select {
+                                        class: "select select-bordered select-sm",
+                                        onchange: move |evt| {
+                                            if let Some(template) = SheetTemplate::from_key(&evt.value()) {
+                                                sheet_template.set(template);
+                                                sheet_start.set(sheet_start().min(template.per_sheet()));
+                                            }
+                                        },
+                                        for template in SheetTemplate::ALL {
+                                            option { value: template.key(), selected: template == sheet_template(), {template.name()} }
+                                        }
+                                    }
+                                }
+                                label { class: "flex flex-col gap-1",
+                                    span { class: "label-text", {t!("label_sheet.copies").to_string()} }
+                                    input {
+                                        class: "input input-bordered input-sm w-24",
+                                        r#type: "number",
+                                        min: "1",
+                                        value: "{sheet_copies}",
+                                        oninput: move |evt| {
+                                            if let Ok(copies) = evt.value().parse::<usize>() {
+                                                sheet_copies.set(copies.clamp(1, 999));
+                                            }
+                                        }
+                                    }
+                                }
+                                label { class: "flex flex-col gap-1",
+                                    span { class: "label-text", {t!("label_sheet.start").to_string()} }
+                                    input {
+                                        class: "input input-bordered input-sm w-24",
+                                        r#type: "number",
+                                        min: "1",
+                                        max: "{sheet_template().per_sheet()}",
+                                        value: "{sheet_start}",
+                                        oninput: move |evt| {
+                                            if let Ok(start) = evt.value().parse::<usize>() {
+                                                sheet_start.set(start.clamp(1, sheet_template().per_sheet()));
+                                            }
+                                        }
+                                    }
+                                }
+                                label { class: "flex flex-col gap-1",
+                                    span { class: "label-text", {t!("label_sheet.offset_x").to_string()} }
+                                    input {
+                                        class: "input input-bordered input-sm w-24",
+                                        r#type: "number",
+                                        step: "0.1",
+                                        value: "{sheet_offset().x_mm}",
+                                        oninput: move |evt| {
+                                            if let Ok(x_mm) = evt.value().parse::<f32>() {
+                                                set_sheet_offset(SheetOffset { x_mm, ..sheet_offset() });
+                                            }
+                                        }
+                                    }
+                                }
+                                label { class: "flex flex-col gap-1",
+                                    span { class: "label-text", {t!("label_sheet.offset_y").to_string()} }
+                                    input {
+                                        class: "input input-bordered input-sm w-24",
+                                        r#type: "number",
+                                        step: "0.1",
+                                        value: "{sheet_offset().y_mm}",
+                                        oninput: move |evt| {
+                                            if let Ok(y_mm) = evt.value().parse::<f32>() {
+                                                set_sheet_offset(SheetOffset { y_mm, ..sheet_offset() });
+                                            }
+                                        }
+                                    }
+                                }
+                                button { class: "btn btn-sm btn-outline", onclick: export_sheet,
+                                    {t!("buttons.sheet_export").to_string()}
+                                }
+                            }
+                            div { class: "mt-2 text-sm",
+                                {t!("label_sheet.sheets", sheets = sheet_job().sheets()).to_string()}
+                            }
+                            if sheet_layout.read().overflow {
+                                div { class: "mt-2 text-sm text-warning",
+                                    {t!("label_export.overflow", size = sheet_template().label.name()).to_string()}
                                 }
                             }
                         }
@@ -1,9 +1,11 @@
 use crate::core::Ingredient;
+use crate::label_sheet::SheetOffset;
 use serde::{Deserialize, Serialize};
 use web_sys::Storage;
 use rust_i18n::t;
 
 const SAVED_INGREDIENTS_KEY: &str = "saved_composite_ingredients";
+const SHEET_OFFSET_KEY: &str = "label_sheet_offset";
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct SavedIngredient {
@@ -98,3 +100,22 @@ pub fn delete_saved_ingredient(name: &str) -> Result<(), String> {
     
     Ok(())
 }
+
+/// Get the sheet printing calibration; it belongs to the printer, so it is
+/// kept per browser rather than per label
+pub fn get_sheet_offset() -> SheetOffset {
+    get_storage()
+        .and_then(|storage| storage.get_item(SHEET_OFFSET_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save the sheet printing calibration
+pub fn save_sheet_offset(offset: SheetOffset) {
+    let Some(storage) = get_storage() else { return };
+    if let Ok(json) = serde_json::to_string(&offset) {
+        if storage.set_item(SHEET_OFFSET_KEY, &json).is_err() {
+            tracing::warn!("{}", t!("errors.localstorage_save_failed"));
+        }
+    }
+}
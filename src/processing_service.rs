@@ -1,5 +1,21 @@
 use serde::Deserialize;
 
+/// Zulassung eines Verarbeitungsschritts nach den Bio-Suisse-Richtlinien.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Zulassung {
+    Erlaubt,
+    /// Nur mit Bewilligung der Markenkommission Verarbeitung und Handel.
+    Bewilligungspflichtig,
+    /// Für Knospe-Produkte ausgeschlossen. Zeilen ohne Hauptgruppe gelten für
+    /// alle Gruppen.
+    Verboten,
+}
+
+/// Der Wildsammlung-Schritt ist eine Qualität, kein Verarbeitungsverfahren,
+/// und für jede Gruppe zulässig.
+pub const WILDSAMMLUNG_STEP: &str = "aus zertifizierter Wildsammlung";
+
 /// Struktur für eine Verarbeitungsregel aus der CSV
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct ProcessingRule {
@@ -13,6 +29,7 @@ pub struct ProcessingRule {
     /// stays a faithful image of `processing_rules.csv` and deserialisation does
     /// not have to ignore a column.
     pub is_default: bool,
+    pub zulassung: Zulassung,
 }
 
 /// Lädt alle Verarbeitungsregeln aus der eingebetteten CSV
@@ -33,17 +50,57 @@ pub fn load_processing_rules() -> Vec<ProcessingRule> {
         .collect()
 }
 
+fn applies_to_category(rule: &ProcessingRule, hauptgruppe: &str, untergruppe: &str) -> bool {
+    rule.biosuisse_hauptgruppe == hauptgruppe
+        && (rule.biosuisse_untergruppe == untergruppe || rule.biosuisse_untergruppe.is_empty())
+}
+
 /// Gibt verfügbare Verarbeitungsschritte für eine BioSuisse-Kategorie zurück
 pub fn get_steps_for_category(hauptgruppe: &str, untergruppe: &str) -> Vec<ProcessingRule> {
     load_processing_rules()
         .into_iter()
-        .filter(|r| {
-            r.biosuisse_hauptgruppe == hauptgruppe
-                && (r.biosuisse_untergruppe == untergruppe || r.biosuisse_untergruppe.is_empty())
-        })
+        .filter(|r| applies_to_category(r, hauptgruppe, untergruppe) && r.zulassung != Zulassung::Verboten)
         .collect()
 }
 
+/// Ein Verarbeitungsschritt, der für Knospe-Produkte nicht ohne Weiteres
+/// zulässig ist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepIssue {
+    Verboten { step: String },
+    Bewilligungspflichtig { step: String },
+    /// Der Schritt steht für die Gruppe nicht in der Auswahlliste. Die Liste
+    /// ist nicht abschliessend; der Schritt muss manuell geprüft werden.
+    NichtGelistet { step: String },
+}
+
+/// Prüft Verarbeitungsschritte gegen die Regeln der Bio-Suisse-Gruppe, der die
+/// BLV-Kategorie zugeordnet ist. `rules` aus [`load_processing_rules`], einmal
+/// für die ganze Rezeptur geladen. `None`, wenn die Kategorie keiner Gruppe
+/// zugeordnet ist: dann muss die Verarbeitung manuell geprüft werden.
+pub fn check_steps_for_blv_category(rules: &[ProcessingRule], blv_category: &str, steps: &[String]) -> Option<Vec<StepIssue>> {
+    let (hauptgruppe, untergruppe) = get_biosuisse_category_from_blv(blv_category)?;
+    let issues = steps
+        .iter()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty() && *s != WILDSAMMLUNG_STEP)
+        .filter_map(|step| {
+            let rule = rules.iter().find(|r| {
+                (r.biosuisse_hauptgruppe.is_empty() || applies_to_category(r, &hauptgruppe, &untergruppe))
+                    && (r.step_de.eq_ignore_ascii_case(step) || r.step_fr.eq_ignore_ascii_case(step))
+            });
+            let step = step.to_string();
+            match rule.map(|r| r.zulassung) {
+                Some(Zulassung::Erlaubt) => None,
+                Some(Zulassung::Bewilligungspflichtig) => Some(StepIssue::Bewilligungspflichtig { step }),
+                Some(Zulassung::Verboten) => Some(StepIssue::Verboten { step }),
+                None => Some(StepIssue::NichtGelistet { step }),
+            }
+        })
+        .collect();
+    Some(issues)
+}
+
 /// Ermittelt BioSuisse-Kategorie aus BLV-Kategorie via Mapping
 /// Matches against all three language columns (de, en, fr) and handles
 /// semicolon-separated category strings from the BLV API.
@@ -101,6 +158,19 @@ pub fn get_steps_for_blv_category(blv_category: &str) -> Vec<ProcessingRule> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn every_rule_parses() {
+        let csv_rows = include_str!("processing_rules.csv").lines().skip(1).filter(|l| !l.is_empty()).count();
+        assert_eq!(load_processing_rules().len(), csv_rows);
+    }
+
+    #[test]
+    fn forbidden_steps_are_not_offered() {
+        let steps = get_steps_for_blv_category("Milch");
+        assert!(!steps.is_empty());
+        assert!(steps.iter().all(|s| s.zulassung != Zulassung::Verboten));
+    }
+
     #[test]
     fn biosuisse_mapping_by_german_name() {
         let result = get_biosuisse_category_from_blv("Milch");
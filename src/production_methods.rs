@@ -0,0 +1,170 @@
+//! Produktionsmethoden, die in der Schweiz nicht zugelassen sind
+//! (Landwirtschaftsdeklarationsverordnung, LDV).
+//!
+//! Eier aus Käfighaltung und Fleisch, das mit Hormonen oder Antibiotika als
+//! Leistungsförderer erzeugt worden sein kann, brauchen einen Hinweis auf der
+//! Etikette. Bei importierten Eiern und importiertem Fleisch muss die
+//! Produktionsmethode deshalb angegeben sein; Schweizer Ware ist per Gesetz
+//! nicht betroffen.
+
+use crate::category_service::{is_egg_category, is_meat_category};
+use crate::core::Ingredient;
+use crate::model::Country;
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
+
+/// Haltungsform der Legehennen.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EggHousing {
+    Freilandhaltung,
+    Bodenhaltung,
+    /// In der Schweiz nicht zugelassen: löst den Hinweis aus.
+    Kaefighaltung,
+}
+
+impl EggHousing {
+    pub const ALL: [EggHousing; 3] = [EggHousing::Freilandhaltung, EggHousing::Bodenhaltung, EggHousing::Kaefighaltung];
+
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            EggHousing::Freilandhaltung => "egg_housing.freilandhaltung",
+            EggHousing::Bodenhaltung => "egg_housing.bodenhaltung",
+            EggHousing::Kaefighaltung => "egg_housing.kaefighaltung",
+        }
+    }
+}
+
+/// Leistungsförderer, mit denen das Fleisch erzeugt worden sein kann.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PerformanceEnhancers {
+    Keine,
+    Hormone,
+    Antibiotika,
+    HormoneUndAntibiotika,
+}
+
+impl PerformanceEnhancers {
+    pub const ALL: [PerformanceEnhancers; 4] = [
+        PerformanceEnhancers::Keine,
+        PerformanceEnhancers::Hormone,
+        PerformanceEnhancers::Antibiotika,
+        PerformanceEnhancers::HormoneUndAntibiotika,
+    ];
+
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            PerformanceEnhancers::Keine => "performance_enhancers.keine",
+            PerformanceEnhancers::Hormone => "performance_enhancers.hormone",
+            PerformanceEnhancers::Antibiotika => "performance_enhancers.antibiotika",
+            PerformanceEnhancers::HormoneUndAntibiotika => "performance_enhancers.hormone_und_antibiotika",
+        }
+    }
+}
+
+/// Art des LDV-Hinweises. Jede Variante hat einen eigenen Text unter
+/// `production_method_statements.*`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProductionMethodKind {
+    /// «Eier aus in der Schweiz nicht zugelassener Käfighaltung».
+    Kaefighaltung,
+    /// «Kann mit Hormonen als Leistungsförderer erzeugt worden sein».
+    Hormone,
+    /// «Kann mit Antibiotika und/oder anderen antimikrobiellen
+    /// Leistungsförderern erzeugt worden sein».
+    Antibiotika,
+    /// Beide Fleischhinweise in einem Satz.
+    HormoneUndAntibiotika,
+}
+
+impl ProductionMethodKind {
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            ProductionMethodKind::Kaefighaltung => "production_method_statements.kaefighaltung",
+            ProductionMethodKind::Hormone => "production_method_statements.hormone",
+            ProductionMethodKind::Antibiotika => "production_method_statements.antibiotika",
+            ProductionMethodKind::HormoneUndAntibiotika => "production_method_statements.hormone_und_antibiotika",
+        }
+    }
+
+    fn of(ingredient: &Ingredient) -> Option<ProductionMethodKind> {
+        let category = ingredient.effective_category()?;
+        if is_egg_category(&category) {
+            return (ingredient.egg_housing == Some(EggHousing::Kaefighaltung))
+                .then_some(ProductionMethodKind::Kaefighaltung);
+        }
+        if is_meat_category(&category) {
+            return match ingredient.performance_enhancers? {
+                PerformanceEnhancers::Keine => None,
+                PerformanceEnhancers::Hormone => Some(ProductionMethodKind::Hormone),
+                PerformanceEnhancers::Antibiotika => Some(ProductionMethodKind::Antibiotika),
+                PerformanceEnhancers::HormoneUndAntibiotika => Some(ProductionMethodKind::HormoneUndAntibiotika),
+            };
+        }
+        None
+    }
+}
+
+/// Ein ausgelöster Hinweis mit den betroffenen Zutaten (in
+/// Rezepturreihenfolge, ohne Duplikate).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProductionMethodStatement {
+    pub kind: ProductionMethodKind,
+    pub ingredients: Vec<String>,
+}
+
+impl ProductionMethodStatement {
+    /// Text für die Etikette in der aktuellen Sprache.
+    pub fn text(&self) -> String {
+        t!(self.kind.translation_key(), ingredients = self.ingredients.join(", ")).to_string()
+    }
+}
+
+/// Stammt die Zutat (oder bei Fleisch die Aufzucht) nicht aus der Schweiz?
+/// Liechtenstein gehört zum Zollgebiet und zählt wie die Schweiz.
+pub fn is_imported(ingredient: &Ingredient) -> bool {
+    let domestic = |c: &Country| c.is_swiss() || *c == Country::LI;
+    ingredient.computed_origins().unwrap_or_default().iter().any(|c| !domestic(c))
+        || ingredient.aufzucht_ort.as_ref().is_some_and(|c| !domestic(c))
+}
+
+/// Die LDV-Hinweise, die die Rezeptur auslöst, in der Reihenfolge der
+/// `ProductionMethodKind`-Varianten.
+pub fn production_method_statements(ingredients: &[Ingredient]) -> Vec<ProductionMethodStatement> {
+    let kinds = [
+        ProductionMethodKind::Kaefighaltung,
+        ProductionMethodKind::Hormone,
+        ProductionMethodKind::Antibiotika,
+        ProductionMethodKind::HormoneUndAntibiotika,
+    ];
+    let leaves: Vec<&Ingredient> = ingredients.iter().flat_map(|i| i.ingredient_leaves()).collect();
+    kinds
+        .into_iter()
+        .filter_map(|kind| {
+            let mut names: Vec<String> = Vec::new();
+            for leaf in leaves.iter().filter(|l| ProductionMethodKind::of(l) == Some(kind)) {
+                if !names.contains(&leaf.name) {
+                    names.push(leaf.name.clone());
+                }
+            }
+            (!names.is_empty()).then_some(ProductionMethodStatement { kind, ingredients: names })
+        })
+        .collect()
+}
+
+/// Fehlende Produktionsmethode einer importierten Ei- oder Fleischzutat, als
+/// (Feld, Übersetzungsschlüssel).
+pub fn missing_production_method(ingredient: &Ingredient) -> Option<(&'static str, &'static str)> {
+    let category = ingredient.effective_category()?;
+    if !is_imported(ingredient) {
+        return None;
+    }
+    if is_egg_category(&category) && ingredient.egg_housing.is_none() {
+        Some(("egg_housing", "validation.egg_housing_required"))
+    } else if is_meat_category(&category) && ingredient.performance_enhancers.is_none() {
+        Some(("performance_enhancers", "validation.performance_enhancers_required"))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,122 @@
+//! Geschützte Ursprungsbezeichnungen (AOP) und geografische Angaben (IGP).
+//!
+//! Namen wie «Gruyère», «Emmentaler» oder «Bündnerfleisch» sind im
+//! Schweizer AOP/IGP-Register eingetragen und dürfen nur von zertifizierten
+//! Betrieben verwendet werden. Das Register steht in
+//! `protected_designations.csv`: ein Eintrag pro Bezeichnung mit Schutzart und
+//! allen Schreibweisen (Sprachen, mit und ohne Akzent), getrennt durch `|`.
+//! Die vorgeschlagene neutrale Bezeichnung steht in den Übersetzungen unter
+//! `protected_designation_alternatives.<id>`.
+
+use serde::Deserialize;
+
+/// Schutzart des Registereintrags; bestimmt das verlangte Zertifikat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Protection {
+    /// Appellation d'origine protégée (GUB).
+    Aop,
+    /// Indication géographique protégée (GGA).
+    Igp,
+}
+
+impl Protection {
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            Protection::Aop => "protection.aop",
+            Protection::Igp => "protection.igp",
+        }
+    }
+}
+
+/// Eine Zeile aus `protected_designations.csv`.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ProtectedDesignation {
+    pub id: String,
+    pub schutz: Protection,
+    bezeichnungen: String,
+}
+
+impl ProtectedDesignation {
+    /// Alle Schreibweisen; die erste ist der Name, wie er im Register steht.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.bezeichnungen.split('|').map(str::trim).filter(|n| !n.is_empty())
+    }
+
+    pub fn name(&self) -> &str {
+        self.names().next().unwrap_or_default()
+    }
+
+    pub fn alternative_key(&self) -> String {
+        format!("protected_designation_alternatives.{}", self.id)
+    }
+}
+
+/// Lädt das Register aus der eingebetteten CSV
+pub fn load_protected_designations() -> Vec<ProtectedDesignation> {
+    let csv_data = include_str!("protected_designations.csv");
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv_data.as_bytes());
+
+    rdr.deserialize()
+        .filter_map(|r| match r {
+            Ok(designation) => Some(designation),
+            Err(e) => {
+                tracing::warn!("Failed to parse protected designation CSV record: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Kleinschreibung mit typografischem Apostroph als geradem, damit
+/// «L’Etivaz» und «L'Etivaz» gleich aussehen.
+fn normalize(text: &str) -> String {
+    text.to_lowercase().replace('’', "'")
+}
+
+/// Kommt `name` in `text` an einem Wortanfang vor? Das Wortende ist offen, so
+/// dass auch Zusammensetzungen («Emmentalerkäse») erkannt werden.
+fn mentions(text: &str, name: &str) -> bool {
+    let name = normalize(name);
+    text.match_indices(&name).any(|(i, _)| {
+        text[..i].chars().next_back().is_none_or(|c| !c.is_alphanumeric())
+    })
+}
+
+/// Der erste Registereintrag, den Titel oder Sachbezeichnung verwenden.
+pub fn protected_designation_in<'a>(
+    texts: &[&str],
+    registry: &'a [ProtectedDesignation],
+) -> Option<&'a ProtectedDesignation> {
+    let texts: Vec<String> = texts.iter().map(|t| normalize(t)).collect();
+    registry
+        .iter()
+        .find(|d| d.names().any(|name| texts.iter().any(|t| mentions(t, name))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_parses_completely() {
+        let csv_rows = include_str!("protected_designations.csv").lines().skip(1).filter(|l| !l.is_empty()).count();
+        let registry = load_protected_designations();
+        assert_eq!(registry.len(), csv_rows);
+        assert!(registry.iter().any(|d| d.name() == "Gruyère" && d.schutz == Protection::Aop));
+        assert!(registry.iter().any(|d| d.name() == "Bündnerfleisch" && d.schutz == Protection::Igp));
+    }
+
+    #[test]
+    fn names_match_at_word_starts_only() {
+        let registry = load_protected_designations();
+        let find = |t: &str| protected_designation_in(&[t], &registry).map(|d| d.id.as_str());
+        assert_eq!(find("Emmentalerkäse"), Some("emmentaler"));
+        assert_eq!(find("Fondue mit GRUYERE"), Some("gruyere"));
+        assert_eq!(find("L’Etivaz AOP"), Some("etivaz"));
+        assert_eq!(find("Käse aus dem Emmental"), None);
+        assert_eq!(find("Kräutersauerkäsecreme"), None);
+    }
+}
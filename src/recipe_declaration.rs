@@ -0,0 +1,233 @@
+//! Rezepturmeldung für den Knospe-Lizenzantrag.
+//!
+//! Bio Suisse verlangt pro Rezeptur eine Liste aller Zutaten mit Anteil,
+//! Qualität, Herkunft, Lieferant, Verarbeitung und der Begründung für erlaubte
+//! nicht-biologische Ausnahmen, dazu den Knospe- und den Schweizer Anteil. Die
+//! Meldung wird aus der bereits berechneten Rezeptur aufgebaut und als CSV (für
+//! die Weiterverarbeitung) oder als druckbares HTML ausgegeben.
+
+use crate::core::{html_escape, Ingredient, Input, Output};
+use rust_i18n::t;
+
+/// Qualität einer Zutat, wie sie die Rezepturmeldung ausweist.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeclaredQuality {
+    Knospe,
+    Umstellung,
+    /// Erlaubte nicht-biologische Zutat; die Begründung steht in der Meldung.
+    ErlaubteAusnahme,
+    Konventionell,
+    NichtLandwirtschaftlich,
+}
+
+impl DeclaredQuality {
+    pub fn of(ingredient: &Ingredient) -> DeclaredQuality {
+        let ausnahme = ingredient.erlaubte_ausnahme_knospe.unwrap_or(false)
+            || ingredient.erlaubte_ausnahme_bio.unwrap_or(false);
+        if !ingredient.is_agricultural() {
+            DeclaredQuality::NichtLandwirtschaftlich
+        } else if ingredient.aus_umstellbetrieb.unwrap_or(false) {
+            DeclaredQuality::Umstellung
+        } else if ingredient.is_bio.unwrap_or(false) {
+            DeclaredQuality::Knospe
+        } else if ausnahme {
+            DeclaredQuality::ErlaubteAusnahme
+        } else if ingredient.is_knospe_compliant() {
+            // Zusammengesetzte Zutat, deren Bestandteile alle konform sind.
+            DeclaredQuality::Knospe
+        } else {
+            DeclaredQuality::Konventionell
+        }
+    }
+
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            DeclaredQuality::Knospe => "recipe_declaration.quality.knospe",
+            DeclaredQuality::Umstellung => "recipe_declaration.quality.umstellung",
+            DeclaredQuality::ErlaubteAusnahme => "recipe_declaration.quality.erlaubte_ausnahme",
+            DeclaredQuality::Konventionell => "recipe_declaration.quality.konventionell",
+            DeclaredQuality::NichtLandwirtschaftlich => "recipe_declaration.quality.nicht_landwirtschaftlich",
+        }
+    }
+}
+
+/// Eine Zeile der Meldung. Bestandteile zusammengesetzter Zutaten folgen
+/// ihrer Zutat mit `level` + 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeclarationRow {
+    pub level: usize,
+    pub name: String,
+    /// Anteil am Endprodukt in Prozent.
+    pub share: f64,
+    pub quality: DeclaredQuality,
+    /// Ländercodes, kommagetrennt.
+    pub origins: String,
+    pub supplier: String,
+    pub processing_steps: String,
+    /// Begründung einer erlaubten nicht-biologischen Ausnahme.
+    pub exception: String,
+}
+
+/// Die Rezepturmeldung einer Rezeptur.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecipeDeclaration {
+    pub product: String,
+    pub licensee: String,
+    pub certification_body: String,
+    pub knospe_share: Option<f64>,
+    pub swiss_share: Option<f64>,
+    pub rows: Vec<DeclarationRow>,
+}
+
+fn rows_for(ingredient: &Ingredient, level: usize, total: f64, rows: &mut Vec<DeclarationRow>) {
+    let origins = ingredient
+        .computed_origins()
+        .unwrap_or_default()
+        .iter()
+        .map(|c| c.country_code())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let exception = [
+        ingredient.erlaubte_ausnahme_knospe_details.as_deref(),
+        ingredient.erlaubte_ausnahme_bio_details.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(str::trim)
+    .find(|d| !d.is_empty())
+    .unwrap_or_default()
+    .to_string();
+    rows.push(DeclarationRow {
+        level,
+        name: ingredient.name.clone(),
+        share: if total > 0.0 { ingredient.computed_amount() / total * 100.0 } else { 0.0 },
+        quality: DeclaredQuality::of(ingredient),
+        origins,
+        supplier: ingredient.supplier_certificate.as_ref().map(|c| c.summary()).unwrap_or_default(),
+        processing_steps: ingredient.processing_steps.clone().unwrap_or_default().join(", "),
+        exception,
+    });
+    for child in ingredient.children.iter().flatten() {
+        rows_for(child, level + 1, total, rows);
+    }
+}
+
+impl RecipeDeclaration {
+    /// Baut die Meldung aus der Eingabe und dem Ergebnis des Calculators.
+    /// Anteile beziehen sich auf die Menge des Endprodukts (`total_amount`).
+    pub fn new(input: &Input, output: &Output, licensee: &str) -> RecipeDeclaration {
+        let mut rows = Vec::new();
+        for ingredient in &input.ingredients {
+            rows_for(&ingredient.resolve_percentages().resolve_conversion(input.production_date), 0, output.total_amount, &mut rows);
+        }
+        let product = [input.product_title.trim(), input.product_subtitle.trim()]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(" – ");
+        RecipeDeclaration {
+            product,
+            licensee: licensee.trim().to_string(),
+            certification_body: input.certification_body.clone().unwrap_or_default(),
+            knospe_share: output.verdicts.knospe_shares.map(|s| s.knospe),
+            swiss_share: output.verdicts.knospe_shares.map(|s| s.swiss),
+            rows,
+        }
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        let percent = |v: Option<f64>| v.map(|v| format!("{:.1}%", v)).unwrap_or_default();
+        vec![
+            (t!("recipe_declaration.product").to_string(), self.product.clone()),
+            (t!("recipe_declaration.licensee").to_string(), self.licensee.clone()),
+            (t!("recipe_declaration.certification_body").to_string(), self.certification_body.clone()),
+            (t!("recipe_declaration.knospe_share").to_string(), percent(self.knospe_share)),
+            (t!("recipe_declaration.swiss_share").to_string(), percent(self.swiss_share)),
+        ]
+    }
+
+    fn header() -> Vec<String> {
+        [
+            "recipe_declaration.column.ingredient",
+            "recipe_declaration.column.share",
+            "recipe_declaration.column.quality",
+            "recipe_declaration.column.origin",
+            "recipe_declaration.column.supplier",
+            "recipe_declaration.column.processing",
+            "recipe_declaration.column.exception",
+        ]
+        .into_iter()
+        .map(|k| t!(k).to_string())
+        .collect()
+    }
+
+    fn cells(row: &DeclarationRow) -> Vec<String> {
+        vec![
+            format!("{}{}", "– ".repeat(row.level), row.name),
+            format!("{:.1}", row.share),
+            t!(row.quality.translation_key()).to_string(),
+            row.origins.clone(),
+            row.supplier.clone(),
+            row.processing_steps.clone(),
+            row.exception.clone(),
+        ]
+    }
+
+    /// CSV mit Semikolon als Trennzeichen, damit Excel in der Schweiz die Datei
+    /// ohne Import-Assistent öffnet. Zuerst die Kopfangaben als Schlüssel/Wert,
+    /// nach einer Leerzeile die Zutatentabelle.
+    pub fn to_csv(&self) -> String {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(b';')
+            .flexible(true)
+            .from_writer(Vec::new());
+        for (key, value) in self.summary() {
+            let _ = writer.write_record([key, value]);
+        }
+        let _ = writer.write_record([""]);
+        let _ = writer.write_record(Self::header());
+        for row in &self.rows {
+            let _ = writer.write_record(Self::cells(row));
+        }
+        writer
+            .into_inner()
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .unwrap_or_default()
+    }
+
+    /// Eigenständige HTML-Seite zum Ausdrucken (A4 quer).
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\">");
+        html.push_str(&format!("<title>{}</title>", html_escape(&t!("recipe_declaration.title"))));
+        html.push_str(
+            "<style>@page{size:A4 landscape;margin:15mm}\
+             body{font-family:sans-serif;font-size:10pt}\
+             table{border-collapse:collapse;width:100%}\
+             th,td{border:1px solid #999;padding:3px 5px;text-align:left;vertical-align:top}\
+             td.num{text-align:right}\
+             dl{display:grid;grid-template-columns:max-content auto;gap:2px 12px}\
+             dd{margin:0}</style></head><body>\n",
+        );
+        html.push_str(&format!("<h1>{}</h1>\n<dl>", html_escape(&t!("recipe_declaration.title"))));
+        for (key, value) in self.summary() {
+            html.push_str(&format!("<dt>{}</dt><dd>{}</dd>", html_escape(&key), html_escape(&value)));
+        }
+        html.push_str("</dl>\n<table><thead><tr>");
+        for column in Self::header() {
+            html.push_str(&format!("<th>{}</th>", html_escape(&column)));
+        }
+        html.push_str("</tr></thead><tbody>\n");
+        for row in &self.rows {
+            html.push_str("<tr>");
+            for (i, cell) in Self::cells(row).iter().enumerate() {
+                let class = if i == 1 { " class=\"num\"" } else { "" };
+                html.push_str(&format!("<td{}>{}</td>", class, html_escape(cell)));
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</tbody></table>\n</body></html>\n");
+        html
+    }
+}
@@ -43,16 +43,46 @@ pub enum RuleDef {
     AP1_3_EingabeNamensgebendeZutat,
     /// AP1.4: Enables manual input of total product weight
     AP1_4_ManuelleEingabeTotal,
+    /// AP1.5: Orders the list by weight in the finished product (added water and
+    /// volatile ingredients after evaporation, reconstituted concentrates) and
+    /// omits added water of at most 5%
+    AP1_5_ReihenfolgeEndprodukt,
+    /// AP1.6: Lets ingredients below 2% follow the recipe order and spices/herbs
+    /// below 2% be declared as «Gewürze»/«Kräuter»
+    AP1_6_FreieReihenfolgeUnter2Prozent,
     /// AP2.1: Shows composite ingredients with their sub-components
     AP2_1_ZusammegesetztOutput,
+    /// AP3.1: Adds the mandatory warning texts triggered by specific ingredients
+    /// (azo dyes, sweeteners, aspartame, polyols, caffeine)
+    AP3_1_PflichthinweiseZutaten,
     /// AP7.1: Requires country of origin for ingredients >50% of total weight (Swiss requirement)
     AP7_1_HerkunftBenoetigtUeber50Prozent,
     /// AP7.3: Requires country of origin for meat ingredients >20% of total weight (Swiss requirement)
     AP7_3_HerkunftFleischUeber20Prozent,
-    /// AP7.4: Requires birthplace and slaughter location for beef ingredients (Swiss requirement)
-    AP7_4_RindfleischHerkunftDetails,
+    /// AP7.4: Requires the rearing and slaughter location for meat of the species in the
+    /// `category_service` species table: cattle, pigs, poultry, sheep, goats (Swiss requirement)
+    AP7_4_FleischHerkunftDetails,
     /// AP7.5: Requires catch location for fish ingredients (Swiss requirement)
     AP7_5_FischFangort,
+    /// AP7.6: Requires the origin of the primary ingredient when the label
+    /// emphasises a different origin (Swiss cross, «Schweizer …» in the title)
+    AP7_6_HerkunftPrimaerzutat,
+    /// AP7.7: Requires the country of origin of honey and prints blends as «Mischung von
+    /// Honig aus EU-Ländern / Nicht-EU-Ländern / EU- und Nicht-EU-Ländern»
+    AP7_7_HerkunftHonig,
+    /// Swissness: Decides whether the Swiss cross / «Swiss made» may be used
+    /// (80% of the raw-material weight from Switzerland, milk 100%)
+    Swissness_80_Prozent_Rohstoffe,
+    /// BAlV: «Berg» / «Alp» in the title or subtitle requires 90% of the agricultural raw
+    /// materials from the mountain / summering area (milk 100%)
+    BAlV_BergAlpBezeichnung,
+    /// AOP/IGP: A registered protected designation («Gruyère», «Bündnerfleisch») in the
+    /// title or subtitle requires the AOP/IGP certificate
+    AOP_IGP_GeschuetzteBezeichnung,
+    /// LDV: Adds the statements for eggs from cage housing and for meat possibly produced
+    /// with hormonal/antibiotic performance enhancers; requires the production method of
+    /// imported eggs and meat
+    LDV_NichtZugelasseneProduktionsmethoden,
     /// Knospe: Requires country of origin for ALL ingredients (Knospe requirement)
     Knospe_AlleZutatenHerkunft,
     /// Knospe: When 100% of agricultural ingredients are from Switzerland, no origin display needed
@@ -61,12 +91,18 @@ pub enum RuleDef {
     Knospe_90_99_Percent_CH_ShowOrigin,
     /// Knospe: When <90% of agricultural ingredients are from Switzerland, show origin based on specific ingredient criteria
     Knospe_Under90_Percent_CH_IngredientRules,
+    /// Knospe: Checks processing steps against the Bio Suisse whitelist of the ingredient's
+    /// main/sub group; categories without a Bio Suisse mapping need a manual review
+    Knospe_Verarbeitungsschritte,
     /// Bio/Knospe: Enables input of whether each ingredient is bio-certified
     Bio_Knospe_EingabeIstBio,
     /// Knospe: Shows Bio Suisse logo based on Swiss ingredient percentage
     Knospe_ShowBioSuisseLogo,
     /// Bio/Knospe: Requires certification body for Bio and Knospe products
     Bio_Knospe_ZertifizierungsstellePflicht,
+    /// Bio/Knospe: The supplier certificate backing an ingredient's Bio/Knospe claim
+    /// must be valid until the date on the label
+    Bio_Knospe_LieferantenZertifikat,
     /// Bio-V: Adds "Bio" to the Sachbezeichnung when >= 95% of the agricultural
     /// weight is Bio-CH certified (permitted non-bio exceptions tolerated up to 5%)
     Bio_ShowBioSachbezeichnung,
@@ -94,18 +130,29 @@ impl Rule for RuleDef {
             RuleDef::AP1_2_ProzentOutputNamensgebend => RuleType::Output,
             RuleDef::AP1_3_EingabeNamensgebendeZutat => RuleType::Conditional,
             RuleDef::AP1_4_ManuelleEingabeTotal => RuleType::Conditional,
+            RuleDef::AP1_5_ReihenfolgeEndprodukt => RuleType::Output,
+            RuleDef::AP1_6_FreieReihenfolgeUnter2Prozent => RuleType::Output,
             RuleDef::AP2_1_ZusammegesetztOutput => RuleType::Output,
+            RuleDef::AP3_1_PflichthinweiseZutaten => RuleType::Output,
             RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent => RuleType::Conditional,
             RuleDef::AP7_3_HerkunftFleischUeber20Prozent => RuleType::Conditional,
-            RuleDef::AP7_4_RindfleischHerkunftDetails => RuleType::Conditional,
+            RuleDef::AP7_4_FleischHerkunftDetails => RuleType::Conditional,
             RuleDef::AP7_5_FischFangort => RuleType::Conditional,
+            RuleDef::AP7_6_HerkunftPrimaerzutat => RuleType::Output,
+            RuleDef::AP7_7_HerkunftHonig => RuleType::Output,
+            RuleDef::Swissness_80_Prozent_Rohstoffe => RuleType::Conditional,
+            RuleDef::BAlV_BergAlpBezeichnung => RuleType::Validation,
+            RuleDef::AOP_IGP_GeschuetzteBezeichnung => RuleType::Validation,
+            RuleDef::LDV_NichtZugelasseneProduktionsmethoden => RuleType::Output,
             RuleDef::Knospe_AlleZutatenHerkunft => RuleType::Validation,
             RuleDef::Knospe_100_Percent_CH_NoOrigin => RuleType::Output,
             RuleDef::Knospe_90_99_Percent_CH_ShowOrigin => RuleType::Output,
             RuleDef::Knospe_Under90_Percent_CH_IngredientRules => RuleType::Output,
+            RuleDef::Knospe_Verarbeitungsschritte => RuleType::Validation,
             RuleDef::Bio_Knospe_EingabeIstBio => RuleType::Conditional,
             RuleDef::Knospe_ShowBioSuisseLogo => RuleType::Conditional,
             RuleDef::Bio_Knospe_ZertifizierungsstellePflicht => RuleType::Validation,
+            RuleDef::Bio_Knospe_LieferantenZertifikat => RuleType::Validation,
             RuleDef::Bio_ShowBioSachbezeichnung => RuleType::Conditional,
             RuleDef::Bio_AllAgriAreBio => RuleType::Output,
             RuleDef::Bio_PartialBioMarking => RuleType::Output,
@@ -119,18 +166,29 @@ impl Rule for RuleDef {
             RuleDef::AP1_2_ProzentOutputNamensgebend => "Zeigt Prozentangabe für namensgebende Zutaten auf dem Etikett",
             RuleDef::AP1_3_EingabeNamensgebendeZutat => "Ermöglicht die Eingabe von namensgebenden Zutaten in der Benutzeroberfläche",
             RuleDef::AP1_4_ManuelleEingabeTotal => "Ermöglicht die manuelle Eingabe der Gesamtmenge",
+            RuleDef::AP1_5_ReihenfolgeEndprodukt => "Ordnet die Zutaten nach ihrem Gewicht im Endprodukt (verdunstetes Wasser, rekonstituierte Zutaten) und lässt zugefügtes Wasser bis 5% weg",
+            RuleDef::AP1_6_FreieReihenfolgeUnter2Prozent => "Zutaten unter 2% in frei wählbarer Reihenfolge, Gewürze/Kräuter unter 2% unter Sammelbezeichnung; prüft, dass diese Freiheit nur unter 2% genutzt wird",
             RuleDef::AP2_1_ZusammegesetztOutput => "Zeigt zusammengesetzte Zutaten mit ihren Bestandteilen auf dem Etikett",
+            RuleDef::AP3_1_PflichthinweiseZutaten => "Ergänzt Pflichthinweise, die einzelne Zutaten auslösen (Azofarbstoffe, Süssungsmittel, Aspartam, Polyole, Koffein)",
             RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent => "Erfordert Herkunftsangabe für Zutaten, die mehr als 50% des Gesamtgewichts ausmachen (Schweizer Vorschrift)",
             RuleDef::AP7_3_HerkunftFleischUeber20Prozent => "Erfordert Herkunftsangabe für Fleisch-Zutaten, die mehr als 20% des Gesamtgewichts ausmachen (Schweizer Vorschrift)",
-            RuleDef::AP7_4_RindfleischHerkunftDetails => "Erfordert detaillierte Herkunftsangabe für Rindfleisch: Aufzucht- und Schlachtungsort (Schweizer Vorschrift)",
+            RuleDef::AP7_4_FleischHerkunftDetails => "Erfordert detaillierte Herkunftsangabe für Fleisch von Rind, Schwein, Geflügel, Schaf und Ziege: Aufzucht- und Schlachtungsort (Schweizer Vorschrift)",
             RuleDef::AP7_5_FischFangort => "Erfordert Fangort für Fisch-Zutaten (Schweizer Vorschrift)",
+            RuleDef::AP7_6_HerkunftPrimaerzutat => "Verlangt die Herkunft der Primärzutat, wenn die Etikette eine andere Herkunft hervorhebt (Schweizerkreuz, «Schweizer …» im Titel)",
+            RuleDef::AP7_7_HerkunftHonig => "Erfordert das Ursprungsland von Honig; Mischungen nur als «Mischung von Honig aus EU-Ländern / Nicht-EU-Ländern / EU- und Nicht-EU-Ländern» (Schweizer Vorschrift)",
+            RuleDef::Swissness_80_Prozent_Rohstoffe => "Swissness: Schweizerkreuz bzw. «Swiss made» nur mit mindestens 80% Schweizer Rohstoffen (Milch 100%); in der Schweiz nicht verfügbare Rohstoffe sind ausgenommen",
+            RuleDef::BAlV_BergAlpBezeichnung => "«Berg» bzw. «Alp» in der Bezeichnung nur mit mindestens 90% der landwirtschaftlichen Rohstoffe aus dem Berg- bzw. Sömmerungsgebiet (Milch 100%) (Berg- und Alp-Verordnung)",
+            RuleDef::AOP_IGP_GeschuetzteBezeichnung => "Geschützte Bezeichnungen aus dem AOP/IGP-Register (z.B. «Gruyère», «Bündnerfleisch») im Titel nur mit AOP/IGP-Zertifikat; sonst wird eine neutrale Bezeichnung vorgeschlagen",
+            RuleDef::LDV_NichtZugelasseneProduktionsmethoden => "Hinweise für Eier aus in der Schweiz nicht zugelassener Käfighaltung und für Fleisch, das mit Hormonen oder Antibiotika als Leistungsförderer erzeugt worden sein kann; verlangt die Produktionsmethode bei importierten Eiern und importiertem Fleisch",
             RuleDef::Knospe_AlleZutatenHerkunft => "Erfordert Herkunftsangabe für alle Zutaten (Knospe Anforderung)",
             RuleDef::Knospe_100_Percent_CH_NoOrigin => "Knospe: Bei 100% landwirtschaftlichen Zutaten aus CH keine Herkunftsangabe nötig",
             RuleDef::Knospe_90_99_Percent_CH_ShowOrigin => "Knospe: Bei 90-99.99% landwirtschaftlichen Zutaten aus CH Herkunftsangabe für CH-Zutaten",
             RuleDef::Knospe_Under90_Percent_CH_IngredientRules => "Knospe: Bei <90% landwirtschaftlichen Zutaten aus CH Herkunftsangabe nach spezifischen Zutatkriterien",
+            RuleDef::Knospe_Verarbeitungsschritte => "Knospe: Verarbeitungsschritte nur nach der Bio-Suisse-Positivliste der Haupt-/Untergruppe; verbotene und bewilligungspflichtige Verfahren werden beanstandet, Kategorien ohne Bio-Suisse-Zuordnung zur manuellen Prüfung gemeldet",
             RuleDef::Bio_Knospe_EingabeIstBio => "Ermöglicht die Eingabe ob eine Zutat bio-zertifiziert ist",
             RuleDef::Knospe_ShowBioSuisseLogo => "Zeigt Bio Suisse Logo basierend auf Schweizer Zutaten-Prozentsatz",
             RuleDef::Bio_Knospe_ZertifizierungsstellePflicht => "Erfordert die Angabe der Bio-Zertifizierungsstelle für Bio und Knospe Produkte",
+            RuleDef::Bio_Knospe_LieferantenZertifikat => "Das Zertifikat des Lieferanten, auf das sich die Bio-/Knospe-Angabe einer Zutat stützt, muss bis zum Datum auf der Etikette gültig sein",
             RuleDef::Bio_ShowBioSachbezeichnung => "Zeigt Bio in Sachbezeichnung wenn ≥ 95% Bio-CH zertifiziert",
             RuleDef::Bio_AllAgriAreBio => "Bio-V: Alle landwirtschaftlichen Zutaten sind bio — kein individueller * Stern",
             RuleDef::Bio_PartialBioMarking => "Bio-V: Teilweise bio — individueller * Stern und Prozentangabe in Legende",
@@ -169,11 +227,20 @@ impl RuleRegistry {
                 RuleDef::AP1_2_ProzentOutputNamensgebend,
                 RuleDef::AP1_3_EingabeNamensgebendeZutat,
                 RuleDef::AP1_4_ManuelleEingabeTotal,
+                RuleDef::AP1_5_ReihenfolgeEndprodukt,
+                RuleDef::AP1_6_FreieReihenfolgeUnter2Prozent,
                 RuleDef::AP2_1_ZusammegesetztOutput,
+                RuleDef::AP3_1_PflichthinweiseZutaten,
                 RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent,
                 RuleDef::AP7_3_HerkunftFleischUeber20Prozent,
-                RuleDef::AP7_4_RindfleischHerkunftDetails,
+                RuleDef::AP7_4_FleischHerkunftDetails,
                 RuleDef::AP7_5_FischFangort,
+                RuleDef::AP7_6_HerkunftPrimaerzutat,
+                RuleDef::AP7_7_HerkunftHonig,
+                RuleDef::Swissness_80_Prozent_Rohstoffe,
+                RuleDef::BAlV_BergAlpBezeichnung,
+                RuleDef::AOP_IGP_GeschuetzteBezeichnung,
+                RuleDef::LDV_NichtZugelasseneProduktionsmethoden,
             ],
         );
 
@@ -184,13 +251,23 @@ impl RuleRegistry {
                 RuleDef::AP1_2_ProzentOutputNamensgebend,
                 RuleDef::AP1_3_EingabeNamensgebendeZutat,
                 RuleDef::AP1_4_ManuelleEingabeTotal,
+                RuleDef::AP1_5_ReihenfolgeEndprodukt,
+                RuleDef::AP1_6_FreieReihenfolgeUnter2Prozent,
                 RuleDef::AP2_1_ZusammegesetztOutput,
+                RuleDef::AP3_1_PflichthinweiseZutaten,
                 RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent,
                 RuleDef::AP7_3_HerkunftFleischUeber20Prozent,
-                RuleDef::AP7_4_RindfleischHerkunftDetails,
+                RuleDef::AP7_4_FleischHerkunftDetails,
                 RuleDef::AP7_5_FischFangort,
+                RuleDef::AP7_6_HerkunftPrimaerzutat,
+                RuleDef::AP7_7_HerkunftHonig,
+                RuleDef::Swissness_80_Prozent_Rohstoffe,
+                RuleDef::BAlV_BergAlpBezeichnung,
+                RuleDef::AOP_IGP_GeschuetzteBezeichnung,
+                RuleDef::LDV_NichtZugelasseneProduktionsmethoden,
                 RuleDef::Bio_Knospe_EingabeIstBio,
                 RuleDef::Bio_Knospe_ZertifizierungsstellePflicht,
+                RuleDef::Bio_Knospe_LieferantenZertifikat,
                 RuleDef::Bio_ShowBioSachbezeichnung,
                 // Wild collection exists under the Bio-Verordnung too, with its own
                 // wording («aus biologisch zertifizierter Wildsammlung», DEC-11).
@@ -205,18 +282,29 @@ impl RuleRegistry {
                 RuleDef::AP1_2_ProzentOutputNamensgebend,
                 RuleDef::AP1_3_EingabeNamensgebendeZutat,
                 RuleDef::AP1_4_ManuelleEingabeTotal,
+                RuleDef::AP1_5_ReihenfolgeEndprodukt,
+                RuleDef::AP1_6_FreieReihenfolgeUnter2Prozent,
                 RuleDef::AP2_1_ZusammegesetztOutput,
+                RuleDef::AP3_1_PflichthinweiseZutaten,
                 RuleDef::AP7_1_HerkunftBenoetigtUeber50Prozent,
                 RuleDef::AP7_3_HerkunftFleischUeber20Prozent,
-                RuleDef::AP7_4_RindfleischHerkunftDetails,
+                RuleDef::AP7_4_FleischHerkunftDetails,
                 RuleDef::AP7_5_FischFangort,
+                RuleDef::AP7_6_HerkunftPrimaerzutat,
+                RuleDef::AP7_7_HerkunftHonig,
+                RuleDef::Swissness_80_Prozent_Rohstoffe,
+                RuleDef::BAlV_BergAlpBezeichnung,
+                RuleDef::AOP_IGP_GeschuetzteBezeichnung,
+                RuleDef::LDV_NichtZugelasseneProduktionsmethoden,
                 RuleDef::Knospe_AlleZutatenHerkunft,
                 RuleDef::Knospe_100_Percent_CH_NoOrigin,
                 RuleDef::Knospe_90_99_Percent_CH_ShowOrigin,
                 RuleDef::Knospe_Under90_Percent_CH_IngredientRules,
+                RuleDef::Knospe_Verarbeitungsschritte,
                 RuleDef::Bio_Knospe_EingabeIstBio,
                 RuleDef::Knospe_ShowBioSuisseLogo,
                 RuleDef::Bio_Knospe_ZertifizierungsstellePflicht,
+                RuleDef::Bio_Knospe_LieferantenZertifikat,
                 RuleDef::Wildsammlung_Ueber10Prozent,
             ],
         );
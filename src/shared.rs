@@ -10,6 +10,11 @@ pub struct Validations(pub Memo<HashMap<String, Vec<String>>>);
 #[derive(Clone, Copy)]
 pub struct VerdictsContext(pub Memo<Verdicts>);
 
+/// The production/label date (YYYY-MM-DD) of the label being edited; conversion
+/// ends are judged as of this day.
+#[derive(Clone, Copy)]
+pub struct ProductionDate(pub Signal<String>);
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Configuration {
     Conventional,
@@ -55,3 +60,29 @@ pub fn restore_params_from_session_storage() -> Option<String> {
     }
     None
 }
+
+/// Offers generated bytes as a file download. Exports are built on demand in
+/// the browser, so there is no URL to link to; a temporary `<a download>`
+/// with a data URL does the job without a server round trip.
+pub fn download_file(file_name: &str, mime: &str, bytes: &[u8]) {
+    use base64::Engine;
+    use wasm_bindgen::JsCast;
+
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let Some(body) = document.body() else {
+        return;
+    };
+    let Ok(anchor) = document.create_element("a") else {
+        return;
+    };
+    let url = format!("data:{};base64,{}", mime, base64::engine::general_purpose::STANDARD.encode(bytes));
+    let _ = anchor.set_attribute("href", &url);
+    let _ = anchor.set_attribute("download", file_name);
+    if let Ok(anchor) = anchor.dyn_into::<web_sys::HtmlElement>() {
+        let _ = body.append_child(&anchor);
+        anchor.click();
+        let _ = body.remove_child(&anchor);
+    }
+}
@@ -0,0 +1,63 @@
+//! Lieferant und Bio-Zertifikat einer Zutat.
+//!
+//! «Bio» oder «Knospe» an einer Zutat stützt sich auf das Zertifikat des
+//! Lieferanten. Damit die Angabe belegt ist, muss das Zertifikat mindestens bis
+//! zum Datum auf der Etikette (mindestens haltbar / zu verbrauchen bis) gültig
+//! sein.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Format des Datumsfelds (`<input type="date">`).
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SupplierCertificate {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub supplier: String,
+    /// Zertifikatsnummer, z.B. «CH-BIO-006-12345».
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub number: String,
+    /// Gültig bis, als JJJJ-MM-TT.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub valid_until: String,
+}
+
+impl SupplierCertificate {
+    pub fn is_empty(&self) -> bool {
+        self.supplier.trim().is_empty() && self.number.trim().is_empty() && self.valid_until.trim().is_empty()
+    }
+
+    pub fn valid_until_date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(self.valid_until.trim(), DATE_FORMAT).ok()
+    }
+
+    /// Läuft das Zertifikat vor `date` ab? Ohne Ablaufdatum lässt sich das
+    /// nicht sagen.
+    pub fn expires_before(&self, date: NaiveDate) -> bool {
+        self.valid_until_date().is_some_and(|until| until < date)
+    }
+
+    /// Lieferant mit Zertifikatsnummer, wie in der Rezepturmeldung.
+    pub fn summary(&self) -> String {
+        match (self.supplier.trim(), self.number.trim()) {
+            (supplier, "") => supplier.to_string(),
+            ("", number) => number.to_string(),
+            (supplier, number) => format!("{} ({})", supplier, number),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expiry_is_compared_by_day() {
+        let certificate = SupplierCertificate { valid_until: "2026-12-31".to_string(), ..Default::default() };
+        let date = |s| NaiveDate::parse_from_str(s, DATE_FORMAT).unwrap();
+        assert!(!certificate.expires_before(date("2026-12-31")));
+        assert!(certificate.expires_before(date("2027-01-01")));
+        assert!(!SupplierCertificate::default().expires_before(date("2099-01-01")));
+    }
+}
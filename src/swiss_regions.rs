@@ -0,0 +1,247 @@
+//! Herkunft unterhalb der Landesebene: Kantone, bekannte Regionen («aus dem
+//! Emmental», «Bündner …») und die landwirtschaftlichen Zonen Berggebiet und
+//! Sömmerungsgebiet (Alp).
+//!
+//! Eine Region ist immer ein Teil der Schweiz: sie steht als
+//! `Country::Region` in den Herkunftsländern einer Zutat und zählt überall,
+//! wo nach Schweizer Herkunft gefragt wird (`Country::is_swiss`), als `CH`.
+
+use serde::{Deserialize, Serialize};
+
+/// Kanton, Region oder Zone der Schweiz. Serialisiert als `CH-…`-Code
+/// (Kantone nach ISO 3166-2).
+#[derive(PartialEq, Eq, Hash, Serialize, Deserialize, Clone, Copy, Debug)]
+pub enum SwissRegion {
+    #[serde(rename = "CH-AG")]
+    Ag,
+    #[serde(rename = "CH-AI")]
+    Ai,
+    #[serde(rename = "CH-AR")]
+    Ar,
+    #[serde(rename = "CH-BE")]
+    Be,
+    #[serde(rename = "CH-BL")]
+    Bl,
+    #[serde(rename = "CH-BS")]
+    Bs,
+    #[serde(rename = "CH-FR")]
+    Fr,
+    #[serde(rename = "CH-GE")]
+    Ge,
+    #[serde(rename = "CH-GL")]
+    Gl,
+    #[serde(rename = "CH-GR")]
+    Gr,
+    #[serde(rename = "CH-JU")]
+    Ju,
+    #[serde(rename = "CH-LU")]
+    Lu,
+    #[serde(rename = "CH-NE")]
+    Ne,
+    #[serde(rename = "CH-NW")]
+    Nw,
+    #[serde(rename = "CH-OW")]
+    Ow,
+    #[serde(rename = "CH-SG")]
+    Sg,
+    #[serde(rename = "CH-SH")]
+    Sh,
+    #[serde(rename = "CH-SO")]
+    So,
+    #[serde(rename = "CH-SZ")]
+    Sz,
+    #[serde(rename = "CH-TG")]
+    Tg,
+    #[serde(rename = "CH-TI")]
+    Ti,
+    #[serde(rename = "CH-UR")]
+    Ur,
+    #[serde(rename = "CH-VD")]
+    Vd,
+    #[serde(rename = "CH-VS")]
+    Vs,
+    #[serde(rename = "CH-ZG")]
+    Zg,
+    #[serde(rename = "CH-ZH")]
+    Zh,
+    #[serde(rename = "CH-EMMENTAL")]
+    Emmental,
+    #[serde(rename = "CH-ENTLEBUCH")]
+    Entlebuch,
+    #[serde(rename = "CH-BERNER-OBERLAND")]
+    BernerOberland,
+    #[serde(rename = "CH-TOGGENBURG")]
+    Toggenburg,
+    #[serde(rename = "CH-ENGADIN")]
+    Engadin,
+    #[serde(rename = "CH-GREYERZ")]
+    Greyerz,
+    #[serde(rename = "CH-SEELAND")]
+    Seeland,
+    #[serde(rename = "CH-ZENTRALSCHWEIZ")]
+    Zentralschweiz,
+    #[serde(rename = "CH-OSTSCHWEIZ")]
+    Ostschweiz,
+    #[serde(rename = "CH-WESTSCHWEIZ")]
+    Westschweiz,
+    #[serde(rename = "CH-BERGGEBIET")]
+    Berggebiet,
+    #[serde(rename = "CH-SOEMMERUNGSGEBIET")]
+    Soemmerungsgebiet,
+}
+
+impl SwissRegion {
+    /// Kantone in alphabetischer Reihenfolge ihrer Kürzel.
+    pub const CANTONS: [SwissRegion; 26] = [
+        SwissRegion::Ag,
+        SwissRegion::Ai,
+        SwissRegion::Ar,
+        SwissRegion::Be,
+        SwissRegion::Bl,
+        SwissRegion::Bs,
+        SwissRegion::Fr,
+        SwissRegion::Ge,
+        SwissRegion::Gl,
+        SwissRegion::Gr,
+        SwissRegion::Ju,
+        SwissRegion::Lu,
+        SwissRegion::Ne,
+        SwissRegion::Nw,
+        SwissRegion::Ow,
+        SwissRegion::Sg,
+        SwissRegion::Sh,
+        SwissRegion::So,
+        SwissRegion::Sz,
+        SwissRegion::Tg,
+        SwissRegion::Ti,
+        SwissRegion::Ur,
+        SwissRegion::Vd,
+        SwissRegion::Vs,
+        SwissRegion::Zg,
+        SwissRegion::Zh,
+    ];
+
+    /// Regionen und Zonen, die keine Kantone sind.
+    pub const REGIONS: [SwissRegion; 12] = [
+        SwissRegion::Emmental,
+        SwissRegion::Entlebuch,
+        SwissRegion::BernerOberland,
+        SwissRegion::Toggenburg,
+        SwissRegion::Engadin,
+        SwissRegion::Greyerz,
+        SwissRegion::Seeland,
+        SwissRegion::Zentralschweiz,
+        SwissRegion::Ostschweiz,
+        SwissRegion::Westschweiz,
+        SwissRegion::Berggebiet,
+        SwissRegion::Soemmerungsgebiet,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            SwissRegion::Ag => "CH-AG",
+            SwissRegion::Ai => "CH-AI",
+            SwissRegion::Ar => "CH-AR",
+            SwissRegion::Be => "CH-BE",
+            SwissRegion::Bl => "CH-BL",
+            SwissRegion::Bs => "CH-BS",
+            SwissRegion::Fr => "CH-FR",
+            SwissRegion::Ge => "CH-GE",
+            SwissRegion::Gl => "CH-GL",
+            SwissRegion::Gr => "CH-GR",
+            SwissRegion::Ju => "CH-JU",
+            SwissRegion::Lu => "CH-LU",
+            SwissRegion::Ne => "CH-NE",
+            SwissRegion::Nw => "CH-NW",
+            SwissRegion::Ow => "CH-OW",
+            SwissRegion::Sg => "CH-SG",
+            SwissRegion::Sh => "CH-SH",
+            SwissRegion::So => "CH-SO",
+            SwissRegion::Sz => "CH-SZ",
+            SwissRegion::Tg => "CH-TG",
+            SwissRegion::Ti => "CH-TI",
+            SwissRegion::Ur => "CH-UR",
+            SwissRegion::Vd => "CH-VD",
+            SwissRegion::Vs => "CH-VS",
+            SwissRegion::Zg => "CH-ZG",
+            SwissRegion::Zh => "CH-ZH",
+            SwissRegion::Emmental => "CH-EMMENTAL",
+            SwissRegion::Entlebuch => "CH-ENTLEBUCH",
+            SwissRegion::BernerOberland => "CH-BERNER-OBERLAND",
+            SwissRegion::Toggenburg => "CH-TOGGENBURG",
+            SwissRegion::Engadin => "CH-ENGADIN",
+            SwissRegion::Greyerz => "CH-GREYERZ",
+            SwissRegion::Seeland => "CH-SEELAND",
+            SwissRegion::Zentralschweiz => "CH-ZENTRALSCHWEIZ",
+            SwissRegion::Ostschweiz => "CH-OSTSCHWEIZ",
+            SwissRegion::Westschweiz => "CH-WESTSCHWEIZ",
+            SwissRegion::Berggebiet => "CH-BERGGEBIET",
+            SwissRegion::Soemmerungsgebiet => "CH-SOEMMERUNGSGEBIET",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<SwissRegion> {
+        SwissRegion::CANTONS
+            .into_iter()
+            .chain(SwissRegion::REGIONS)
+            .find(|r| r.code() == code)
+    }
+
+    /// Deutscher Name, Rückfall für Sprachen ohne Übersetzung.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            SwissRegion::Ag => "Aargau",
+            SwissRegion::Ai => "Appenzell Innerrhoden",
+            SwissRegion::Ar => "Appenzell Ausserrhoden",
+            SwissRegion::Be => "Bern",
+            SwissRegion::Bl => "Basel-Landschaft",
+            SwissRegion::Bs => "Basel-Stadt",
+            SwissRegion::Fr => "Freiburg",
+            SwissRegion::Ge => "Genf",
+            SwissRegion::Gl => "Glarus",
+            SwissRegion::Gr => "Graubünden",
+            SwissRegion::Ju => "Jura",
+            SwissRegion::Lu => "Luzern",
+            SwissRegion::Ne => "Neuenburg",
+            SwissRegion::Nw => "Nidwalden",
+            SwissRegion::Ow => "Obwalden",
+            SwissRegion::Sg => "St. Gallen",
+            SwissRegion::Sh => "Schaffhausen",
+            SwissRegion::So => "Solothurn",
+            SwissRegion::Sz => "Schwyz",
+            SwissRegion::Tg => "Thurgau",
+            SwissRegion::Ti => "Tessin",
+            SwissRegion::Ur => "Uri",
+            SwissRegion::Vd => "Waadt",
+            SwissRegion::Vs => "Wallis",
+            SwissRegion::Zg => "Zug",
+            SwissRegion::Zh => "Zürich",
+            SwissRegion::Emmental => "Emmental",
+            SwissRegion::Entlebuch => "Entlebuch",
+            SwissRegion::BernerOberland => "Berner Oberland",
+            SwissRegion::Toggenburg => "Toggenburg",
+            SwissRegion::Engadin => "Engadin",
+            SwissRegion::Greyerz => "Greyerzerland",
+            SwissRegion::Seeland => "Seeland",
+            SwissRegion::Zentralschweiz => "Zentralschweiz",
+            SwissRegion::Ostschweiz => "Ostschweiz",
+            SwissRegion::Westschweiz => "Westschweiz",
+            SwissRegion::Berggebiet => "Schweizer Berggebiet",
+            SwissRegion::Soemmerungsgebiet => "Schweizer Sömmerungsgebiet (Alp)",
+        }
+    }
+
+    pub fn translation_key(&self) -> String {
+        format!(
+            "swiss_regions.{}",
+            self.code()
+                .trim_start_matches("CH-")
+                .to_lowercase()
+                .replace('-', "_")
+        )
+    }
+
+    pub fn is_canton(&self) -> bool {
+        SwissRegion::CANTONS.contains(self)
+    }
+}
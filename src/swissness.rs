@@ -0,0 +1,179 @@
+//! Swissness (MSchG Art. 48b): darf ein Lebensmittel das Schweizerkreuz oder
+//! «Swiss made»/«Schweizer …» tragen?
+//!
+//! Mindestens 80% des Gewichts der Rohstoffe müssen aus der Schweiz stammen,
+//! Milch und Milchprodukte zu 100%. Rohstoffe, die es in der Schweiz nicht
+//! (genügend) gibt, stehen in `swissness_exclusions.csv`: «nicht_verfuegbar»
+//! fällt aus der Berechnung heraus (Selbstversorgungsgrad unter 20%),
+//! «teilweise» zählt zur Hälfte (20–49.9%). Wasser und andere
+//! nicht-landwirtschaftliche Zutaten zählen nicht. Rohstoffe ohne Herkunft
+//! bleiben aus der Berechnung, bis sie erfasst ist; das Urteil nennt sie.
+
+use crate::category_service::is_dairy_category;
+use crate::core::Ingredient;
+use crate::model::Country;
+use crate::verdicts::{SwissnessBlockReason, SwissnessVerdict};
+use serde::Deserialize;
+
+/// Mindestanteil Schweizer Rohstoffe in Prozent.
+pub const SWISSNESS_THRESHOLD: f64 = 80.0;
+
+/// Verfügbarkeit eines Rohstoffs in der Schweiz.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Availability {
+    /// Selbstversorgungsgrad unter 20%: zählt nicht.
+    NichtVerfuegbar,
+    /// Selbstversorgungsgrad 20–49.9%: zählt zur Hälfte.
+    Teilweise,
+}
+
+/// Eine Zeile aus `swissness_exclusions.csv`.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SwissnessExclusion {
+    pub rohstoff: String,
+    pub verfuegbarkeit: Availability,
+}
+
+/// Lädt die Ausnahmeliste aus der eingebetteten CSV
+pub fn load_swissness_exclusions() -> Vec<SwissnessExclusion> {
+    let csv_data = include_str!("swissness_exclusions.csv");
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv_data.as_bytes());
+
+    rdr.deserialize()
+        .filter_map(|r| match r {
+            Ok(exclusion) => Some(exclusion),
+            Err(e) => {
+                tracing::warn!("Failed to parse swissness exclusion CSV record: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
+fn availability(ingredient: &Ingredient, exclusions: &[SwissnessExclusion]) -> Option<Availability> {
+    exclusions
+        .iter()
+        .find(|e| {
+            [Some(ingredient.name.as_str()), ingredient.canonical.as_deref()]
+                .into_iter()
+                .flatten()
+                .any(|n| n.trim().eq_ignore_ascii_case(e.rohstoff.trim()))
+        })
+        .map(|e| e.verfuegbarkeit)
+}
+
+fn origin_known(ingredient: &Ingredient) -> bool {
+    ingredient
+        .computed_origins()
+        .is_some_and(|o| o.iter().any(|c| *c != Country::NoOriginRequired))
+}
+
+fn is_swiss(ingredient: &Ingredient) -> bool {
+    ingredient
+        .computed_origins()
+        .is_some_and(|o| !o.is_empty() && o.iter().all(Country::is_swiss))
+}
+
+fn is_dairy(ingredient: &Ingredient) -> bool {
+    ingredient.effective_category().is_some_and(|c| is_dairy_category(&c))
+}
+
+/// Swissness-Anteil in Prozent, `None` wenn nach den Ausnahmen kein
+/// anrechenbarer Rohstoff mit bekannter Herkunft übrig bleibt.
+///
+/// Ein ausgenommener Rohstoff, der trotzdem aus der Schweiz kommt, zählt
+/// voll — die Ausnahme soll nicht bestrafen, wer Schweizer Ware einsetzt.
+pub fn swissness_share(ingredients: &[Ingredient], exclusions: &[SwissnessExclusion]) -> Option<f64> {
+    let mut total = 0.0;
+    let mut swiss = 0.0;
+    for leaf in ingredients.iter().flat_map(|i| i.ingredient_leaves()) {
+        if !leaf.is_agricultural() || !origin_known(leaf) {
+            continue;
+        }
+        let leaf_swiss = is_swiss(leaf);
+        let amount = leaf.computed_amount();
+        let weight = match (availability(leaf, exclusions), leaf_swiss) {
+            (_, true) | (None, false) => amount,
+            (Some(Availability::Teilweise), false) => amount / 2.0,
+            (Some(Availability::NichtVerfuegbar), false) => 0.0,
+        };
+        total += weight;
+        if leaf_swiss {
+            swiss += weight;
+        }
+    }
+    (total > 0.0).then(|| swiss / total * 100.0)
+}
+
+/// Milchrohstoffe, die nicht (nur) aus der Schweiz stammen. Für Milch gibt
+/// es keine Ausnahme: sie muss zu 100% Schweizer Milch sein.
+pub fn non_swiss_dairy(ingredients: &[Ingredient]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for leaf in ingredients.iter().flat_map(|i| i.ingredient_leaves()) {
+        if is_dairy(leaf) && origin_known(leaf) && !is_swiss(leaf) && !names.contains(&leaf.name) {
+            names.push(leaf.name.clone());
+        }
+    }
+    names
+}
+
+/// Anrechenbare Rohstoffe ohne Herkunft. Was ohnehin nicht zählt
+/// («nicht_verfuegbar»), braucht für das Urteil keine.
+pub fn unknown_origin(ingredients: &[Ingredient], exclusions: &[SwissnessExclusion]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for leaf in ingredients.iter().flat_map(|i| i.ingredient_leaves()) {
+        if leaf.is_agricultural()
+            && !origin_known(leaf)
+            && availability(leaf, exclusions) != Some(Availability::NichtVerfuegbar)
+            && !names.contains(&leaf.name)
+        {
+            names.push(leaf.name.clone());
+        }
+    }
+    names
+}
+
+/// Swissness-Urteil für die Rezeptur.
+pub fn decide_swissness(ingredients: &[Ingredient], exclusions: &[SwissnessExclusion]) -> SwissnessVerdict {
+    let unknown = unknown_origin(ingredients, exclusions);
+    let Some(share) = swissness_share(ingredients, exclusions) else {
+        let reason = if unknown.is_empty() {
+            SwissnessBlockReason::NothingToAssess
+        } else {
+            SwissnessBlockReason::OriginUnknown { ingredients: unknown }
+        };
+        return SwissnessVerdict::NotAllowed { share: 0.0, reasons: vec![reason] };
+    };
+    let mut reasons = Vec::new();
+    if share < SWISSNESS_THRESHOLD {
+        reasons.push(SwissnessBlockReason::ShareBelow80);
+    }
+    let dairy = non_swiss_dairy(ingredients);
+    if !dairy.is_empty() {
+        reasons.push(SwissnessBlockReason::MilkNotSwiss { ingredients: dairy });
+    }
+    if !unknown.is_empty() {
+        reasons.push(SwissnessBlockReason::OriginUnknown { ingredients: unknown });
+    }
+    if reasons.is_empty() {
+        SwissnessVerdict::Allowed { share }
+    } else {
+        SwissnessVerdict::NotAllowed { share, reasons }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exclusion_list_parses_completely() {
+        let csv_rows = include_str!("swissness_exclusions.csv").lines().skip(1).filter(|l| !l.is_empty()).count();
+        let exclusions = load_swissness_exclusions();
+        assert_eq!(exclusions.len(), csv_rows);
+        assert!(exclusions.iter().any(|e| e.rohstoff == "Kakaobutter" && e.verfuegbarkeit == Availability::NichtVerfuegbar));
+    }
+}
@@ -13,6 +13,11 @@
 //! (siehe `requirements/TD-1_conditionals_contract.md`).
 
 use crate::conditional_keys as keys;
+use crate::mandatory_warnings::MandatoryWarning;
+use crate::production_methods::ProductionMethodStatement;
+use crate::honey_origin::HoneyOriginStatement;
+use crate::origin_emphasis::PrimaryOriginStatement;
+use crate::mountain_designation::MountainDesignation;
 use std::collections::HashMap;
 
 /// Warum «Bio» in der Sachbezeichnung verweigert wird. Jede Variante hat
@@ -51,6 +56,16 @@ pub struct KnospeLogo {
     pub umstellung: bool,
 }
 
+/// Anteile an den landwirtschaftlichen Zutaten in Prozent, aus denen das
+/// Knospe-Urteil folgt.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KnospeShares {
+    /// Knospe-konform (inkl. erlaubter Ausnahmen).
+    pub knospe: f64,
+    /// Aus der Schweiz; entscheidet über die Knospe mit Schweizerkreuz.
+    pub swiss: f64,
+}
+
 /// Warum keine Knospe erscheint.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum KnospeBlockReason {
@@ -83,6 +98,45 @@ pub enum CheckState {
     Failed,
 }
 
+/// Warum das Schweizerkreuz / «Swiss made» nicht verwendet werden darf.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SwissnessBlockReason {
+    /// Weniger als 80% der anrechenbaren Rohstoffe aus der Schweiz.
+    ShareBelow80,
+    /// Milch muss zu 100% aus der Schweiz stammen; diese Zutaten tun es nicht.
+    MilkNotSwiss { ingredients: Vec<String> },
+    /// Ohne Herkunft lässt sich nicht beurteilen, ob diese Zutaten
+    /// Schweizer Rohstoffe sind; der Anteil ist bis dahin ohne sie gerechnet.
+    OriginUnknown { ingredients: Vec<String> },
+    /// Nach den Ausnahmen bleibt kein anrechenbarer Rohstoff übrig.
+    NothingToAssess,
+}
+
+/// Swissness-Urteil (MSchG): `share` ist der Schweizer Anteil in Prozent der
+/// anrechenbaren Rohstoffe.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SwissnessVerdict {
+    Allowed { share: f64 },
+    NotAllowed { share: f64, reasons: Vec<SwissnessBlockReason> },
+}
+
+/// Warum die Bezeichnung «Berg» / «Alp» nicht verwendet werden darf.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MountainBlockReason {
+    /// Weniger als 90% der landwirtschaftlichen Rohstoffe aus dem Gebiet.
+    ShareBelow90,
+    /// Milch muss vollständig aus dem Gebiet stammen; diese Zutaten tun es nicht.
+    MilkOutsideArea { ingredients: Vec<String> },
+}
+
+/// BAlV-Urteil über die im Titel verwendete Bezeichnung: `share` ist der
+/// Anteil aus dem Berg- bzw. Sömmerungsgebiet in Prozent.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MountainVerdict {
+    Allowed { designation: MountainDesignation, share: f64 },
+    NotAllowed { designation: MountainDesignation, share: f64, reasons: Vec<MountainBlockReason> },
+}
+
 /// Gesammelte Urteile eines `execute()`-Laufs. `None` heisst: die zugehörige
 /// Regel ist in dieser Konfiguration nicht aktiv.
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -98,9 +152,35 @@ pub struct Verdicts {
     pub namensgebende_zutat_input: bool,
     /// AP1.4: manuelles Total-Eingabefeld anzeigen.
     pub manuelles_total_input: bool,
+    /// AP1.5: Eingabefeld für den Rekonstitutionsfaktor anzeigen.
+    pub reconstitution_input: bool,
+    /// AP1.6: Schalter «freie Reihenfolge unter 2%» und Sammelbezeichnungen anzeigen.
+    pub freie_reihenfolge_input: bool,
     /// AP7.1/Fleisch: Indizes der Zutaten, die eine Herkunftsangabe brauchen.
     /// Leer = keine. Ersetzt die dynamische `herkunft_benoetigt_{i}`-Familie.
     pub origin_required_indices: Vec<usize>,
+    /// AP3.1: Pflichthinweise, die einzelne Zutaten auslösen (Azofarbstoffe,
+    /// Süssungsmittel, …). Leer = keine oder Regel nicht aktiv.
+    pub mandatory_warnings: Vec<MandatoryWarning>,
+    /// AP7.6: «Rohstoff aus …»-Angaben für Primärzutaten, deren Herkunft von
+    /// der hervorgehobenen abweicht.
+    pub primary_origin_statements: Vec<PrimaryOriginStatement>,
+    /// LDV: Hinweise zu in der Schweiz nicht zugelassenen Produktionsmethoden
+    /// (Käfigeier, Leistungsförderer bei Fleisch).
+    pub production_method_statements: Vec<ProductionMethodStatement>,
+    /// AP7.7: «Herkunft: …» für Honig als Einzelzutat.
+    pub honey_origin_statement: Option<HoneyOriginStatement>,
+    /// Swissness: darf das Schweizerkreuz / «Swiss made» verwendet werden?
+    pub swissness: Option<SwissnessVerdict>,
+    /// BAlV: trägt die Rezeptur das «Berg» / «Alp» im Titel? `None` auch, wenn
+    /// der Titel keine der Bezeichnungen verwendet.
+    pub mountain_designation: Option<MountainVerdict>,
+    /// Knospe: Zutaten, deren Kategorie keiner Bio-Suisse-Gruppe zugeordnet
+    /// ist oder deren Verarbeitungsschritt nicht in der Liste der Gruppe steht;
+    /// ihre Verarbeitung muss manuell geprüft werden.
+    pub processing_review: Vec<String>,
+    /// Knospe: Anteile für die Rezepturmeldung; `None` ohne Knospe-Regelwerk.
+    pub knospe_shares: Option<KnospeShares>,
 }
 
 impl Verdicts {